@@ -1,9 +1,14 @@
-use super::{make_mul_aux, AssignedPoint, EccConfig, MulAux, Point};
+use super::glv::glv_bit_len;
+use super::{
+    make_msm_aux, make_mul_aux, msm_window_size, AssignedPoint, AssignedPointOrIdentity, EccConfig,
+    MulAux, Point,
+};
 use crate::integer::chip::IntegerChip;
 use crate::integer::rns::{Integer, Rns};
 use crate::{halo2, maingate};
 use halo2::arithmetic::CurveAffine;
 use halo2::circuit::Layouter;
+use halo2::halo2curves::ff::Field;
 use halo2::plonk::Error;
 use integer::halo2::circuit::Value;
 use integer::maingate::{MainGateInstructions, RegionCtx};
@@ -30,8 +35,13 @@ pub struct BaseFieldEccChip<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const
         Value<C>,
     )>,
     /// Auxiliary points for optimized multiplication for each (window_size,
-    /// n_pairs) pairs
-    aux_registry:
+    /// n_pairs, n_bits) triples
+    aux_registry: BTreeMap<
+        (usize, usize, usize),
+        AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    >,
+    /// Auxiliary points for `msm` for each (window_size, n_bits) pairs
+    msm_aux_registry:
         BTreeMap<(usize, usize), AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
 }
 
@@ -44,6 +54,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
             integer_chip: IntegerChip::new(config.integer_chip_config(), Rc::new(Rns::construct())),
             aux_generator: None,
             aux_registry: BTreeMap::new(),
+            msm_aux_registry: BTreeMap::new(),
         }
     }
 
@@ -64,18 +75,20 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     }
 
     /// Returns a `Point` (Rns representation) from a point in the emulated EC
+    /// Point at infinity is encoded as $(0, 0)$
     pub fn to_rns_point(
         &self,
         point: C,
     ) -> Point<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-        let coords = point.coordinates();
-        // disallow point of infinity
-        // it will not pass assing point enforcement
-        let coords = coords.unwrap();
+        Point::new(self.rns(), point)
+    }
 
-        let x = Integer::from_fe(*coords.x(), self.rns());
-        let y = Integer::from_fe(*coords.y(), self.rns());
-        Point { x, y }
+    /// Returns emulated EC constant $a$ or `None` if it is zero
+    pub(crate) fn parameter_a(
+        &self,
+    ) -> Option<Integer<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
+        let a = C::a();
+        (!bool::from(a.is_zero())).then(|| Integer::from_fe(a, self.rns()))
     }
 
     /// Returns emulated EC constant $b$
@@ -88,12 +101,30 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         &self,
         window_size: usize,
         number_of_pairs: usize,
+    ) -> Result<MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.get_mul_aux_with_bits(
+            window_size,
+            number_of_pairs,
+            C::ScalarExt::NUM_BITS as usize,
+        )
+    }
+
+    /// Auxilary point for optimized multiplication algorithm where scalars
+    /// are `number_of_bits` long
+    fn get_mul_aux_with_bits(
+        &self,
+        window_size: usize,
+        number_of_pairs: usize,
+        number_of_bits: usize,
     ) -> Result<MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let to_add = match self.aux_generator.clone() {
             Some((assigned, _)) => Ok(assigned),
             None => Err(Error::Synthesis),
         }?;
-        let to_sub = match self.aux_registry.get(&(window_size, number_of_pairs)) {
+        let to_sub = match self
+            .aux_registry
+            .get(&(window_size, number_of_pairs, number_of_bits))
+        {
             Some(aux) => Ok(aux.clone()),
             None => Err(Error::Synthesis),
         }?;
@@ -101,6 +132,24 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         // see https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
         Ok(MulAux::new(to_add, to_sub))
     }
+
+    /// Auxilary points for `msm` where `to_add` is the initial value of the
+    /// first bucket and `to_sub` cancels the offset of all buckets
+    fn get_msm_aux(
+        &self,
+        window_size: usize,
+        number_of_bits: usize,
+    ) -> Result<MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        let to_sub = match self.msm_aux_registry.get(&(window_size, number_of_bits)) {
+            Some(aux) => Ok(aux.clone()),
+            None => Err(Error::Synthesis),
+        }?;
+        Ok(MulAux::new(to_add, to_sub))
+    }
 }
 
 impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -127,6 +176,17 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(())
     }
 
+    /// Expose `AssignedPointOrIdentity` as Public Input
+    /// Point at infinity is exposed as all zero limbs
+    pub fn expose_public_point_or_identity(
+        &self,
+        layouter: impl Layouter<C::Scalar>,
+        point: AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        self.expose_public(layouter, point.point, offset)
+    }
+
     /// Takes `Point` and assign its coordiantes as constant
     /// Returned as `AssignedPoint`
     pub fn assign_constant(
@@ -143,7 +203,10 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(AssignedPoint::new(x, y))
     }
 
-    /// Takes `Point` of the EC and returns it as `AssignedPoint`
+    /// Takes `Point` of the EC and returns it as `AssignedPoint`. Point at
+    /// infinity is encoded as $(0, 0)$ which fails the on curve check, so
+    /// points that can be the identity must be assigned with
+    /// [`BaseFieldEccChip::assign_point_or_identity`]
     pub fn assign_point(
         &self,
         ctx: &mut RegionCtx<'_, C::Scalar>,
@@ -164,6 +227,63 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(point)
     }
 
+    /// Takes a point of the EC that can be the point at infinity and returns
+    /// it as `AssignedPointOrIdentity`
+    pub fn assign_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: Value<C>,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let integer_chip = self.integer_chip();
+        let main_gate = self.main_gate();
+
+        let is_identity = point.map(|point| {
+            if bool::from(point.is_identity()) {
+                C::Scalar::ONE
+            } else {
+                C::Scalar::ZERO
+            }
+        });
+        let is_identity = main_gate.assign_bit(ctx, is_identity)?;
+
+        let point = point.map(|point| self.to_rns_point(point));
+        let (x, y) = point
+            .map(|point| (point.x().clone(), point.y().clone()))
+            .unzip();
+        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+        let y = integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
+        let point = AssignedPoint::new(x, y);
+
+        // Point must be on curve unless it is the point at infinity. In order
+        // to keep the constraint uniform identity is replaced with the
+        // generator
+        let not_identity = &main_gate.not(ctx, &is_identity)?;
+        let to_check = self.select_or_assign(ctx, not_identity, &point, C::generator())?;
+        self.assert_is_on_curve(ctx, &to_check)?;
+
+        // Point at infinity must be encoded as (0, 0)
+        let zero = &integer_chip.assign_constant(ctx, C::Base::ZERO)?;
+        let x = &integer_chip.select(ctx, point.x(), zero, &is_identity)?;
+        let y = &integer_chip.select(ctx, point.y(), zero, &is_identity)?;
+        integer_chip.assert_zero(ctx, x)?;
+        integer_chip.assert_zero(ctx, y)?;
+
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
+    /// Converts an `AssignedPoint` which is known not to be the point at
+    /// infinity to `AssignedPointOrIdentity`
+    pub fn to_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let is_identity = self.main_gate().assign_constant(ctx, C::Scalar::ZERO)?;
+        Ok(AssignedPointOrIdentity::new(point.clone(), is_identity))
+    }
+
     /// Assigns the auxiliary generator point
     pub fn assign_aux_generator(
         &mut self,
@@ -183,12 +303,68 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         window_size: usize,
         number_of_pairs: usize,
     ) -> Result<(), Error> {
+        self.assign_aux_with_bits(
+            ctx,
+            window_size,
+            number_of_pairs,
+            C::ScalarExt::NUM_BITS as usize,
+        )
+    }
+
+    /// Assigns multiplication auxiliary point for GLV multiplication with
+    /// given window size and number of pairs before the scalars are split
+    pub fn assign_aux_glv(
+        &mut self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        window_size: usize,
+        number_of_pairs: usize,
+    ) -> Result<(), Error> {
+        self.assign_aux_with_bits(
+            ctx,
+            window_size,
+            2 * number_of_pairs,
+            glv_bit_len::<C::ScalarExt>(),
+        )
+    }
+
+    /// Assigns multiplication auxiliary point for `msm`
+    /// of given number of points
+    pub fn assign_aux_msm(
+        &mut self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        number_of_points: usize,
+    ) -> Result<(), Error> {
+        let number_of_bits = C::ScalarExt::NUM_BITS as usize;
+        let window_size = msm_window_size(number_of_points, number_of_bits);
         match self.aux_generator {
             Some((_, point)) => {
-                let aux = point.map(|point| make_mul_aux(point, window_size, number_of_pairs));
+                let aux = point.map(|point| make_msm_aux(point, window_size, number_of_bits));
+                let aux = self.assign_point(ctx, aux)?;
+                self.msm_aux_registry
+                    .insert((window_size, number_of_bits), aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Assigns multiplication auxiliary point for a triple of (window_size,
+    /// n_pairs, n_bits)
+    fn assign_aux_with_bits(
+        &mut self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        window_size: usize,
+        number_of_pairs: usize,
+        number_of_bits: usize,
+    ) -> Result<(), Error> {
+        match self.aux_generator {
+            Some((_, point)) => {
+                let aux = point
+                    .map(|point| make_mul_aux(point, window_size, number_of_pairs, number_of_bits));
                 let aux = self.assign_point(ctx, aux)?;
                 self.aux_registry
-                    .insert((window_size, number_of_pairs), aux);
+                    .insert((window_size, number_of_pairs, number_of_bits), aux);
                 Ok(())
             }
             // aux generator is not assigned yet
@@ -206,6 +382,12 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
 
         let y_square = &integer_chip.square(ctx, point.y())?;
         let x_square = &integer_chip.square(ctx, point.x())?;
+        // x^3 + a * x = (x^2 + a) * x so that non zero $a$ costs only a
+        // constant addition
+        let x_square = &match self.parameter_a() {
+            Some(a) => integer_chip.add_constant(ctx, x_square, &a)?,
+            None => x_square.clone(),
+        };
         let x_cube = &integer_chip.mul(ctx, point.x(), x_square)?;
         let x_cube_b = &integer_chip.add_constant(ctx, x_cube, &self.parameter_b())?;
         integer_chip.assert_equal(ctx, x_cube_b, y_square)?;
@@ -238,6 +420,37 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(AssignedPoint::new(x, y))
     }
 
+    /// Constraints assert two `AssignedPointOrIdentity`s are equal
+    pub fn assert_equal_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p0: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        // Coordinates of the point at infinity are fixed to (0, 0) so that
+        // comparing coordinates along with the flag is enough
+        self.main_gate()
+            .assert_equal(ctx, &p0.is_identity, &p1.is_identity)?;
+        self.assert_equal(ctx, &p0.point, &p1.point)
+    }
+
+    /// Selects between 2 `AssignedPointOrIdentity` determined by an
+    /// `AssignedCondition`
+    pub fn select_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        c: &AssignedCondition<C::Scalar>,
+        p1: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let point = self.select(ctx, c, &p1.point, &p2.point)?;
+        let is_identity = self
+            .main_gate()
+            .select(ctx, &p1.is_identity, &p2.is_identity, c)?;
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
     /// Selects between an `AssignedPoint` and a point on the EC `Emulated`
     /// determined by an `AssignedCondition`
     pub fn select_or_assign(
@@ -292,6 +505,28 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         self._double_incomplete(ctx, p)
     }
 
+    /// Adds 2 `AssignedPointOrIdentity`s. Unlike `add` operands can be equal
+    /// or opposite of each other and any of them can be the point at infinity
+    pub fn add_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p0: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        self._add_complete(ctx, p0, p1)
+    }
+
+    /// Doubles an `AssignedPointOrIdentity`
+    pub fn double_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        p: &AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        self._double_complete(ctx, p)
+    }
+
     /// Given an `AssignedPoint` $P$ computes P * 2^logn
     pub fn double_n(
         &self,
@@ -351,18 +586,20 @@ mod tests {
     use crate::integer::rns::Rns;
     use crate::integer::NUMBER_OF_LOOKUP_LIMBS;
     use crate::maingate;
-    use halo2::arithmetic::CurveAffine;
+    use crate::mul_fixed_doubling_scalar;
+    use halo2::arithmetic::{best_multiexp, CurveAffine};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
     use halo2::halo2curves::{
-        ff::{Field, FromUniformBytes, PrimeField},
+        ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup},
         group::{Curve as _, Group},
     };
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
     use integer::maingate::RegionCtx;
     use maingate::mock_prover_verify;
     use maingate::{
-        AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig,
-        RangeInstructions,
+        AssignedValue, DimensionMeasurement, MainGate, MainGateConfig, MainGateInstructions,
+        RangeChip, RangeConfig, RangeInstructions,
     };
     use paste::paste;
     use rand_core::OsRng;
@@ -526,6 +763,96 @@ mod tests {
         run::<Vesta>();
     }
 
+    #[derive(Clone, Debug, Default)]
+    struct TestEccCompleteAddition<C> {
+        _marker: PhantomData<C>,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccCompleteAddition<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = C::CurveExt::random(OsRng);
+                    let b = C::CurveExt::random(OsRng);
+                    let identity = C::CurveExt::identity();
+
+                    // test addition including exceptional cases
+                    for (p0, p1) in [
+                        (a, b),
+                        (a, a),
+                        (a, -a),
+                        (identity, b),
+                        (a, identity),
+                        (identity, identity),
+                    ] {
+                        let expected = p0 + p1;
+                        let p0 = ecc_chip.assign_point_or_identity(ctx, Value::known(p0.into()))?;
+                        let p1 = ecc_chip.assign_point_or_identity(ctx, Value::known(p1.into()))?;
+                        let expected = ecc_chip
+                            .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                        let result = ecc_chip.add_complete(ctx, &p0, &p1)?;
+                        ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                    }
+
+                    // test doubling including the identity
+                    for p in [a, identity] {
+                        let expected = p + p;
+                        let p = ecc_chip.assign_point_or_identity(ctx, Value::known(p.into()))?;
+                        let expected = ecc_chip
+                            .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                        let result = ecc_chip.double_complete(ctx, &p)?;
+                        ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_complete_addition_circuit() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            let circuit = TestEccCompleteAddition::<C>::default();
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccPublicInput<C: CurveAffine> {
         a: Value<C>,
@@ -623,6 +950,107 @@ mod tests {
         run::<Vesta>();
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestEccPointOrIdentity<C: CurveAffine> {
+        point: Value<C>,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccPointOrIdentity<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            let point = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = C::CurveExt::random(OsRng);
+                    let identity = C::CurveExt::identity();
+
+                    for (p1, p2) in [(a, identity), (identity, a), (identity, identity)] {
+                        for cond in [true, false] {
+                            let expected = if cond { p1 } else { p2 };
+                            let p1 =
+                                ecc_chip.assign_point_or_identity(ctx, Value::known(p1.into()))?;
+                            let p2 =
+                                ecc_chip.assign_point_or_identity(ctx, Value::known(p2.into()))?;
+                            let expected = ecc_chip
+                                .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                            let cond = main_gate
+                                .assign_bit(ctx, Value::known(C::Scalar::from(cond as u64)))?;
+                            let result = ecc_chip.select_point_or_identity(ctx, &cond, &p1, &p2)?;
+                            ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                        }
+                    }
+
+                    ecc_chip.assign_point_or_identity(ctx, self.point)
+                },
+            )?;
+            ecc_chip.expose_public_point_or_identity(layouter.namespace(|| "point"), point, 0)?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_point_or_identity() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            let (rns, _) = setup::<C>(20);
+            let rns = Rc::new(rns);
+
+            for point in [
+                <C as CurveAffine>::CurveExt::random(OsRng).to_affine(),
+                <C as CurveAffine>::CurveExt::identity().to_affine(),
+            ] {
+                let public_data = Point::new(Rc::clone(&rns), point).public();
+                let circuit = TestEccPointOrIdentity {
+                    point: Value::known(point),
+                };
+                mock_prover_verify(&circuit, vec![public_data]);
+            }
+
+            // public input of the identity is all zero limbs
+            let point = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let public_data = Point::new(Rc::clone(&rns), point).public();
+            let circuit = TestEccPointOrIdentity {
+                point: Value::known(<C as CurveAffine>::CurveExt::identity().to_affine()),
+            };
+            let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+            let prover = MockProver::run(dimension.k(), &circuit, vec![public_data]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccMul<C: CurveAffine> {
         window_size: usize,
@@ -714,6 +1142,266 @@ mod tests {
         run::<Vesta>();
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulFixed<C: CurveAffine> {
+        window_size: usize,
+        scalars: Vec<C::Scalar>,
+        or_identity: bool,
+        _marker: PhantomData<C>,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccMulFixed<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::CurveExt::random(OsRng).to_affine();
+                    for s in self.scalars.iter() {
+                        let result = base * *s;
+
+                        let s = main_gate.assign_value(ctx, Value::known(*s))?;
+                        if self.or_identity {
+                            let result_1 =
+                                ecc_chip.mul_fixed_or_identity(ctx, base, &s, self.window_size)?;
+                            let result_0 = ecc_chip
+                                .assign_point_or_identity(ctx, Value::known(result.into()))?;
+                            ecc_chip.assert_equal_point_or_identity(ctx, &result_0, &result_1)?;
+                            continue;
+                        }
+
+                        let result_1 = ecc_chip.mul_fixed(ctx, base, &s, self.window_size)?;
+
+                        if !bool::from(result.is_identity()) {
+                            let result_0 =
+                                ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                            ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_mul_fixed_circuit() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            for window_size in 3..6 {
+                let scalars = vec![
+                    C::Scalar::random(OsRng),
+                    C::Scalar::ONE,
+                    -C::Scalar::ONE,
+                    mul_fixed_doubling_scalar(window_size),
+                ];
+                let circuit = TestEccMulFixed::<C> {
+                    window_size,
+                    scalars,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+
+                // zero scalar is rejected
+                let circuit = TestEccMulFixed::<C> {
+                    window_size,
+                    scalars: vec![C::Scalar::ZERO],
+                    ..Default::default()
+                };
+                let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+                let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+                assert!(prover
+                    .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                    .is_err());
+
+                // zero scalar results in the identity
+                let circuit = TestEccMulFixed::<C> {
+                    window_size,
+                    scalars: vec![C::Scalar::ZERO, C::Scalar::random(OsRng)],
+                    or_identity: true,
+                    ..Default::default()
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
+    #[test]
+    fn test_base_field_ecc_mul_fixed_small_window() {
+        // Sums of lower windows may exceed the order of Pasta curves with
+        // window size 2
+        let circuit = TestEccMulFixed::<Pallas> {
+            window_size: 2,
+            scalars: vec![<Pallas as CurveAffine>::ScalarExt::random(OsRng)],
+            ..Default::default()
+        };
+        assert!(matches!(
+            DimensionMeasurement::measure(&circuit),
+            Err(Error::Synthesis)
+        ));
+
+        let circuit = TestEccMulFixed::<Bn256> {
+            window_size: 2,
+            scalars: vec![
+                <Bn256 as CurveAffine>::ScalarExt::random(OsRng),
+                mul_fixed_doubling_scalar(2),
+            ],
+            ..Default::default()
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulGlv<C: CurveAffine> {
+        window_size: usize,
+        number_of_pairs: usize,
+        aux_generator: C,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccMulGlv<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_glv(ctx, self.window_size, 1)?;
+                    ecc_chip.assign_aux_glv(ctx, self.window_size, self.number_of_pairs)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::CurveExt::random(OsRng);
+                    let scalars = [
+                        C::Scalar::random(OsRng),
+                        C::Scalar::ONE,
+                        -C::Scalar::ONE,
+                        C::Scalar::ZETA,
+                    ];
+                    for s in scalars {
+                        let result = base * s;
+
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = main_gate.assign_value(ctx, Value::known(s))?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                        let result_1 = ecc_chip.mul_glv(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    }
+
+                    let mut pairs = vec![];
+                    let mut result = C::CurveExt::identity();
+                    for _ in 0..self.number_of_pairs {
+                        let base = C::CurveExt::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        result += base * s;
+
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = main_gate.assign_value(ctx, Value::known(s))?;
+                        pairs.push((base, s));
+                    }
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal_glv(ctx, pairs, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_mul_glv_circuit() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            for window_size in 1..5 {
+                let aux_generator = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+
+                let circuit = TestEccMulGlv {
+                    aux_generator,
+                    window_size,
+                    number_of_pairs: 3,
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccBatchMul<C: CurveAffine> {
         window_size: usize,
@@ -820,4 +1508,129 @@ mod tests {
     test_base_field_ecc_mul_batch_circuit!(Bn256);
     test_base_field_ecc_mul_batch_circuit!(Pallas);
     test_base_field_ecc_mul_batch_circuit!(Vesta);
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMsm<C: CurveAffine> {
+        number_of_points: usize,
+        aux_generator: C,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccMsm<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = MainGate::<C::Scalar>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_msm(ctx, self.number_of_points)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let bases: Vec<C> = (0..self.number_of_points)
+                        .map(|_| C::CurveExt::random(OsRng).to_affine())
+                        .collect();
+                    let scalars: Vec<C::Scalar> = (0..self.number_of_points)
+                        .map(|_| C::Scalar::random(OsRng))
+                        .collect();
+                    let result = best_multiexp(&scalars, &bases);
+
+                    let pairs = bases
+                        .into_iter()
+                        .zip(scalars)
+                        .map(|(base, s)| {
+                            let base = ecc_chip.assign_point(ctx, Value::known(base))?;
+                            let s = main_gate.assign_value(ctx, Value::known(s))?;
+                            Ok((base, s))
+                        })
+                        .collect::<Result<_, Error>>()?;
+
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 = ecc_chip.msm(ctx, pairs)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run_test_msm<C: CurveAffine>(number_of_points: usize)
+    where
+        C::Scalar: FromUniformBytes<64>,
+    {
+        let aux_generator = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+        let circuit = TestEccMsm {
+            aux_generator,
+            number_of_points,
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
+
+    macro_rules! test_base_field_ecc_msm_circuit {
+        ($C:ty) => {
+            paste! {
+                #[test]
+                fn [<test_base_field_ecc_msm_circuit_ $C:lower>]() {
+                    for number_of_points in [1, 2, 3, 4, 5, 8, 16] {
+                        run_test_msm::<$C>(number_of_points);
+                    }
+                }
+            }
+        };
+    }
+
+    test_base_field_ecc_msm_circuit!(Bn256);
+    test_base_field_ecc_msm_circuit!(Pallas);
+    test_base_field_ecc_msm_circuit!(Vesta);
+
+    #[test]
+    fn test_base_field_ecc_msm_circuit_64() {
+        run_test_msm::<Bn256>(64);
+    }
+
+    #[test]
+    fn test_base_field_ecc_msm_circuit_128() {
+        run_test_msm::<Bn256>(128);
+    }
+
+    #[test]
+    #[ignore = "large circuits take long with the mock prover"]
+    fn test_base_field_ecc_msm_circuit_256() {
+        run_test_msm::<Bn256>(256);
+    }
 }
@@ -1,6 +1,9 @@
-use super::{AssignedPoint, BaseFieldEccChip};
-use crate::maingate::{AssignedCondition, AssignedValue, MainGateInstructions};
-use crate::{halo2, Selector, Table, Windowed};
+use super::{AssignedPoint, AssignedPointOrIdentity, BaseFieldEccChip};
+use crate::glv::{decompose_scalar, endomorphism, glv_bit_len};
+use crate::integer::rns::Integer;
+use crate::integer::IntegerInstructions;
+use crate::maingate::{big_to_fe, AssignedCondition, AssignedValue, MainGateInstructions, Term};
+use crate::{halo2, make_fixed_tables, msm_window_size, MulAux, Selector, Table, Windowed};
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::{Field, PrimeField};
 use halo2::plonk::Error;
@@ -15,8 +18,9 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         ctx: &mut RegionCtx<'_, C::Scalar>,
         bits: &mut Vec<AssignedCondition<C::Scalar>>,
         window_size: usize,
+        number_of_bits: usize,
     ) -> Result<(), Error> {
-        assert_eq!(bits.len(), C::Scalar::NUM_BITS as usize);
+        assert_eq!(bits.len(), number_of_bits);
 
         // TODO: This is a tmp workaround. Instead of padding with zeros we can use a
         // shorter ending window.
@@ -102,7 +106,7 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         let main_gate = self.main_gate();
         let decomposed = &mut main_gate.to_bits(ctx, scalar, C::Scalar::NUM_BITS as usize)?;
 
-        self.pad(ctx, decomposed, window_size)?;
+        self.pad(ctx, decomposed, window_size, C::Scalar::NUM_BITS as usize)?;
         let windowed = Self::window(decomposed.to_vec(), window_size);
         let table = &self.make_incremental_table(ctx, &aux.to_add, point, window_size)?;
 
@@ -121,6 +125,177 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         self.add(ctx, &acc, &aux.to_sub)
     }
 
+    /// Scalar multiplication of a constant point in the EC
+    ///
+    /// Window tables are precomputed off-circuit and assigned as constants, so
+    /// unlike `mul` neither table construction nor doublings are constrained.
+    /// Tables are constructed so that additions of lower windows never hit
+    /// exceptional cases. The most significant window is added with complete
+    /// addition since it is a doubling for some scalars, so any non zero
+    /// scalar is supported while zero scalar fails to satisfy the
+    /// constraints. See `mul_fixed_or_identity` for scalars that can be zero.
+    /// Window size must be at least 2 and fails with `Error::Synthesis` if it
+    /// is too small for the scalar field.
+    pub fn mul_fixed(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: C,
+        scalar: &AssignedValue<C::Scalar>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let result = self.mul_fixed_or_identity(ctx, point, scalar, window_size)?;
+        self.main_gate().assert_zero(ctx, result.is_identity())?;
+        Ok(result.point().clone())
+    }
+
+    /// Scalar multiplication of a constant point in the EC as `mul_fixed`
+    /// does where the scalar can be zero, so the result is returned as
+    /// `AssignedPointOrIdentity`
+    pub fn mul_fixed_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: C,
+        scalar: &AssignedValue<C::Scalar>,
+        window_size: usize,
+    ) -> Result<AssignedPointOrIdentity<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        assert!(window_size > 1);
+        let main_gate = self.main_gate();
+        let decomposed = &mut main_gate.to_bits(ctx, scalar, C::Scalar::NUM_BITS as usize)?;
+        self.pad(ctx, decomposed, window_size, C::Scalar::NUM_BITS as usize)?;
+        let windowed = Self::window(decomposed.to_vec(), window_size);
+        let tables = make_fixed_tables(point, window_size, windowed.0.len())?;
+
+        // Windows are in big endian order while tables start from the least
+        // significant window
+        let mut selected = windowed
+            .0
+            .iter()
+            .rev()
+            .zip(tables.into_iter())
+            .map(|(selector, table)| {
+                let table = table
+                    .into_iter()
+                    .map(|point| self.assign_constant(ctx, point))
+                    .collect::<Result<_, Error>>()?;
+                self.select_multi(ctx, selector, &Table(table))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let most_significant = selected.pop().unwrap();
+        let mut acc = selected[0].clone();
+        for to_add in selected.iter().skip(1) {
+            acc = self.add(ctx, &acc, to_add)?;
+        }
+
+        let acc = self.to_point_or_identity(ctx, &acc)?;
+        let most_significant = self.to_point_or_identity(ctx, &most_significant)?;
+        self.add_complete(ctx, &acc, &most_significant)
+    }
+
+    /// Scalar multiplication of a point in the EC using the GLV endomorphism
+    /// `(x, y) -> (beta * x, y)` that acts as multiplication by `lambda`.
+    ///
+    /// Scalar is split as `k = k_1 + k_2 * lambda` where `k_1` and `k_2` are
+    /// about half the size of the scalar, so that `k * P` is computed as a
+    /// two point multi-product with half the number of doublings. Fails with
+    /// `Error::Synthesis` if the curve has no such endomorphism. Auxiliary
+    /// point must be assigned with `assign_aux_glv` for a single pair. As in
+    /// `mul` the scalar must not be zero.
+    pub fn mul_glv(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        point: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedValue<C::Scalar>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.mul_batch_1d_horizontal_glv(ctx, vec![(point.clone(), scalar.clone())], window_size)
+    }
+
+    /// Computes multi-product as `mul_batch_1d_horizontal` where each scalar
+    /// is split with the GLV endomorphism as in `mul_glv`, so that the
+    /// multi-product is computed over twice the number of points with half the
+    /// number of doublings. Auxiliary point must be assigned with
+    /// `assign_aux_glv` for the same number of pairs.
+    #[allow(clippy::type_complexity)]
+    pub fn mul_batch_1d_horizontal_glv(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        pairs: Vec<(
+            AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedValue<C::Scalar>,
+        )>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(window_size > 0);
+        assert!(!pairs.is_empty());
+        let (beta, lambda) = endomorphism::<C>().ok_or(Error::Synthesis)?;
+        let number_of_bits = glv_bit_len::<C::Scalar>();
+        let aux = self.get_mul_aux_with_bits(window_size, 2 * pairs.len(), number_of_bits)?;
+
+        let main_gate = self.main_gate();
+        let integer_chip = self.integer_chip();
+        let beta = Integer::from_fe(beta, self.rns());
+
+        let mut points = vec![];
+        let mut decomposed_scalars = vec![];
+        for (point, scalar) in pairs.iter() {
+            // Witness decomposition of the scalar as absolute values and signs.
+            // Witness is not trusted, it is bound by the constraints below
+            let decomposed = scalar.value().map(|k| decompose_scalar(*k, lambda));
+            let mut signed_parts = vec![];
+            let mut signs = vec![];
+            for i in 0..2 {
+                let part = decomposed
+                    .as_ref()
+                    .map(|decomposed| big_to_fe::<C::Scalar>(decomposed[i].0.clone()));
+                let sign = decomposed.as_ref().map(|decomposed| {
+                    if decomposed[i].1 {
+                        C::Scalar::ONE
+                    } else {
+                        C::Scalar::ZERO
+                    }
+                });
+                let part = main_gate.assign_value(ctx, part)?;
+                let sign = main_gate.assign_bit(ctx, sign)?;
+                // Also bounds the part to `number_of_bits`
+                decomposed_scalars.push(main_gate.to_bits(ctx, &part, number_of_bits)?);
+                let negated = main_gate.neg_with_constant(ctx, &part, C::Scalar::ZERO)?;
+                signed_parts.push(main_gate.select(ctx, &negated, &part, &sign)?);
+                signs.push(sign);
+            }
+
+            // k = k_1 + k_2 * lambda
+            main_gate.assert_zero_sum(
+                ctx,
+                &[
+                    Term::Assigned(&signed_parts[0], C::Scalar::ONE),
+                    Term::Assigned(&signed_parts[1], lambda),
+                    Term::Assigned(scalar, -C::Scalar::ONE),
+                ],
+                C::Scalar::ZERO,
+            )?;
+
+            // phi(P) = (beta * x, y) = lambda * P
+            let x = integer_chip.mul_constant(ctx, point.x(), &beta)?;
+            let endo_point = AssignedPoint::new(x, point.y().clone());
+
+            for (point, sign) in [point.clone(), endo_point].iter().zip(signs.iter()) {
+                let negated = self.neg(ctx, point)?;
+                points.push(self.select(ctx, sign, &negated, point)?);
+            }
+        }
+
+        self.mul_batch_bits(
+            ctx,
+            &points,
+            decomposed_scalars,
+            window_size,
+            number_of_bits,
+            aux,
+        )
+    }
+
     /// Computes multi-product
     ///
     /// Given a vector of point, scalar pairs
@@ -143,34 +318,194 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
 
         let main_gate = self.main_gate();
 
-        let mut decomposed_scalars: Vec<Vec<AssignedCondition<C::Scalar>>> = pairs
+        let decomposed_scalars: Vec<Vec<AssignedCondition<C::Scalar>>> = pairs
             .iter()
             .map(|(_, scalar)| main_gate.to_bits(ctx, scalar, C::Scalar::NUM_BITS as usize))
             .collect::<Result<_, Error>>()?;
 
+        let points: Vec<_> = pairs.into_iter().map(|(point, _)| point).collect();
+        self.mul_batch_bits(
+            ctx,
+            &points,
+            decomposed_scalars,
+            window_size,
+            C::Scalar::NUM_BITS as usize,
+            aux,
+        )
+    }
+
+    /// Computes multi-product of points with scalars that are given in
+    /// little endian bit decomposed form
+    #[allow(clippy::type_complexity)]
+    fn mul_batch_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        points: &[AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        mut decomposed_scalars: Vec<Vec<AssignedCondition<C::Scalar>>>,
+        window_size: usize,
+        number_of_bits: usize,
+        aux: MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert_eq!(points.len(), decomposed_scalars.len());
         for decomposed in decomposed_scalars.iter_mut() {
-            self.pad(ctx, decomposed, window_size)?;
+            self.pad(ctx, decomposed, window_size, number_of_bits)?;
         }
 
         let windowed_scalars: Vec<Windowed<C::Scalar>> = decomposed_scalars
             .iter()
             .map(|decomposed| Self::window(decomposed.to_vec(), window_size))
             .collect();
-        let number_of_windows = windowed_scalars[0].0.len();
 
         let mut binary_aux = aux.to_add.clone();
-        let tables: Vec<Table<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = pairs
+        let tables: Vec<Table<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = points
             .iter()
             .enumerate()
-            .map(|(i, (point, _))| {
+            .map(|(i, point)| {
                 let table = self.make_incremental_table(ctx, &binary_aux, point, window_size);
-                if i != pairs.len() - 1 {
+                if i != points.len() - 1 {
                     binary_aux = self.double(ctx, &binary_aux)?;
                 }
                 table
             })
             .collect::<Result<_, Error>>()?;
 
+        self.accumulate_windows(ctx, &tables, &windowed_scalars, window_size, aux)
+    }
+
+    /// Computes multi-product with the bucket method of Pippenger
+    ///
+    /// Scalars are split into windows and at each window every point is added
+    /// to the bucket that its window value selects. Bucket selection is done
+    /// in-circuit: the bucket is read with a selection over all buckets and
+    /// the sum is written back only to the bucket whose index is the window
+    /// value, so that a point costs a single addition per window. Buckets are
+    /// then summed up as $\sum_j j * B_j$ with running sums and window sums
+    /// are accumulated doubling the accumulator in between. Bucket $j$ starts
+    /// from $2^j * A$ where $A$ is the auxiliary generator to avoid
+    /// exceptional cases and the offset is subtracted at the end. Window size
+    /// is chosen from the number of points. Auxiliary point must be assigned
+    /// with `assign_aux_msm`. As in other multiplication methods the result
+    /// must not be the identity.
+    #[allow(clippy::type_complexity)]
+    pub fn msm(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        pairs: Vec<(
+            AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedValue<C::Scalar>,
+        )>,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(!pairs.is_empty());
+        let number_of_bits = C::ScalarExt::NUM_BITS as usize;
+        let window_size = msm_window_size(pairs.len(), number_of_bits);
+        let aux = self.get_msm_aux(window_size, number_of_bits)?;
+
+        let main_gate = self.main_gate();
+        let windowed_scalars: Vec<Windowed<C::Scalar>> = pairs
+            .iter()
+            .map(|(_, scalar)| {
+                let decomposed = &mut main_gate.to_bits(ctx, scalar, number_of_bits)?;
+                self.pad(ctx, decomposed, window_size, number_of_bits)?;
+                Ok(Self::window(decomposed.to_vec(), window_size))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        // Bucket `j` starts from `2^j * aux`
+        let number_of_buckets = 1 << window_size;
+        let mut initial_buckets = vec![aux.to_add.clone()];
+        for _ in 1..number_of_buckets {
+            let bucket = self.double(ctx, initial_buckets.last().unwrap())?;
+            initial_buckets.push(bucket);
+        }
+
+        let number_of_windows = windowed_scalars[0].0.len();
+        let mut acc: Option<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> =
+            None;
+        for i in 0..number_of_windows {
+            let mut buckets = initial_buckets.clone();
+            for ((point, _), windowed) in pairs.iter().zip(windowed_scalars.iter()) {
+                let selector = &windowed.0[i];
+                let bucket = self.select_multi(ctx, selector, &Table(buckets.clone()))?;
+                let sum = self.add(ctx, &bucket, point)?;
+                let flags = self.window_value_flags(ctx, selector)?;
+                buckets = buckets
+                    .iter()
+                    .zip(flags.iter())
+                    .map(|(bucket, flag)| self.select(ctx, flag, &sum, bucket))
+                    .collect::<Result<_, Error>>()?;
+            }
+
+            let window_sum = self.sum_buckets(ctx, &buckets)?;
+            acc = Some(match acc {
+                None => window_sum,
+                Some(acc) => {
+                    let acc = self.double_n(ctx, &acc, window_size)?;
+                    self.add(ctx, &acc, &window_sum)?
+                }
+            });
+        }
+
+        self.add(ctx, &acc.unwrap(), &aux.to_sub)
+    }
+
+    /// Returns flags for each value of the window where only the flag at the
+    /// index of the window value is set. Selector bits are in little endian
+    fn window_value_flags(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        selector: &Selector<C::Scalar>,
+    ) -> Result<Vec<AssignedCondition<C::Scalar>>, Error> {
+        let main_gate = self.main_gate();
+        let mut bits = selector.0.iter();
+        let bit = bits.next().unwrap();
+        let mut flags = vec![main_gate.not(ctx, bit)?, bit.clone()];
+        for bit in bits {
+            // flags of values where the bit is set are added after the ones
+            // where it is not, which doubles the number of values
+            let set: Vec<AssignedCondition<C::Scalar>> = flags
+                .iter()
+                .map(|flag| main_gate.mul(ctx, flag, bit))
+                .collect::<Result<_, Error>>()?;
+            let unset: Vec<AssignedCondition<C::Scalar>> = flags
+                .iter()
+                .zip(set.iter())
+                .map(|(flag, set)| main_gate.sub(ctx, flag, set))
+                .collect::<Result<_, Error>>()?;
+            flags = unset.into_iter().chain(set).collect();
+        }
+        Ok(flags)
+    }
+
+    /// Sums up buckets as $\sum_j j * B_j$ with running sums starting from the
+    /// last bucket
+    fn sum_buckets(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        buckets: &[AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (last, rest) = buckets.split_last().unwrap();
+        let mut running_sum = last.clone();
+        let mut sum = last.clone();
+        for bucket in rest.iter().skip(1).rev() {
+            running_sum = self.add(ctx, &running_sum, bucket)?;
+            sum = self.add(ctx, &sum, &running_sum)?;
+        }
+        Ok(sum)
+    }
+
+    /// Adds up selected table values window by window doubling the
+    /// accumulator in between and finally subtracts the auxiliary offset
+    fn accumulate_windows(
+        &self,
+        ctx: &mut RegionCtx<'_, C::Scalar>,
+        tables: &[Table<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        windowed_scalars: &[Windowed<C::Scalar>],
+        window_size: usize,
+        aux: MulAux<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert_eq!(tables.len(), windowed_scalars.len());
+        let number_of_windows = windowed_scalars[0].0.len();
+
         // preparation for the first round
         // initialize accumulator
         let mut acc = self.select_multi(ctx, &windowed_scalars[0].0[0], &tables[0])?;
@@ -0,0 +1,694 @@
+use super::{make_mul_aux, EccConfig};
+use crate::halo2;
+use crate::integer::fq2::{AssignedFq2, Fq2Chip, QuadraticExtension};
+use crate::integer::rns::Rns;
+use crate::integer::{AssignedInteger, IntegerChip, IntegerInstructions};
+use crate::maingate;
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Value;
+use halo2::halo2curves::ff::{Field, PrimeField};
+use halo2::plonk::Error;
+use integer::maingate::RegionCtx;
+use maingate::{AssignedCondition, MainGate, MainGateInstructions};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Point of a curve over a quadratic extension field that is assumed to be on
+/// curve and not infinity
+#[derive(Clone, Debug)]
+pub struct AssignedG2Point<
+    F: QuadraticExtension,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    x: AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    y: AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<
+        F: QuadraticExtension,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > AssignedG2Point<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedG2Point` given its coordinates
+    /// Does not check for validity
+    pub fn new(
+        x: AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        y: AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        AssignedG2Point { x, y }
+    }
+
+    /// Returns $x$ coordinate
+    pub fn x(&self) -> &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.x
+    }
+
+    /// Returns $y$ coordinate
+    pub fn y(&self) -> &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.y
+    }
+}
+
+/// Constaints operations of a short Weierstrass curve with $a = 0$ over a
+/// quadratic extension field such as BN254 G2
+///
+/// Only curve equation is enforced on assigned points. Membership to the
+/// prime order subgroup is checked with `assert_in_subgroup`, which must be
+/// called for points that are not trusted by the caller.
+#[derive(Clone, Debug)]
+#[allow(clippy::type_complexity)]
+pub struct G2Chip<
+    C: CurveAffine,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> where
+    C::Base: QuadraticExtension,
+{
+    /// `Fq2Chip` for the base field of the EC
+    fq2_chip: Fq2Chip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    /// `IntegerChip` for the scalar field of the EC
+    scalar_field_chip: IntegerChip<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    /// Auxiliary point for optimized multiplication algorithm
+    aux_generator: Option<(
+        AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        Value<C>,
+    )>,
+    /// Auxiliary points for optimized multiplication for each window size
+    aux_registry: BTreeMap<usize, AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+}
+
+impl<C: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    G2Chip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+where
+    C::Base: QuadraticExtension,
+{
+    /// Residue numeral system
+    /// Used to emulate the prime field under the extension and the scalar
+    /// field of the curve over the native field `N`
+    #[allow(clippy::type_complexity)]
+    pub fn rns() -> (
+        Rns<<C::Base as QuadraticExtension>::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        Rns<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) {
+        (Rns::construct(), Rns::construct())
+    }
+
+    /// Return `G2Chip` from `EccConfig`
+    pub fn new(config: EccConfig) -> Self {
+        assert!(
+            bool::from(C::a().is_zero()),
+            "only curves with a = 0 are supported"
+        );
+        let (rns_base_field, rns_scalar_field) = Self::rns();
+        let integer_config = config.integer_chip_config();
+        Self {
+            fq2_chip: Fq2Chip::new(IntegerChip::new(
+                integer_config.clone(),
+                Rc::new(rns_base_field),
+            )),
+            scalar_field_chip: IntegerChip::new(integer_config, Rc::new(rns_scalar_field)),
+            aux_generator: None,
+            aux_registry: BTreeMap::new(),
+        }
+    }
+
+    /// Return `Fq2Chip` for the base field of the EC
+    pub fn fq2_chip(&self) -> &Fq2Chip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fq2_chip
+    }
+
+    /// Return `IntegerChip` for the scalar field of the EC
+    pub fn scalar_field_chip(&self) -> &IntegerChip<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.scalar_field_chip
+    }
+
+    /// Return `Maingate` of the `G2Chip`
+    pub fn main_gate(&self) -> &MainGate<N> {
+        self.fq2_chip.main_gate()
+    }
+
+    /// Takes a point of the EC and assigns its coordinates as constant
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: C,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // disallow point of infinity
+        let coords = point.coordinates().unwrap();
+        let x = self.fq2_chip.assign_constant(ctx, *coords.x())?;
+        let y = self.fq2_chip.assign_constant(ctx, *coords.y())?;
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    /// Takes a point of the EC and returns it as `AssignedG2Point`
+    /// Point is checked to be on curve but not to be in the subgroup
+    pub fn assign_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<C>,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (x, y) = point
+            .map(|point| {
+                let coords = point.coordinates().unwrap();
+                (*coords.x(), *coords.y())
+            })
+            .unzip();
+        let x = self.fq2_chip.assign_element(ctx, x)?;
+        let y = self.fq2_chip.assign_element(ctx, y)?;
+
+        let point = AssignedG2Point::new(x, y);
+        self.assert_is_on_curve(ctx, &point)?;
+        Ok(point)
+    }
+
+    /// Assigns the auxiliary generator point
+    pub fn assign_aux_generator(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        aux_generator: Value<C>,
+    ) -> Result<(), Error> {
+        let aux_generator_assigned = self.assign_point(ctx, aux_generator)?;
+        self.aux_generator = Some((aux_generator_assigned, aux_generator));
+        Ok(())
+    }
+
+    /// Assigns multiplication auxiliary point for a window size
+    pub fn assign_aux(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+    ) -> Result<(), Error> {
+        match self.aux_generator {
+            Some((_, point)) => {
+                let number_of_bits = C::Scalar::NUM_BITS as usize;
+                let aux = point.map(|point| make_mul_aux(point, window_size, 1, number_of_bits));
+                let aux = self.assign_point(ctx, aux)?;
+                self.aux_registry.insert(window_size, aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Constraints to ensure `AssignedG2Point` is on curve
+    pub fn assert_is_on_curve(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let ch = self.fq2_chip();
+
+        let y_square = &ch.square(ctx, point.y())?;
+        let x_square = &ch.square(ctx, point.x())?;
+        let x_cube = &ch.mul(ctx, point.x(), x_square)?;
+        let x_cube_b = &ch.add_constant(ctx, x_cube, C::b())?;
+        ch.assert_equal(ctx, x_cube_b, y_square)
+    }
+
+    /// Constraints to ensure `AssignedG2Point` is in the prime order subgroup
+    ///
+    /// Checks that `[n - 1] * P = -P` where `n` is the subgroup order. Points
+    /// out of the subgroup either fail the equation or hit an exceptional
+    /// case of addition in `mul`, so in any case constraints are not
+    /// satisfied. Auxiliary point for the window size must be assigned.
+    pub fn assert_in_subgroup(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<(), Error> {
+        let minus_one = self
+            .scalar_field_chip
+            .assign_constant(ctx, -C::Scalar::ONE)?;
+        let p_0 = self.mul(ctx, point, &minus_one, window_size)?;
+        let p_1 = self.neg(ctx, point)?;
+        self.assert_equal(ctx, &p_0, &p_1)
+    }
+
+    /// Constraints assert two `AssignedG2Point`s are equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let ch = self.fq2_chip();
+        ch.assert_equal(ctx, p0.x(), p1.x())?;
+        ch.assert_equal(ctx, p0.y(), p1.y())
+    }
+
+    /// Selects between 2 `AssignedG2Point` determined by an `AssignedCondition`
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        let x = ch.select(ctx, p1.x(), p2.x(), c)?;
+        let y = ch.select(ctx, p1.y(), p2.y(), c)?;
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    /// Negates an `AssignedG2Point`
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let y = self.fq2_chip.neg(ctx, p.y())?;
+        Ok(AssignedG2Point::new(p.x().clone(), y))
+    }
+
+    /// Adds 2 distinct `AssignedG2Point`s
+    ///
+    /// Operands must not be equal or opposite of each other, otherwise
+    /// inversion of $x_1 - x_0$ is not satisfiable
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+
+        // lambda = p1_y - p0_y / p1_x - p0_x
+        let numerator = &ch.sub(ctx, p1.y(), p0.y())?;
+        let denominator = &ch.sub(ctx, p1.x(), p0.x())?;
+        let lambda = &ch.div_incomplete(ctx, numerator, denominator)?;
+
+        // c_x =  lambda * lambda - p0_x - p1_x
+        let lambda_square = &ch.square(ctx, lambda)?;
+        let x = ch.sub_sub(ctx, lambda_square, p0.x(), p1.x())?;
+
+        // c_y = lambda * (p0_x - c_x) - p0_y
+        let t = &ch.sub(ctx, p0.x(), &x)?;
+        let t = &ch.mul(ctx, t, lambda)?;
+        let y = ch.sub(ctx, t, p0.y())?;
+
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    /// Doubles an `AssignedG2Point`
+    pub fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+
+        // lambda = 3 * p_x^2 / 2 * p_y
+        let x_square = &ch.square(ctx, p.x())?;
+        let numerator = &ch.mul3(ctx, x_square)?;
+        let denominator = &ch.mul2(ctx, p.y())?;
+        let lambda = &ch.div_incomplete(ctx, numerator, denominator)?;
+
+        // c_x = lambda * lambda - 2 * p_x
+        let lambda_square = &ch.square(ctx, lambda)?;
+        let x = ch.sub_sub(ctx, lambda_square, p.x(), p.x())?;
+
+        // c_y = lambda * (p_x - c_x) - p_y
+        let t = &ch.sub(ctx, p.x(), &x)?;
+        let t = &ch.mul(ctx, lambda, t)?;
+        let y = ch.sub(ctx, t, p.y())?;
+
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    /// Given an `AssignedG2Point` $P$ computes P * 2^logn
+    pub fn double_n(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        logn: usize,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let mut acc = p.clone();
+        for _ in 0..logn {
+            acc = self.double(ctx, &acc)?;
+        }
+        Ok(acc)
+    }
+
+    /// Selects a point in > 2 sized table using a window of bits in little
+    /// endian order
+    fn select_multi(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        window: &[AssignedCondition<N>],
+        table: &[AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert_eq!(table.len(), 1 << window.len());
+
+        let mut reducer = table.to_vec();
+        for (i, bit) in window.iter().enumerate() {
+            let n = 1 << (window.len() - 1 - i);
+            for j in 0..n {
+                let k = 2 * j;
+                reducer[j] = self.select(ctx, bit, &reducer[k + 1], &reducer[k])?;
+            }
+        }
+        Ok(reducer[0].clone())
+    }
+
+    /// Scalar multiplication of a point in the EC
+    ///
+    /// Performed with the fixed window algorithm where the incremental table
+    /// is offset by the auxiliary generator, which must be assigned along with
+    /// the auxiliary point for the window size with `assign_aux`. As in
+    /// `GeneralEccChip::mul` the scalar must not be zero.
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedG2Point<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(window_size > 0);
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        let to_sub = self
+            .aux_registry
+            .get(&window_size)
+            .cloned()
+            .ok_or(Error::Synthesis)?;
+
+        // Pad bits up to the next multiple of window size
+        let mut bits = self.scalar_field_chip.decompose(ctx, scalar)?;
+        let padding = (window_size - (bits.len() % window_size)) % window_size;
+        for _ in 0..padding {
+            bits.push(self.main_gate().assign_constant(ctx, N::ZERO)?);
+        }
+
+        // table[i] = aux + i * P
+        let mut table = vec![to_add];
+        for i in 0..(1 << window_size) - 1 {
+            table.push(self.add(ctx, &table[i], point)?);
+        }
+
+        // Windows are processed starting from the most significant one
+        let mut windows = bits.chunks(window_size).rev();
+        let mut acc = self.select_multi(ctx, windows.next().unwrap(), &table)?;
+        for window in windows {
+            acc = self.double_n(ctx, &acc, window_size)?;
+            let to_add = self.select_multi(ctx, window, &table)?;
+            acc = self.add(ctx, &acc, &to_add)?;
+        }
+
+        self.add(ctx, &acc, &to_sub)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::G2Chip;
+    use crate::curves::bn256::{Fr as BnScalar, G2Affine};
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::halo2;
+    use crate::halo2::halo2curves::{
+        ff::{Field, FromUniformBytes, PrimeField},
+        group::{prime::PrimeCurveAffine, Curve as _, Group},
+    };
+    use crate::integer::rns::Integer;
+    use crate::integer::{IntegerInstructions, Range};
+    use crate::maingate;
+    use crate::EccConfig;
+    use halo2::arithmetic::CurveAffine;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::mock_prover_verify;
+    use maingate::{
+        DimensionMeasurement, MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions,
+        RegionCtx,
+    };
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        fn new<N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let (rns_base, rns_scalar) =
+                G2Chip::<G2Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestG2Arithmetic<N: PrimeField> {
+        window_size: usize,
+        aux_generator: G2Affine,
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestG2Arithmetic<N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut g2_chip =
+                G2Chip::<G2Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(config.ecc_chip_config());
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    g2_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    g2_chip.assign_aux(ctx, self.window_size)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region g2",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = <G2Affine as CurveAffine>::CurveExt::random(OsRng);
+                    let b = <G2Affine as CurveAffine>::CurveExt::random(OsRng);
+                    let s = BnScalar::random(OsRng);
+
+                    let a_assigned = &g2_chip.assign_point(ctx, Value::known(a.to_affine()))?;
+                    let b_assigned = &g2_chip.assign_point(ctx, Value::known(b.to_affine()))?;
+
+                    // addition
+                    let c_0 = &g2_chip.assign_point(ctx, Value::known((a + b).to_affine()))?;
+                    let c_1 = &g2_chip.add(ctx, a_assigned, b_assigned)?;
+                    g2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // doubling
+                    let c_0 = &g2_chip.assign_point(ctx, Value::known(a.double().to_affine()))?;
+                    let c_1 = &g2_chip.double(ctx, a_assigned)?;
+                    g2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // negation
+                    let c_0 = &g2_chip.assign_point(ctx, Value::known((-a).to_affine()))?;
+                    let c_1 = &g2_chip.neg(ctx, a_assigned)?;
+                    g2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // constant generator
+                    let c_0 = &g2_chip.assign_point(ctx, Value::known(G2Affine::generator()))?;
+                    let c_1 = &g2_chip.assign_constant(ctx, G2Affine::generator())?;
+                    g2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // scalar multiplication
+                    let c_0 = &g2_chip.assign_point(ctx, Value::known((a * s).to_affine()))?;
+                    let s = Integer::from_fe(s, g2_chip.scalar_field_chip().rns());
+                    let s = &g2_chip.scalar_field_chip().assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let c_1 = &g2_chip.mul(ctx, a_assigned, s, self.window_size)?;
+                    g2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // subgroup membership
+                    let d = G2Affine::generator() * BnScalar::random(OsRng);
+                    let d_assigned = &g2_chip.assign_point(ctx, Value::known(d.to_affine()))?;
+                    g2_chip.assert_in_subgroup(ctx, d_assigned, self.window_size)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_g2_arithmetic_circuit() {
+        fn run<N: FromUniformBytes<64> + Ord>() {
+            for window_size in 1..4 {
+                let aux_generator = <G2Affine as CurveAffine>::CurveExt::random(OsRng).to_affine();
+                let circuit = TestG2Arithmetic::<N> {
+                    window_size,
+                    aux_generator,
+                    _marker: PhantomData,
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+
+        run::<BnScalar>();
+        run::<PastaFp>();
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestG2Subgroup<N: PrimeField> {
+        point: G2Affine,
+        aux_generator: G2Affine,
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestG2Subgroup<N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut g2_chip =
+                G2Chip::<G2Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(config.ecc_chip_config());
+            let window_size = 2;
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    g2_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    g2_chip.assign_aux(ctx, window_size)?;
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region g2",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let point = g2_chip.assign_point(ctx, Value::known(self.point))?;
+                    g2_chip.assert_in_subgroup(ctx, &point, window_size)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        type Fq2 = <G2Affine as CurveAffine>::Base;
+
+        // Points on the twist are out of the subgroup with overwhelming
+        // probability since the cofactor is large
+        let point = std::iter::repeat_with(|| {
+            let x = Fq2::random(OsRng);
+            let y = Option::<Fq2>::from((x.square() * x + G2Affine::b()).sqrt())?;
+            Option::<G2Affine>::from(G2Affine::from_xy(x, y))
+        })
+        .flatten()
+        .next()
+        .unwrap();
+        assert!(!bool::from(
+            (point * -BnScalar::ONE + point).to_affine().is_identity()
+        ));
+
+        let aux_generator = <G2Affine as CurveAffine>::CurveExt::random(OsRng).to_affine();
+        let circuit = |point| TestG2Subgroup::<BnScalar> {
+            point,
+            aux_generator,
+            _marker: PhantomData,
+        };
+
+        let subgroup_point = (G2Affine::generator() * BnScalar::random(OsRng)).to_affine();
+        mock_prover_verify(&circuit(subgroup_point), vec![vec![]]);
+
+        let circuit = circuit(point);
+        let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+        let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+        assert!(prover
+            .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+            .is_err());
+    }
+}
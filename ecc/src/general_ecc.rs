@@ -1,14 +1,18 @@
-use super::{make_mul_aux, AssignedPoint, EccConfig, MulAux, Point};
+use super::glv::glv_bit_len;
+use super::{
+    make_msm_aux, make_mul_aux, msm_window_size, AssignedPoint, AssignedPointOrIdentity, EccConfig,
+    MulAux, Point,
+};
 use crate::halo2;
 use crate::integer::rns::{Integer, Rns};
 use crate::integer::{IntegerChip, IntegerInstructions, Range, UnassignedInteger};
 use crate::maingate;
 use halo2::arithmetic::CurveAffine;
 use halo2::circuit::{Layouter, Value};
-use halo2::halo2curves::ff::PrimeField;
+use halo2::halo2curves::ff::{Field, PrimeField};
 use halo2::plonk::Error;
 use integer::maingate::RegionCtx;
-use maingate::{AssignedCondition, MainGate};
+use maingate::{AssignedCondition, MainGate, MainGateInstructions};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
@@ -35,8 +39,13 @@ pub struct GeneralEccChip<
         Value<Emulated>,
     )>,
     /// Auxiliary points for optimized multiplication for each (window_size,
-    /// n_pairs) pairs
-    aux_registry:
+    /// n_pairs, n_bits) triples
+    aux_registry: BTreeMap<
+        (usize, usize, usize),
+        AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    >,
+    /// Auxiliary points for `msm` for each (window_size, n_bits) pairs
+    msm_aux_registry:
         BTreeMap<(usize, usize), AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
 }
 
@@ -66,6 +75,7 @@ impl<
             scalar_field_chip: IntegerChip::new(integer_config, Rc::new(rns_scalar_field)),
             aux_generator: None,
             aux_registry: BTreeMap::new(),
+            msm_aux_registry: BTreeMap::new(),
         }
     }
 
@@ -117,18 +127,20 @@ impl<
     }
 
     /// Returns a `Point` (Rns representation) from a point in the emulated EC
+    /// Point at infinity is encoded as $(0, 0)$
     pub fn to_rns_point(
         &self,
         point: Emulated,
     ) -> Point<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-        let coords = point.coordinates();
-        // disallow point of infinity
-        // it will not pass assing point enforcement
-        let coords = coords.unwrap();
+        Point::new(self.rns_base(), point)
+    }
 
-        let x = Integer::from_fe(*coords.x(), self.rns_base());
-        let y = Integer::from_fe(*coords.y(), self.rns_base());
-        Point { x, y }
+    /// Returns emulated EC constant $a$ or `None` if it is zero
+    pub(crate) fn parameter_a(
+        &self,
+    ) -> Option<Integer<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
+        let a = Emulated::a();
+        (!bool::from(a.is_zero())).then(|| Integer::from_fe(a, self.rns_base()))
     }
 
     /// Returns emulated EC constant $b$
@@ -142,12 +154,29 @@ impl<
         window_size: usize,
         number_of_pairs: usize,
     ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        // Gets chips' aux generator
+        self.get_mul_aux_with_bits(
+            window_size,
+            number_of_pairs,
+            Emulated::ScalarExt::NUM_BITS as usize,
+        )
+    }
+
+    /// Auxilary point for optimized multiplication algorithm where scalars
+    /// are `number_of_bits` long
+    fn get_mul_aux_with_bits(
+        &self,
+        window_size: usize,
+        number_of_pairs: usize,
+        number_of_bits: usize,
+    ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let to_add = match self.aux_generator.clone() {
             Some((assigned, _)) => Ok(assigned),
             None => Err(Error::Synthesis),
         }?;
-        let to_sub = match self.aux_registry.get(&(window_size, number_of_pairs)) {
+        let to_sub = match self
+            .aux_registry
+            .get(&(window_size, number_of_pairs, number_of_bits))
+        {
             Some(aux) => Ok(aux.clone()),
             None => Err(Error::Synthesis),
         }?;
@@ -155,6 +184,24 @@ impl<
         // see https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
         Ok(MulAux::new(to_add, to_sub))
     }
+
+    /// Auxilary points for `msm` where `to_add` is the initial value of the
+    /// first bucket and `to_sub` cancels the offset of all buckets
+    fn get_msm_aux(
+        &self,
+        window_size: usize,
+        number_of_bits: usize,
+    ) -> Result<MulAux<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let to_add = match self.aux_generator.clone() {
+            Some((assigned, _)) => Ok(assigned),
+            None => Err(Error::Synthesis),
+        }?;
+        let to_sub = match self.msm_aux_registry.get(&(window_size, number_of_bits)) {
+            Some(aux) => Ok(aux.clone()),
+            None => Err(Error::Synthesis),
+        }?;
+        Ok(MulAux::new(to_add, to_sub))
+    }
 }
 
 impl<
@@ -171,7 +218,6 @@ impl<
         point: AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         offset: usize,
     ) -> Result<(), Error> {
-        use integer::maingate::MainGateInstructions;
         let main_gate = self.main_gate();
 
         let mut offset = offset;
@@ -186,6 +232,17 @@ impl<
         Ok(())
     }
 
+    /// Expose `AssignedPointOrIdentity` as Public Input
+    /// Point at infinity is exposed as all zero limbs
+    pub fn expose_public_point_or_identity(
+        &self,
+        layouter: impl Layouter<N>,
+        point: AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        self.expose_public(layouter, point.point, offset)
+    }
+
     /// Takes `Point` and assign its coordiantes as constant
     /// Returned as `AssignedPoint`
     pub fn assign_constant(
@@ -203,7 +260,10 @@ impl<
         Ok(AssignedPoint::new(x, y))
     }
 
-    /// Takes `Point` of the EC and returns it as `AssignedPoint`
+    /// Takes `Point` of the EC and returns it as `AssignedPoint`. Point at
+    /// infinity is encoded as $(0, 0)$ which fails the on curve check, so
+    /// points that can be the identity must be assigned with
+    /// [`GeneralEccChip::assign_point_or_identity`]
     pub fn assign_point(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -224,6 +284,63 @@ impl<
         Ok(point)
     }
 
+    /// Takes a point of the EC that can be the point at infinity and returns
+    /// it as `AssignedPointOrIdentity`
+    pub fn assign_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<Emulated>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let integer_chip = self.base_field_chip();
+        let main_gate = self.main_gate();
+
+        let is_identity = point.map(|point| {
+            if bool::from(point.is_identity()) {
+                N::ONE
+            } else {
+                N::ZERO
+            }
+        });
+        let is_identity = main_gate.assign_bit(ctx, is_identity)?;
+
+        let point = point.map(|point| self.to_rns_point(point));
+        let (x, y) = point
+            .map(|point| (point.x().clone(), point.y().clone()))
+            .unzip();
+        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+        let y = integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
+        let point = AssignedPoint::new(x, y);
+
+        // Point must be on curve unless it is the point at infinity. In order
+        // to keep the constraint uniform identity is replaced with the
+        // generator
+        let not_identity = &main_gate.not(ctx, &is_identity)?;
+        let to_check = self.select_or_assign(ctx, not_identity, &point, Emulated::generator())?;
+        self.assert_is_on_curve(ctx, &to_check)?;
+
+        // Point at infinity must be encoded as (0, 0)
+        let zero = &integer_chip.assign_constant(ctx, Emulated::Base::ZERO)?;
+        let x = &integer_chip.select(ctx, point.x(), zero, &is_identity)?;
+        let y = &integer_chip.select(ctx, point.y(), zero, &is_identity)?;
+        integer_chip.assert_zero(ctx, x)?;
+        integer_chip.assert_zero(ctx, y)?;
+
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
+    /// Converts an `AssignedPoint` which is known not to be the point at
+    /// infinity to `AssignedPointOrIdentity`
+    pub fn to_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let is_identity = self.main_gate().assign_constant(ctx, N::ZERO)?;
+        Ok(AssignedPointOrIdentity::new(point.clone(), is_identity))
+    }
+
     /// Assigns the auxiliary generator point
     pub fn assign_aux_generator(
         &mut self,
@@ -242,13 +359,69 @@ impl<
         ctx: &mut RegionCtx<'_, N>,
         window_size: usize,
         number_of_pairs: usize,
+    ) -> Result<(), Error> {
+        self.assign_aux_with_bits(
+            ctx,
+            window_size,
+            number_of_pairs,
+            Emulated::ScalarExt::NUM_BITS as usize,
+        )
+    }
+
+    /// Assigns multiplication auxiliary point for GLV multiplication with
+    /// given window size and number of pairs before the scalars are split
+    pub fn assign_aux_glv(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+        number_of_pairs: usize,
+    ) -> Result<(), Error> {
+        self.assign_aux_with_bits(
+            ctx,
+            window_size,
+            2 * number_of_pairs,
+            glv_bit_len::<Emulated::ScalarExt>(),
+        )
+    }
+
+    /// Assigns multiplication auxiliary point for `msm`
+    /// of given number of points
+    pub fn assign_aux_msm(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        number_of_points: usize,
+    ) -> Result<(), Error> {
+        let number_of_bits = Emulated::ScalarExt::NUM_BITS as usize;
+        let window_size = msm_window_size(number_of_points, number_of_bits);
+        match self.aux_generator {
+            Some((_, point)) => {
+                let aux = point.map(|point| make_msm_aux(point, window_size, number_of_bits));
+                let aux = self.assign_point(ctx, aux)?;
+                self.msm_aux_registry
+                    .insert((window_size, number_of_bits), aux);
+                Ok(())
+            }
+            // aux generator is not assigned yet
+            None => Err(Error::Synthesis),
+        }
+    }
+
+    /// Assigns multiplication auxiliary point for a triple of (window_size,
+    /// n_pairs, n_bits)
+    fn assign_aux_with_bits(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        window_size: usize,
+        number_of_pairs: usize,
+        number_of_bits: usize,
     ) -> Result<(), Error> {
         match self.aux_generator {
             Some((_, point)) => {
-                let aux = point.map(|point| make_mul_aux(point, window_size, number_of_pairs));
+                let aux = point
+                    .map(|point| make_mul_aux(point, window_size, number_of_pairs, number_of_bits));
                 let aux = self.assign_point(ctx, aux)?;
                 self.aux_registry
-                    .insert((window_size, number_of_pairs), aux);
+                    .insert((window_size, number_of_pairs, number_of_bits), aux);
                 Ok(())
             }
             // aux generator is not assigned yet
@@ -266,6 +439,12 @@ impl<
 
         let y_square = &integer_chip.square(ctx, point.y())?;
         let x_square = &integer_chip.square(ctx, point.x())?;
+        // x^3 + a * x = (x^2 + a) * x so that non zero $a$ costs only a
+        // constant addition
+        let x_square = &match self.parameter_a() {
+            Some(a) => integer_chip.add_constant(ctx, x_square, &a)?,
+            None => x_square.clone(),
+        };
         let x_cube = &integer_chip.mul(ctx, point.x(), x_square)?;
         let x_cube_b = &integer_chip.add_constant(ctx, x_cube, &self.parameter_b())?;
         integer_chip.assert_equal(ctx, x_cube_b, y_square)?;
@@ -298,6 +477,37 @@ impl<
         Ok(AssignedPoint::new(x, y))
     }
 
+    /// Constraints assert two `AssignedPointOrIdentity`s are equal
+    pub fn assert_equal_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        // Coordinates of the point at infinity are fixed to (0, 0) so that
+        // comparing coordinates along with the flag is enough
+        self.main_gate()
+            .assert_equal(ctx, &p0.is_identity, &p1.is_identity)?;
+        self.assert_equal(ctx, &p0.point, &p1.point)
+    }
+
+    /// Selects between 2 `AssignedPointOrIdentity` determined by an
+    /// `AssignedCondition`
+    pub fn select_point_or_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let point = self.select(ctx, c, &p1.point, &p2.point)?;
+        let is_identity = self
+            .main_gate()
+            .select(ctx, &p1.is_identity, &p2.is_identity, c)?;
+        Ok(AssignedPointOrIdentity::new(point, is_identity))
+    }
+
     /// Selects between an `AssignedPoint` and a point on the EC `Emulated`
     /// determined by an `AssignedCondition`
     pub fn select_or_assign(
@@ -353,6 +563,28 @@ impl<
         self._double_incomplete(ctx, p)
     }
 
+    /// Adds 2 `AssignedPointOrIdentity`s. Unlike `add` operands can be equal
+    /// or opposite of each other and any of them can be the point at infinity
+    pub fn add_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        self._add_complete(ctx, p0, p1)
+    }
+
+    /// Doubles an `AssignedPointOrIdentity`
+    pub fn double_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        self._double_complete(ctx, p)
+    }
+
     /// Given an `AssignedPoint` $P$ computes P * 2^logn
     pub fn double_n(
         &self,
@@ -388,6 +620,15 @@ impl<
         let y_neg = integer_chip.neg(ctx, p.y())?;
         Ok(AssignedPoint::new(p.x().clone(), y_neg))
     }
+
+    /// Returns sign of the assigned point
+    pub fn sign(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        self.base_field_chip().sign(ctx, p.y())
+    }
 }
 
 #[cfg(test)]
@@ -398,21 +639,24 @@ mod tests {
     use super::{AssignedPoint, EccConfig, GeneralEccChip, Point};
     use crate::halo2;
     use crate::halo2::halo2curves::{
-        ff::{Field, FromUniformBytes, PrimeField},
+        ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup},
         group::{prime::PrimeCurveAffine, Curve as _, Group},
     };
     use crate::integer::rns::Rns;
     use crate::integer::NUMBER_OF_LOOKUP_LIMBS;
     use crate::integer::{AssignedInteger, IntegerInstructions};
     use crate::maingate;
-    use halo2::arithmetic::CurveAffine;
+    use crate::mul_fixed_doubling_scalar;
+    use halo2::arithmetic::{best_multiexp, CurveAffine};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
     use integer::rns::Integer;
     use integer::Range;
     use maingate::mock_prover_verify;
     use maingate::{
-        MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        DimensionMeasurement, MainGateBuilder, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions, RegionCtx,
     };
     use paste::paste;
     use rand_core::OsRng;
@@ -422,6 +666,7 @@ mod tests {
         EpAffine as Pallas, EqAffine as Vesta, Fp as PastaFp, Fq as PastaFq,
     };
     use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+    use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
 
     const NUMBER_OF_LIMBS: usize = 4;
     const BIT_LEN_LIMB: usize = 68;
@@ -471,11 +716,26 @@ mod tests {
             const BIT_LEN_LIMB: usize,
         >(
             meta: &mut ConstraintSystem<N>,
+        ) -> Self {
+            Self::new_with_main_gate_builder::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(
+                meta,
+                MainGateBuilder::default(),
+            )
+        }
+
+        fn new_with_main_gate_builder<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >(
+            meta: &mut ConstraintSystem<N>,
+            main_gate_builder: MainGateBuilder,
         ) -> Self {
             let (rns_base, rns_scalar) =
                 GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
 
-            let main_gate_config = MainGate::<N>::configure(meta);
+            let main_gate_config = main_gate_builder.configure(meta);
             let mut overflow_bit_lens: Vec<usize> = vec![];
             overflow_bit_lens.extend(rns_base.overflow_lengths());
             overflow_bit_lens.extend(rns_scalar.overflow_lengths());
@@ -622,6 +882,130 @@ mod tests {
         run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
         run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
         run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct TestEccCompleteAddition<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        _marker: PhantomData<(C, N)>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccCompleteAddition<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = C::Curve::random(OsRng);
+                    let b = C::Curve::random(OsRng);
+                    let identity = C::Curve::identity();
+
+                    // test addition including exceptional cases
+                    for (p0, p1) in [
+                        (a, b),
+                        (a, a),
+                        (a, -a),
+                        (identity, b),
+                        (a, identity),
+                        (identity, identity),
+                    ] {
+                        let expected = p0 + p1;
+                        let p0 = ecc_chip.assign_point_or_identity(ctx, Value::known(p0.into()))?;
+                        let p1 = ecc_chip.assign_point_or_identity(ctx, Value::known(p1.into()))?;
+                        let expected = ecc_chip
+                            .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                        let result = ecc_chip.add_complete(ctx, &p0, &p1)?;
+                        ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                    }
+
+                    // test doubling including the identity
+                    for p in [a, identity] {
+                        let expected = p + p;
+                        let p = ecc_chip.assign_point_or_identity(ctx, Value::known(p.into()))?;
+                        let expected = ecc_chip
+                            .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                        let result = ecc_chip.double_complete(ctx, &p)?;
+                        ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_complete_addition_circuit() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let circuit = TestEccCompleteAddition::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::default();
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
     }
 
     #[derive(Default, Clone, Debug)]
@@ -748,14 +1132,13 @@ mod tests {
     }
 
     #[derive(Default, Clone, Debug)]
-    struct TestEccMul<
+    struct TestEccPointOrIdentity<
         C: CurveAffine,
         N: PrimeField,
         const NUMBER_OF_LIMBS: usize,
         const BIT_LEN_LIMB: usize,
     > {
-        window_size: usize,
-        aux_generator: C,
+        point: Value<C>,
         _marker: PhantomData<N>,
     }
 
@@ -764,7 +1147,7 @@ mod tests {
             N: PrimeField,
             const NUMBER_OF_LIMBS: usize,
             const BIT_LEN_LIMB: usize,
-        > Circuit<N> for TestEccMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+        > Circuit<N> for TestEccPointOrIdentity<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
     {
         type Config = TestCircuitConfig;
         type FloorPlanner = SimpleFloorPlanner;
@@ -785,48 +1168,39 @@ mod tests {
             mut layouter: impl Layouter<N>,
         ) -> Result<(), Error> {
             let ecc_chip_config = config.ecc_chip_config();
-            let mut ecc_chip =
+            let ecc_chip =
                 GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let main_gate = ecc_chip.main_gate();
 
-            layouter.assign_region(
-                || "assign aux values",
-                |region| {
-                    let offset = 0;
-                    let ctx = &mut RegionCtx::new(region, offset);
-                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
-                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
-                    ecc_chip.get_mul_aux(self.window_size, 1)?;
-                    Ok(())
-                },
-            )?;
-
-            let scalar_chip = ecc_chip.scalar_field_chip();
-
-            layouter.assign_region(
-                || "region mul",
+            let point = layouter.assign_region(
+                || "region 0",
                 |region| {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
 
-                    let base = C::Curve::random(OsRng);
-                    let s = C::Scalar::random(OsRng);
-                    let result = base * s;
-
-                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
-                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
-                    let s = scalar_chip.assign_integer(
-                        ctx,
-                        Value::known(s).into(),
-                        Range::Remainder,
-                    )?;
-                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
-
-                    let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
-                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    let a = C::Curve::random(OsRng);
+                    let identity = C::Curve::identity();
+
+                    for (p1, p2) in [(a, identity), (identity, a), (identity, identity)] {
+                        for cond in [true, false] {
+                            let expected = if cond { p1 } else { p2 };
+                            let p1 =
+                                ecc_chip.assign_point_or_identity(ctx, Value::known(p1.into()))?;
+                            let p2 =
+                                ecc_chip.assign_point_or_identity(ctx, Value::known(p2.into()))?;
+                            let expected = ecc_chip
+                                .assign_point_or_identity(ctx, Value::known(expected.into()))?;
+                            let cond =
+                                main_gate.assign_bit(ctx, Value::known(N::from(cond as u64)))?;
+                            let result = ecc_chip.select_point_or_identity(ctx, &cond, &p1, &p2)?;
+                            ecc_chip.assert_equal_point_or_identity(ctx, &expected, &result)?;
+                        }
+                    }
 
-                    Ok(())
+                    ecc_chip.assign_point_or_identity(ctx, self.point)
                 },
             )?;
+            ecc_chip.expose_public_point_or_identity(layouter.namespace(|| "point"), point, 0)?;
 
             config.config_range(&mut layouter)?;
 
@@ -835,23 +1209,529 @@ mod tests {
     }
 
     #[test]
-    fn test_general_ecc_mul_circuit() {
+    fn test_general_ecc_point_or_identity() {
         fn run<
             C: CurveAffine,
             N: FromUniformBytes<64> + Ord,
             const NUMBER_OF_LIMBS: usize,
             const BIT_LEN_LIMB: usize,
         >() {
-            for window_size in 1..5 {
-                let aux_generator = C::Curve::random(OsRng).to_affine();
+            let (rns_base, _, _) = setup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(0);
+            let rns_base = Rc::new(rns_base);
 
-                let circuit = TestEccMul::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-                    aux_generator,
-                    window_size,
+            for point in [C::Curve::random(OsRng).to_affine(), C::identity()] {
+                let public_data = Point::new(Rc::clone(&rns_base), point).public();
+                let circuit = TestEccPointOrIdentity::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    point: Value::known(point),
                     ..Default::default()
                 };
-                let instance = vec![vec![]];
-                mock_prover_verify(&circuit, instance);
+                mock_prover_verify(&circuit, vec![public_data]);
+            }
+
+            // public input of the identity is all zero limbs
+            let point = C::Curve::random(OsRng).to_affine();
+            let public_data = Point::new(Rc::clone(&rns_base), point).public();
+            let circuit = TestEccPointOrIdentity::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                point: Value::known(C::identity()),
+                ..Default::default()
+            };
+            let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+            let prover = MockProver::run(dimension.k(), &circuit, vec![public_data]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMul<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+        const DOUBLE_MUL: bool = true,
+        const POW: u64 = 0,
+    > {
+        window_size: usize,
+        aux_generator: C,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+            const DOUBLE_MUL: bool,
+            const POW: u64,
+        > Circuit<N> for TestEccMul<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, DOUBLE_MUL, POW>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let builder = MainGateBuilder::default().double_mul(DOUBLE_MUL);
+            let builder = if POW > 1 { builder.pow(POW) } else { builder };
+            TestCircuitConfig::new_with_main_gate_builder::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(
+                meta, builder,
+            )
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
+                    ecc_chip.get_mul_aux(self.window_size, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let s = C::Scalar::random(OsRng);
+                    let result = base * s;
+
+                    let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                    let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(s).into(),
+                        Range::Remainder,
+                    )?;
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                    let result_1 = ecc_chip.mul(ctx, &base, &s, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_circuit() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for window_size in 1..5 {
+                let aux_generator = C::Curve::random(OsRng).to_affine();
+
+                let circuit = TestEccMul::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    aux_generator,
+                    window_size,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256r1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256r1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[test]
+    fn test_general_ecc_mul_row_usage_per_gate_shape() {
+        let window_size = 4;
+        let aux_generator = <Secp256k1 as CurveAffine>::CurveExt::random(OsRng).to_affine();
+        let single_mul = TestEccMul::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB, false> {
+            aux_generator,
+            window_size,
+            ..Default::default()
+        };
+        let double_mul = TestEccMul::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB, true> {
+            aux_generator,
+            window_size,
+            ..Default::default()
+        };
+        let pow = TestEccMul::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB, true, 5> {
+            aux_generator,
+            window_size,
+            ..Default::default()
+        };
+        mock_prover_verify(&single_mul, vec![vec![]]);
+        mock_prover_verify(&pow, vec![vec![]]);
+
+        let single_mul = DimensionMeasurement::measure(&single_mul)
+            .unwrap()
+            .advice_rows();
+        let double_mul = DimensionMeasurement::measure(&double_mul)
+            .unwrap()
+            .advice_rows();
+        let pow = DimensionMeasurement::measure(&pow).unwrap().advice_rows();
+        // power term is not used by the ECC chip
+        assert_eq!(pow, double_mul);
+        // every window selects from the table of `2^window_size` points with
+        // `2^window_size - 1` point selections, and selection of each limb and
+        // the native value of both coordinates takes an extra row without the
+        // second multiplication
+        let number_of_windows =
+            <Secp256k1 as CurveAffine>::ScalarExt::NUM_BITS as u64 / window_size as u64;
+        let point_selections = number_of_windows * ((1 << window_size) - 1);
+        let main_gate_selections = point_selections * 2 * (NUMBER_OF_LIMBS as u64 + 1);
+        assert_eq!(single_mul, double_mul + main_gate_selections);
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulFixed<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        window_size: usize,
+        scalars: Vec<C::Scalar>,
+        or_identity: bool,
+        _marker: PhantomData<(C, N)>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccMulFixed<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul fixed",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng).to_affine();
+                    for s in self.scalars.iter() {
+                        let result = base * *s;
+
+                        let s = Integer::from_fe(*s, ecc_chip.rns_scalar());
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        if self.or_identity {
+                            let result_1 =
+                                ecc_chip.mul_fixed_or_identity(ctx, base, &s, self.window_size)?;
+                            let result_0 = ecc_chip
+                                .assign_point_or_identity(ctx, Value::known(result.into()))?;
+                            ecc_chip.assert_equal_point_or_identity(ctx, &result_0, &result_1)?;
+                            continue;
+                        }
+
+                        let result_1 = ecc_chip.mul_fixed(ctx, base, &s, self.window_size)?;
+
+                        if !bool::from(result.is_identity()) {
+                            let result_0 =
+                                ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                            ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_fixed_circuit() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for window_size in 3..6 {
+                let scalars = vec![
+                    C::Scalar::random(OsRng),
+                    C::Scalar::ONE,
+                    -C::Scalar::ONE,
+                    mul_fixed_doubling_scalar(window_size),
+                ];
+                let circuit = TestEccMulFixed::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    scalars,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+
+                // zero scalar is rejected
+                let circuit = TestEccMulFixed::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    scalars: vec![C::Scalar::ZERO],
+                    ..Default::default()
+                };
+                let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+                let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+                assert!(prover
+                    .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                    .is_err());
+
+                // zero scalar results in the identity
+                let circuit = TestEccMulFixed::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    window_size,
+                    scalars: vec![C::Scalar::ZERO, C::Scalar::random(OsRng)],
+                    or_identity: true,
+                    ..Default::default()
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Pallas, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Vesta, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[test]
+    fn test_general_ecc_mul_fixed_small_window() {
+        // Sums of lower windows may exceed the order of Pasta curves with
+        // window size 2
+        let circuit = TestEccMulFixed::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            window_size: 2,
+            scalars: vec![PastaFq::random(OsRng)],
+            ..Default::default()
+        };
+        assert!(matches!(
+            DimensionMeasurement::measure(&circuit),
+            Err(Error::Synthesis)
+        ));
+
+        let circuit = TestEccMulFixed::<Bn256, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            window_size: 2,
+            scalars: vec![BnScalar::random(OsRng), mul_fixed_doubling_scalar(2)],
+            ..Default::default()
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMulGlv<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        window_size: usize,
+        number_of_pairs: usize,
+        aux_generator: C,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccMulGlv<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_glv(ctx, self.window_size, 1)?;
+                    ecc_chip.assign_aux_glv(ctx, self.window_size, self.number_of_pairs)?;
+                    Ok(())
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region mul glv",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let base = C::Curve::random(OsRng);
+                    let scalars = [
+                        C::Scalar::random(OsRng),
+                        C::Scalar::ONE,
+                        -C::Scalar::ONE,
+                        C::Scalar::ZETA,
+                    ];
+                    for s in scalars {
+                        let result = base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+
+                        let result_1 = ecc_chip.mul_glv(ctx, &base, &s, self.window_size)?;
+                        ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+                    }
+
+                    let mut pairs = vec![];
+                    let mut result = C::Curve::identity();
+                    for _ in 0..self.number_of_pairs {
+                        let base = C::Curve::random(OsRng);
+                        let s = C::Scalar::random(OsRng);
+                        result += base * s;
+
+                        let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                        let base = ecc_chip.assign_point(ctx, Value::known(base.into()))?;
+                        let s = scalar_chip.assign_integer(
+                            ctx,
+                            Value::known(s).into(),
+                            Range::Remainder,
+                        )?;
+                        pairs.push((base, s));
+                    }
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 =
+                        ecc_chip.mul_batch_1d_horizontal_glv(ctx, pairs, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_mul_glv_circuit() {
+        fn run<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            for window_size in 1..5 {
+                let aux_generator = C::Curve::random(OsRng).to_affine();
+
+                let circuit = TestEccMulGlv::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                    aux_generator,
+                    window_size,
+                    number_of_pairs: 3,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
             }
         }
 
@@ -1009,4 +1889,138 @@ mod tests {
     test_general_ecc_mul_batch_circuit!(Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
     test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
     test_general_ecc_mul_batch_circuit!(Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccMsm<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        aux_generator: C,
+        number_of_points: usize,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccMsm<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let mut ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux_msm(ctx, self.number_of_points)?;
+                    Ok(())
+                },
+            )?;
+
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region msm",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let bases: Vec<C> = (0..self.number_of_points)
+                        .map(|_| C::Curve::random(OsRng).to_affine())
+                        .collect();
+                    let scalars: Vec<C::Scalar> = (0..self.number_of_points)
+                        .map(|_| C::Scalar::random(OsRng))
+                        .collect();
+                    let result = best_multiexp(&scalars, &bases);
+
+                    let pairs = bases
+                        .into_iter()
+                        .zip(scalars)
+                        .map(|(base, s)| {
+                            let s = Integer::from_fe(s, ecc_chip.rns_scalar());
+                            let base = ecc_chip.assign_point(ctx, Value::known(base))?;
+                            let s = scalar_chip.assign_integer(
+                                ctx,
+                                Value::known(s).into(),
+                                Range::Remainder,
+                            )?;
+                            Ok((base, s))
+                        })
+                        .collect::<Result<_, Error>>()?;
+
+                    let result_0 = ecc_chip.assign_point(ctx, Value::known(result.into()))?;
+                    let result_1 = ecc_chip.msm(ctx, pairs)?;
+                    ecc_chip.assert_equal(ctx, &result_0, &result_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    macro_rules! test_general_ecc_msm_circuit {
+        ($C:ty, $N:ty, $NUMBER_OF_LIMBS:expr, $BIT_LEN_LIMB:expr) => {
+            paste! {
+                #[test]
+                fn [<test_general_ecc_msm_circuit_ $C:lower _ $N:lower>]() {
+                    for number_of_points in [1, 2, 3, 5] {
+                        let aux_generator = <$C as PrimeCurveAffine>::Curve::random(OsRng).to_affine();
+
+                        let circuit = TestEccMsm::<$C, $N, $NUMBER_OF_LIMBS, $BIT_LEN_LIMB> {
+                            aux_generator,
+                            number_of_points,
+                            ..Default::default()
+                        };
+                        let instance = vec![vec![]];
+                        mock_prover_verify(&circuit, instance);
+                    }
+                }
+            }
+        }
+    }
+
+    test_general_ecc_msm_circuit!(Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_msm_circuit!(Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+    test_general_ecc_msm_circuit!(Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
+
+    #[test]
+    fn test_general_ecc_msm_circuit_128() {
+        let aux_generator = <Secp256k1 as PrimeCurveAffine>::Curve::random(OsRng).to_affine();
+        let circuit = TestEccMsm::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            aux_generator,
+            number_of_points: 128,
+            ..Default::default()
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
 }
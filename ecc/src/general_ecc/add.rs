@@ -1,11 +1,11 @@
-use super::AssignedPoint;
 use super::GeneralEccChip;
+use super::{AssignedPoint, AssignedPointOrIdentity};
 use crate::halo2;
 use halo2::arithmetic::CurveAffine;
 use halo2::halo2curves::ff::PrimeField;
 use halo2::plonk::Error;
-use integer::maingate::RegionCtx;
-use integer::IntegerInstructions;
+use integer::maingate::{MainGateInstructions, RegionCtx};
+use integer::{AssignedInteger, IntegerInstructions};
 
 impl<
         Emulated: CurveAffine,
@@ -14,6 +14,24 @@ impl<
         const BIT_LEN_LIMB: usize,
     > GeneralEccChip<Emulated, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
+    /// Returns numerator of the tangent slope $3 * x^2 + a$ given $x^2$
+    /// Constant term is skipped for $a = 0$ and any other $a$ costs a single
+    /// constant addition. There is no separate path for $a = -3$: in affine
+    /// coordinates $a$ is never multiplied and $3 * (x - 1) * (x + 1)$ would
+    /// trade the square for a multiplication, so $-3$ takes the general path
+    fn tangent_numerator(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x_square: &AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.base_field_chip();
+        let numerator = ch.mul3(ctx, x_square)?;
+        match self.parameter_a() {
+            Some(a) => ch.add_constant(ctx, &numerator, &a),
+            None => Ok(numerator),
+        }
+    }
+
     /// Optimized point addition algorithm
     ///
     /// The operands `a` and `b` must be distinct and neither
@@ -56,9 +74,9 @@ impl<
     ) -> Result<AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
         let ch = self.base_field_chip();
 
-        // lambda = (3 * a_x^2) / 2 * a_y
+        // lambda = (3 * a_x^2 + a) / 2 * a_y
         let x_0_square = &ch.square(ctx, &point.x)?;
-        let numerator = &ch.mul3(ctx, x_0_square)?;
+        let numerator = &self.tangent_numerator(ctx, x_0_square)?;
         let denominator = &ch.mul2(ctx, &point.y)?;
         let lambda = &ch.div_incomplete(ctx, numerator, denominator)?;
 
@@ -74,6 +92,97 @@ impl<
         Ok(AssignedPoint::new(x.clone(), y))
     }
 
+    /// Exception-free point addition
+    ///
+    /// Operands can be equal or opposite of each other and any of them can be
+    /// the point at infinity. Point at infinity in the result is encoded as
+    /// $(0, 0)$.
+    pub(crate) fn _add_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        let (a_is_identity, b_is_identity) = (&a.is_identity, &b.is_identity);
+        let (a, b) = (&a.point, &b.point);
+        let ch = self.base_field_chip();
+        let main_gate = self.main_gate();
+
+        // Replace identity operands with the generator so that all denominators
+        // below are non zero. Result of this branch is discarded by the final
+        // selection anyway.
+        let not_a_is_identity = &main_gate.not(ctx, a_is_identity)?;
+        let not_b_is_identity = &main_gate.not(ctx, b_is_identity)?;
+        let a_safe = &self.select_or_assign(ctx, not_a_is_identity, a, Emulated::generator())?;
+        let b_safe = &self.select_or_assign(ctx, not_b_is_identity, b, Emulated::generator())?;
+
+        // x_eq = a_x == b_x, y_eq = a_y == b_y
+        let dx = &ch.sub(ctx, &b_safe.x, &a_safe.x)?;
+        let dy = &ch.sub(ctx, &b_safe.y, &a_safe.y)?;
+        let (_, x_eq) = ch.invert(ctx, dx)?;
+        let (_, y_eq) = ch.invert(ctx, dy)?;
+
+        // lambda = (3 * a_x^2 + a) / (2 * a_y) if x_eq else (b_y - a_y) / (b_x - a_x)
+        // Since curves of prime order have no points with y = 0 denominator is
+        // never zero
+        let a_x_square = &ch.square(ctx, &a_safe.x)?;
+        let numerator_double = &self.tangent_numerator(ctx, a_x_square)?;
+        let denominator_double = &ch.mul2(ctx, &a_safe.y)?;
+        let numerator = &ch.select(ctx, numerator_double, dy, &x_eq)?;
+        let denominator = &ch.select(ctx, denominator_double, dx, &x_eq)?;
+        let lambda = &ch.div_incomplete(ctx, numerator, denominator)?;
+
+        // c_x =  lambda * lambda - a_x - b_x
+        let lambda_square = &ch.square(ctx, lambda)?;
+        let x = ch.sub_sub(ctx, lambda_square, &a_safe.x, &b_safe.x)?;
+
+        // c_y = lambda * (a_x - c_x) - a_y
+        let t = &ch.sub(ctx, &a_safe.x, &x)?;
+        let t = &ch.mul(ctx, t, lambda)?;
+        let y = ch.sub(ctx, t, &a_safe.y)?;
+        let sum = &AssignedPoint::new(x, y);
+
+        // result = b if a is identity else a if b is identity else a + b
+        let result = &self.select(ctx, b_is_identity, a, sum)?;
+        let result = self.select(ctx, a_is_identity, b, result)?;
+
+        // result is identity if both operands are identity or if none of them
+        // is identity and a == -b
+        let not_y_eq = &main_gate.not(ctx, &y_eq)?;
+        let is_opposite = &main_gate.and(ctx, &x_eq, not_y_eq)?;
+        let is_opposite = &main_gate.and(ctx, not_b_is_identity, is_opposite)?;
+        let is_identity = main_gate.select(ctx, b_is_identity, is_opposite, a_is_identity)?;
+
+        // Point at infinity is always encoded as (0, 0)
+        let not_identity = &main_gate.not(ctx, &is_identity)?;
+        let result = self.select_or_assign(ctx, not_identity, &result, Emulated::identity())?;
+
+        Ok(AssignedPointOrIdentity::new(result, is_identity))
+    }
+
+    /// Exception-free point doubling
+    ///
+    /// Input can be the point at infinity which is the only case when the
+    /// result is the point at infinity on a prime order curve.
+    pub(crate) fn _double_complete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPointOrIdentity<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>
+    {
+        // Identity is replaced with the generator to keep the denominator non
+        // zero, then the result is replaced back with the input which is
+        // already encoded as (0, 0)
+        let is_identity = &point.is_identity;
+        let not_identity = &self.main_gate().not(ctx, is_identity)?;
+        let point_safe =
+            &self.select_or_assign(ctx, not_identity, &point.point, Emulated::generator())?;
+        let doubled = &self._double_incomplete(ctx, point_safe)?;
+        let result = self.select(ctx, is_identity, &point.point, doubled)?;
+        Ok(AssignedPointOrIdentity::new(result, is_identity.clone()))
+    }
+
     /// Given 2 `AssignedPoint` $P$ and $Q$ efficiently computes $2*P + Q$
     ///
     /// see: https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
@@ -0,0 +1,116 @@
+use crate::halo2::arithmetic::CurveAffine;
+use crate::halo2::halo2curves::ff::{Field, PrimeField, WithSmallOrderMulGroup};
+use crate::halo2::halo2curves::group::Curve;
+use crate::maingate::{fe_to_big, modulus};
+use num_bigint::{BigInt, BigUint as big_uint};
+use num_integer::Integer as _;
+use num_traits::{One, Signed, Zero};
+
+/// Finds endomorphism constants $(\beta, \lambda)$ of the curve such that
+/// $(\beta * x, y) = \lambda * (x, y)$ for every point on the curve.
+///
+/// Candidates are cube roots of unity in the base and scalar fields. Returns
+/// `None` if the curve does not have such an endomorphism, for example if
+/// parameter $a$ is not zero.
+pub(crate) fn endomorphism<C: CurveAffine>() -> Option<(C::Base, C::Scalar)> {
+    let generator = C::generator();
+    let coords = generator.coordinates().unwrap();
+    let lambda = C::Scalar::ZETA;
+    let expected = (generator * lambda).to_affine();
+    [C::Base::ZETA, C::Base::ZETA.square()]
+        .into_iter()
+        .find(|beta| {
+            let candidate = C::from_xy(*coords.x() * beta, *coords.y());
+            bool::from(candidate.is_some()) && candidate.unwrap() == expected
+        })
+        .map(|beta| (beta, lambda))
+}
+
+/// Bit length of the scalars after GLV decomposition. Includes a small
+/// margin over the half of the scalar size.
+pub(crate) fn glv_bit_len<F: PrimeField>() -> usize {
+    (F::NUM_BITS as usize + 1) / 2 + 2
+}
+
+/// Finds a short basis $(a_1, b_1), (a_2, b_2)$ of the lattice
+/// $\{(x, y) | x + y * \lambda = 0 \mod n\}$ with the extended euclidean
+/// algorithm, see Algorithm 3.74 in Guide to Elliptic Curve Cryptography.
+fn lattice_basis(n: &BigInt, lambda: &BigInt) -> [(BigInt, BigInt); 2] {
+    let sqrt_n = n.sqrt();
+
+    // Invariant: r_i = s_i * n + t_i * lambda
+    let (mut r_0, mut r_1) = (n.clone(), lambda.clone());
+    let (mut t_0, mut t_1) = (BigInt::zero(), BigInt::one());
+    while r_1 >= sqrt_n {
+        let q = &r_0 / &r_1;
+        let r_2 = &r_0 - &q * &r_1;
+        let t_2 = &t_0 - &q * &t_1;
+        (r_0, r_1) = (r_1, r_2);
+        (t_0, t_1) = (t_1, t_2);
+    }
+    // r_0 is the last remainder that is not less than sqrt(n)
+    let q = &r_0 / &r_1;
+    let r_2 = &r_0 - &q * &r_1;
+    let t_2 = &t_0 - &q * &t_1;
+
+    let norm = |v: &(BigInt, BigInt)| &v.0 * &v.0 + &v.1 * &v.1;
+    let v_1 = (r_1, -t_1);
+    let (v_2, v_3) = ((r_0, -t_0), (r_2, -t_2));
+    let v_2 = if norm(&v_2) <= norm(&v_3) { v_2 } else { v_3 };
+    [v_1, v_2]
+}
+
+/// Decomposes scalar $k$ into $k_1$ and $k_2$ such that
+/// $k = k_1 + k_2 * \lambda \mod n$ where both $k_1$ and $k_2$ are about the
+/// half size of $n$. Returns absolute values along with signs as `true` for
+/// negative values. This is only a witness, callers must constrain that the
+/// parts recompose to $k$ and fit in `glv_bit_len` bits.
+pub(crate) fn decompose_scalar<F: PrimeField>(k: F, lambda: F) -> [(big_uint, bool); 2] {
+    let n: BigInt = modulus::<F>().into();
+    let [(a_1, b_1), (a_2, b_2)] = lattice_basis(&n, &fe_to_big(lambda).into());
+    let k: BigInt = fe_to_big(k).into();
+
+    // round(a / n)
+    let round_div = |a: BigInt| (a * 2 + &n).div_floor(&(&n * 2));
+    let c_1 = round_div(&b_2 * &k);
+    let c_2 = round_div(-&b_1 * &k);
+
+    let k_1 = &k - &c_1 * &a_1 - &c_2 * &a_2;
+    let k_2 = -&c_1 * &b_1 - &c_2 * &b_2;
+
+    [k_1, k_2].map(|e| (e.magnitude().clone(), e.is_negative()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decompose_scalar, endomorphism, glv_bit_len};
+    use crate::curves::bn256::G1Affine as Bn256;
+    use crate::curves::pasta::{EpAffine as Pallas, EqAffine as Vesta};
+    use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+    use crate::halo2::arithmetic::CurveAffine;
+    use crate::halo2::halo2curves::ff::Field;
+    use crate::maingate::big_to_fe;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_glv_decomposition() {
+        fn run<C: CurveAffine>() {
+            let (_, lambda) = endomorphism::<C>().unwrap();
+            for _ in 0..100 {
+                let k = C::Scalar::random(OsRng);
+                let [(k_1, k_1_neg), (k_2, k_2_neg)] = decompose_scalar(k, lambda);
+                assert!(k_1.bits() as usize <= glv_bit_len::<C::Scalar>());
+                assert!(k_2.bits() as usize <= glv_bit_len::<C::Scalar>());
+                let k_1: C::Scalar = big_to_fe(k_1);
+                let k_2: C::Scalar = big_to_fe(k_2);
+                let k_1 = if k_1_neg { -k_1 } else { k_1 };
+                let k_2 = if k_2_neg { -k_2 } else { k_2 };
+                assert_eq!(k, k_1 + k_2 * lambda);
+            }
+        }
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+        run::<Secp256k1>();
+    }
+}
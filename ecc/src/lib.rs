@@ -4,12 +4,19 @@
 #![deny(missing_docs)]
 
 pub use base_field_ecc::*;
+pub use g2::*;
 pub use general_ecc::*;
+pub use twisted_edwards::*;
 
 /// Constraints for the SW curve that are used in the same proof system
 pub mod base_field_ecc;
+/// Constraints for curves over quadratic extension fields such as BN254 G2
+pub mod g2;
 /// Constaints for any SW curve
 pub mod general_ecc;
+mod glv;
+/// Constraints for twisted Edwards curves
+pub mod twisted_edwards;
 
 pub use integer;
 pub use integer::halo2;
@@ -22,10 +29,11 @@ use crate::halo2::arithmetic::CurveAffine;
 use crate::integer::chip::IntegerConfig;
 use crate::integer::rns::{Integer, Rns};
 use crate::integer::AssignedInteger;
-use crate::maingate::{big_to_fe, AssignedCondition, MainGateConfig, RangeConfig};
+use crate::maingate::{big_to_fe, modulus, AssignedCondition, MainGateConfig, RangeConfig};
 use halo2::halo2curves::{ff::PrimeField, group::Curve};
+use halo2::plonk::Error;
 use num_bigint::BigUint as big_uint;
-use num_traits::One;
+use num_traits::{One, Zero};
 use std::fmt;
 use std::rc::Rc;
 
@@ -45,21 +53,28 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     Point<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
     /// Returns `Point` form a point in a EC with W as its base field
-    /// Infinity point is not allowed
+    /// Infinity point is encoded as $(0, 0)$ which is not on any curve with
+    /// non zero $b$
     pub fn new(
         rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
         point: impl CurveAffine<Base = W>,
     ) -> Self {
-        let coords = point.coordinates();
-        // disallow point of infinity
-        let coords = coords.unwrap();
+        let x = point
+            .coordinates()
+            .map(|coords| *coords.x())
+            .unwrap_or(W::ZERO);
+        let y = point
+            .coordinates()
+            .map(|coords| *coords.y())
+            .unwrap_or(W::ZERO);
 
-        let x = Integer::from_fe(*coords.x(), Rc::clone(&rns));
-        let y = Integer::from_fe(*coords.y(), Rc::clone(&rns));
+        let x = Integer::from_fe(x, Rc::clone(&rns));
+        let y = Integer::from_fe(y, Rc::clone(&rns));
         Point { x, y }
     }
 
     /// Returns $x$ and $y$ coordinates limbs as native field elements
+    /// Infinity point is exposed as all zero limbs
     pub fn public(&self) -> Vec<N> {
         let mut public_data = Vec::new();
         public_data.extend(self.x.limbs());
@@ -80,6 +95,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
 #[derive(Clone)]
 /// point that is assumed to be on curve and not infinity
+/// see `AssignedPointOrIdentity` for points that can be infinity
 pub struct AssignedPoint<
     W: PrimeField,
     N: PrimeField,
@@ -125,6 +141,60 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     }
 }
 
+/// Point that is either on curve or the point at infinity
+///
+/// Point at infinity is represented with coordinates $(0, 0)$ and
+/// `is_identity` set, so that two equal points always have equal
+/// coordinates
+#[derive(Clone)]
+pub struct AssignedPointOrIdentity<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub(crate) point: AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub(crate) is_identity: AssignedCondition<N>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    fmt::Debug for AssignedPointOrIdentity<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AssignedPointOrIdentity")
+            .field("xn", &self.point.x.native().value())
+            .field("yn", &self.point.y.native().value())
+            .field("is_identity", &self.is_identity.value())
+            .finish()?;
+        Ok(())
+    }
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedPointOrIdentity<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedPointOrIdentity` given its coordinates and the
+    /// condition that is true if the point is the point at infinity.
+    /// Does not check for validity
+    pub fn new(
+        point: AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        is_identity: AssignedCondition<N>,
+    ) -> Self {
+        AssignedPointOrIdentity { point, is_identity }
+    }
+
+    /// Returns the underlying coordinates
+    pub fn point(&self) -> &AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.point
+    }
+
+    /// Returns the condition that is true if the point is the point at
+    /// infinity
+    pub fn is_identity(&self) -> &AssignedCondition<N> {
+        &self.is_identity
+    }
+}
+
 /// Config for Ecc Chip
 #[derive(Clone, Debug)]
 pub struct EccConfig {
@@ -153,11 +223,16 @@ impl EccConfig {
 ///
 /// Computes AuxFin from AuxInit for batch multiplication
 /// see https://hackmd.io/ncuKqRXzR-Cw-Au2fGzsMg?view
-fn make_mul_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_pairs: usize) -> C {
+fn make_mul_aux<C: CurveAffine>(
+    aux_to_add: C,
+    window_size: usize,
+    number_of_pairs: usize,
+    number_of_bits: usize,
+) -> C {
     assert!(window_size > 0);
     assert!(number_of_pairs > 0);
 
-    let n = C::Scalar::NUM_BITS as usize;
+    let n = number_of_bits;
     let mut number_of_selectors = n / window_size;
     if n % window_size != 0 {
         number_of_selectors += 1;
@@ -173,6 +248,124 @@ fn make_mul_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_pai
     (-aux_to_add * big_to_fe::<C::Scalar>(k)).to_affine()
 }
 
+/// Precomputes window tables for fixed base scalar multiplication.
+///
+/// Returns `number_of_windows` tables starting from the least significant
+/// window. For windows other than the most significant one $k$th entry is
+/// $(k + 2) * 2^{w * i} * P$, and the most significant window compensates the
+/// added offsets. This way sums of lower windows never collide with the next
+/// entry to be added, see "Fixed-base scalar multiplication" in Zcash protocol
+/// specification. Window size must be at least 2 for this to hold.
+///
+/// That requires sums of lower windows to be less than the order of the
+/// group, which doesn't hold for small windows over a scalar field that is
+/// close to a power of two, such as `window_size = 2` for Pasta curves. Such
+/// parameters and tables that would contain the point at infinity are
+/// rejected with `Error::Synthesis`.
+fn make_fixed_tables<C: CurveAffine>(
+    point: C,
+    window_size: usize,
+    number_of_windows: usize,
+) -> Result<Vec<Vec<C>>, Error> {
+    assert!(window_size > 1);
+    assert!(number_of_windows > 1);
+
+    let table_size = 1usize << window_size;
+    let shift = |i: usize| big_uint::one() << (window_size * i);
+
+    // Largest sum of lower windows is `sum((2^w + 1) * 2^(w * i))`
+    let max_sum = (0..number_of_windows - 1).fold(big_uint::zero(), |acc, i| {
+        acc + shift(i) * big_uint::from(table_size + 1)
+    });
+    if max_sum >= modulus::<C::Scalar>() {
+        return Err(Error::Synthesis);
+    }
+
+    let offset: C::Scalar = big_to_fe(
+        (0..number_of_windows - 1).fold(big_uint::zero(), |acc, i| acc + (shift(i) << 1usize)),
+    );
+    let point = point.to_curve();
+
+    (0..number_of_windows)
+        .map(|i| {
+            (0..table_size)
+                .map(|k| {
+                    let scalar = if i == number_of_windows - 1 {
+                        big_to_fe::<C::Scalar>(big_uint::from(k) * shift(i)) - offset
+                    } else {
+                        big_to_fe::<C::Scalar>(big_uint::from(k + 2) * shift(i))
+                    };
+                    let entry = (point * scalar).to_affine();
+                    // tables are assigned as constants so they must have coordinates
+                    if bool::from(entry.is_identity()) {
+                        return Err(Error::Synthesis);
+                    }
+                    Ok(entry)
+                })
+                .collect::<Result<Vec<C>, Error>>()
+        })
+        .collect()
+}
+
+/// Returns a scalar for which the most significant window in fixed base
+/// scalar multiplication is added to an equal point. Lower windows of the
+/// scalar `low` add up to `low + offset` while the most significant window is
+/// `top * 2^(w * (n - 1)) - offset`.
+#[cfg(test)]
+pub(crate) fn mul_fixed_doubling_scalar<F: PrimeField>(window_size: usize) -> F {
+    let number_of_windows = (F::NUM_BITS as usize + window_size - 1) / window_size;
+    let shift = |i: usize| big_uint::one() << (window_size * i);
+    let offset =
+        (0..number_of_windows - 1).fold(big_uint::zero(), |acc, i| acc + (shift(i) << 1usize));
+    let top = (1usize..)
+        .find(|top| shift(number_of_windows - 1) * *top >= &offset << 1usize)
+        .unwrap();
+    big_to_fe((shift(number_of_windows - 1) * top - offset) << 1usize)
+}
+
+/// Chooses the window size of the bucket method in `msm`.
+///
+/// At each window a point is added to the bucket its window value selects,
+/// which costs an addition, a selection over `2^c` buckets to read the bucket
+/// and `2^c` selections to write it back. Buckets are then summed up with
+/// about `2^(c + 1)` additions and the accumulator is doubled `c` times.
+/// Window size is chosen to minimize the estimated cost where a point addition
+/// is assumed to be about as expensive as 8 point selections.
+pub(crate) fn msm_window_size(number_of_points: usize, number_of_bits: usize) -> usize {
+    const MAX_WINDOW_SIZE: usize = 8;
+    const ADD_COST: usize = 8;
+    const SELECT_COST: usize = 1;
+    assert!(number_of_points > 0);
+
+    (1..=MAX_WINDOW_SIZE)
+        .min_by_key(|window_size| {
+            let number_of_windows = (number_of_bits + window_size - 1) / window_size;
+            let number_of_buckets = 1 << window_size;
+            let per_window = number_of_points * (ADD_COST + 2 * number_of_buckets * SELECT_COST)
+                + 2 * number_of_buckets * ADD_COST
+                + window_size * ADD_COST;
+            number_of_windows * per_window + number_of_buckets * ADD_COST
+        })
+        .unwrap()
+}
+
+/// Finds the point to subtract from the end result of `msm`.
+///
+/// Bucket $j$ starts from $2^j * A$ at every window so that sums of window
+/// buckets $\sum_j j * B_j$ are offset by $\sum_j j * 2^j * A$, and the
+/// offset of each window is doubled along with the accumulator.
+fn make_msm_aux<C: CurveAffine>(aux_to_add: C, window_size: usize, number_of_bits: usize) -> C {
+    assert!(window_size > 0);
+
+    let number_of_windows = (number_of_bits + window_size - 1) / window_size;
+    let one = big_uint::one();
+    let window_offset =
+        (1..1usize << window_size).fold(big_uint::zero(), |acc, j| acc + (&one << j) * j);
+    let k0 =
+        (0..number_of_windows).fold(big_uint::zero(), |acc, i| acc | (&one << (i * window_size)));
+    (-aux_to_add * big_to_fe::<C::Scalar>(window_offset * k0)).to_affine()
+}
+
 /// Vector of `AssignedCondition` which is the binary representation of a
 /// scalar.
 ///
@@ -0,0 +1,718 @@
+use super::{AssignedPoint, EccConfig};
+use crate::halo2;
+use crate::integer::rns::{Integer, Rns};
+use crate::integer::{AssignedInteger, IntegerChip, IntegerInstructions, Range, UnassignedInteger};
+use crate::maingate::{self, big_to_fe, fe_to_big};
+use halo2::circuit::Value;
+use halo2::halo2curves::ed25519::{Fq as Ed25519Base, Fr as Ed25519Scalar};
+use halo2::halo2curves::ff::{Field, PrimeField};
+use halo2::plonk::Error;
+use maingate::{AssignedCondition, MainGate, MainGateInstructions, RegionCtx};
+use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Parameters of a twisted Edwards curve $a * x^2 + y^2 = 1 + d * x^2 * y^2$
+pub trait TwistedEdwardsCurve: Clone + Debug {
+    /// Base field of the curve
+    type Base: PrimeField;
+    /// Scalar field of the prime order subgroup
+    type Scalar: PrimeField;
+    /// Cofactor of the curve. Expected to be a power of two
+    const COFACTOR: u64;
+
+    /// Returns curve constant $a$
+    fn a() -> Self::Base;
+
+    /// Returns curve constant $d$. Addition formula is complete if $a$ is a
+    /// square and $d$ is a non square in the base field
+    fn d() -> Self::Base;
+
+    /// Returns affine coordinates of the generator of the prime order subgroup
+    fn generator() -> (Self::Base, Self::Base);
+}
+
+/// Ed25519 curve as specified in RFC 8032
+#[derive(Clone, Debug)]
+pub struct Ed25519;
+
+impl TwistedEdwardsCurve for Ed25519 {
+    type Base = Ed25519Base;
+    type Scalar = Ed25519Scalar;
+    const COFACTOR: u64 = 8;
+
+    fn a() -> Self::Base {
+        -Self::Base::ONE
+    }
+
+    fn d() -> Self::Base {
+        -Self::Base::from(121665) * Self::Base::from(121666).invert().unwrap()
+    }
+
+    fn generator() -> (Self::Base, Self::Base) {
+        let x = big_uint::parse_bytes(
+            b"15112221349535400772501151409588531511454012693041857206046113283949847762202",
+            10,
+        )
+        .unwrap();
+        let y = Self::Base::from(4) * Self::Base::from(5).invert().unwrap();
+        (big_to_fe(x), y)
+    }
+}
+
+/// Constaints twisted Edwards curve operations such as assigment, point
+/// decompression, addition and multiplication. Points are represented as
+/// `AssignedPoint` in affine coordinates where identity is $(0, 1)$.
+/// Addition is complete so unlike short Weierstrass chips no auxiliary
+/// points are required.
+#[derive(Clone, Debug)]
+#[allow(clippy::type_complexity)]
+pub struct TwistedEdwardsChip<
+    C: TwistedEdwardsCurve,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    /// `IntegerChip` for the base field of the curve
+    base_field_chip: IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    /// `IntegerChip` for the scalar field of the curve
+    scalar_field_chip: IntegerChip<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    _marker: PhantomData<C>,
+}
+
+impl<
+        C: TwistedEdwardsCurve,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > TwistedEdwardsChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Residue numeral system
+    /// Used to emulate the base field `C::Base` and the scalar field
+    /// `C::Scalar` over the native field `N`
+    pub fn rns() -> (
+        Rns<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        Rns<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) {
+        (Rns::construct(), Rns::construct())
+    }
+
+    /// Return `TwistedEdwardsChip` from `EccConfig`
+    pub fn new(config: EccConfig) -> Self {
+        let (rns_base_field, rns_scalar_field) = Self::rns();
+        let integer_config = config.integer_chip_config();
+        Self {
+            base_field_chip: IntegerChip::new(integer_config.clone(), Rc::new(rns_base_field)),
+            scalar_field_chip: IntegerChip::new(integer_config, Rc::new(rns_scalar_field)),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Residue numeral system for the base field of the curve
+    pub fn rns_base(&self) -> Rc<Rns<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
+        self.base_field_chip.rns()
+    }
+
+    /// Residue numeral system for the scalar field of the curve
+    pub fn rns_scalar(&self) -> Rc<Rns<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
+        self.scalar_field_chip.rns()
+    }
+
+    /// Assign Rns base for chip
+    pub fn new_unassigned_base(
+        &self,
+        e: Value<C::Base>,
+    ) -> UnassignedInteger<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        e.map(|e| Integer::from_fe(e, self.rns_base())).into()
+    }
+
+    /// Assign Rns Scalar for chip
+    pub fn new_unassigned_scalar(
+        &self,
+        e: Value<C::Scalar>,
+    ) -> UnassignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        e.map(|e| Integer::from_fe(e, self.rns_scalar())).into()
+    }
+
+    /// Return `IntegerChip` for the base field of the curve
+    pub fn base_field_chip(&self) -> &IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.base_field_chip
+    }
+
+    /// Return `IntegerChip` for the scalar field of the curve
+    pub fn scalar_field_chip(&self) -> &IntegerChip<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.scalar_field_chip
+    }
+
+    /// Return `Maingate` of the `TwistedEdwardsChip`
+    pub fn main_gate(&self) -> &MainGate<N> {
+        self.base_field_chip.main_gate()
+    }
+
+    /// Returns curve constant $a$
+    fn parameter_a(&self) -> Integer<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(C::a(), self.rns_base())
+    }
+
+    /// Returns curve constant $d$
+    fn parameter_d(&self) -> Integer<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(C::d(), self.rns_base())
+    }
+
+    /// Returns one in the base field
+    fn one(&self) -> Integer<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(C::Base::ONE, self.rns_base())
+    }
+
+    /// Takes affine coordinates and assign them as constant
+    /// Returned as `AssignedPoint`
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: (C::Base, C::Base),
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let base_field_chip = self.base_field_chip();
+        let x = base_field_chip.assign_constant(ctx, point.0)?;
+        let y = base_field_chip.assign_constant(ctx, point.1)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Assigns identity point $(0, 1)$ as constant
+    pub fn assign_identity(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.assign_constant(ctx, (C::Base::ZERO, C::Base::ONE))
+    }
+
+    /// Assigns generator of the prime order subgroup as constant
+    pub fn assign_generator(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.assign_constant(ctx, C::generator())
+    }
+
+    /// Takes affine coordinates of a point and returns it as `AssignedPoint`
+    /// Constraints the point to be on the curve
+    pub fn assign_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<(C::Base, C::Base)>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let (x, y) = point.unzip();
+        let x = integer_chip.assign_integer(ctx, self.new_unassigned_base(x), Range::Remainder)?;
+        let y = integer_chip.assign_integer(ctx, self.new_unassigned_base(y), Range::Remainder)?;
+
+        let point = AssignedPoint::new(x, y);
+        self.assert_is_on_curve(ctx, &point)?;
+        Ok(point)
+    }
+
+    /// Decompresses a point from its encoding given in little endian bits
+    /// Encoding is the $y$ coordinate followed by the sign bit of the $x$
+    /// coordinate as in RFC 8032. Non canonical $y$ values, encodings that
+    /// are not on the curve and zero $x$ with sign bit set are rejected.
+    pub fn decompress(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        encoding: &[AssignedCondition<N>],
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let integer_chip = self.base_field_chip();
+
+        let (y_bits, sign) = encoding.split_at(C::Base::NUM_BITS as usize);
+        assert_eq!(sign.len(), 1);
+        let sign = &sign[0];
+
+        // Recover `x` out of circuit. Invalid encodings are given a dummy
+        // witness and fail in constraints below
+        let y = y_bits
+            .iter()
+            .rev()
+            .fold(Value::known(big_uint::zero()), |acc, bit| {
+                acc.zip(bit.value())
+                    .map(|(acc, bit)| (acc << 1usize) + fe_to_big(*bit))
+            })
+            .map(big_to_fe::<C::Base>);
+        let x = y.zip(sign.value()).map(|(y, sign)| {
+            let y_square = y.square();
+            let u = y_square - C::Base::ONE;
+            let v = C::d() * y_square - C::a();
+            let x = v
+                .invert()
+                .and_then(|v_inv| (u * v_inv).sqrt())
+                .unwrap_or(C::Base::ZERO);
+            if bool::from(x.is_odd()) == (*sign == N::ONE) {
+                x
+            } else {
+                -x
+            }
+        });
+        let point = self.assign_point(ctx, x.zip(y))?;
+
+        // `decompose` also enforces `y` to be canonical
+        let decomposed = integer_chip.decompose(ctx, point.y())?;
+        assert_eq!(decomposed.len(), y_bits.len());
+        for (bit, expected) in decomposed.iter().zip(y_bits.iter()) {
+            main_gate.assert_equal(ctx, bit, expected)?;
+        }
+        let x_sign = integer_chip.sign(ctx, point.x())?;
+        main_gate.assert_equal(ctx, &x_sign, sign)?;
+
+        Ok(point)
+    }
+
+    /// Constraints to ensure `AssignedPoint` is on curve
+    pub fn assert_is_on_curve(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let integer_chip = self.base_field_chip();
+
+        let x_square = &integer_chip.square(ctx, point.x())?;
+        let y_square = &integer_chip.square(ctx, point.y())?;
+        let a_x_square = &integer_chip.mul_constant(ctx, x_square, &self.parameter_a())?;
+        let lhs = &integer_chip.add(ctx, a_x_square, y_square)?;
+
+        let x_square_y_square = &integer_chip.mul(ctx, x_square, y_square)?;
+        let rhs = &integer_chip.mul_constant(ctx, x_square_y_square, &self.parameter_d())?;
+        let rhs = &integer_chip.add_constant(ctx, rhs, &self.one())?;
+        integer_chip.assert_equal(ctx, lhs, rhs)
+    }
+
+    /// Constraints assert two `AssignedPoint`s are equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let integer_chip = self.base_field_chip();
+        integer_chip.assert_equal(ctx, p0.x(), p1.x())?;
+        integer_chip.assert_equal(ctx, p0.y(), p1.y())
+    }
+
+    /// Selects between 2 `AssignedPoint` determined by an `AssignedCondition`
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        c: &AssignedCondition<N>,
+        p1: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p2: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+        let x = integer_chip.select(ctx, p1.x(), p2.x(), c)?;
+        let y = integer_chip.select(ctx, p1.y(), p2.y(), c)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Returns $-P$ which is $(-x, y)$
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let x = self.base_field_chip().neg(ctx, p.x())?;
+        Ok(AssignedPoint::new(x, p.y().clone()))
+    }
+
+    /// Adds 2 points with the unified and complete addition formula
+    /// $x_3 = (x_1 y_2 + y_1 x_2) / (1 + d x_1 x_2 y_1 y_2)$
+    /// $y_3 = (y_1 y_2 - a x_1 x_2) / (1 - d x_1 x_2 y_1 y_2)$
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p0: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p1: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+
+        let x0_y1 = &integer_chip.mul(ctx, p0.x(), p1.y())?;
+        let y0_x1 = &integer_chip.mul(ctx, p0.y(), p1.x())?;
+        let x0_x1 = &integer_chip.mul(ctx, p0.x(), p1.x())?;
+        let y0_y1 = &integer_chip.mul(ctx, p0.y(), p1.y())?;
+        let t = &integer_chip.mul(ctx, x0_x1, y0_y1)?;
+        let t = &integer_chip.mul_constant(ctx, t, &self.parameter_d())?;
+
+        // Denominators are never zero for points on the curve since $d$ is
+        // not a square
+        let x_numerator = &integer_chip.add(ctx, x0_y1, y0_x1)?;
+        let x_denominator = &integer_chip.add_constant(ctx, t, &self.one())?;
+        let x = integer_chip.div_incomplete(ctx, x_numerator, x_denominator)?;
+
+        let a_x0_x1 = &integer_chip.mul_constant(ctx, x0_x1, &self.parameter_a())?;
+        let y_numerator = &integer_chip.sub(ctx, y0_y1, a_x0_x1)?;
+        let y_denominator = &integer_chip.neg(ctx, t)?;
+        let y_denominator = &integer_chip.add_constant(ctx, y_denominator, &self.one())?;
+        let y = integer_chip.div_incomplete(ctx, y_numerator, y_denominator)?;
+
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Doubles a point using the curve equation to lower the degree
+    /// $x_3 = 2 x y / (a x^2 + y^2)$
+    /// $y_3 = (y^2 - a x^2) / (2 - a x^2 - y^2)$
+    pub fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.base_field_chip();
+
+        let x_square = &integer_chip.square(ctx, p.x())?;
+        let y_square = &integer_chip.square(ctx, p.y())?;
+        let a_x_square = &integer_chip.mul_constant(ctx, x_square, &self.parameter_a())?;
+        let x_y = &integer_chip.mul(ctx, p.x(), p.y())?;
+
+        // $a x^2 + y^2 = 1 + d x^2 y^2$ which is never zero
+        let x_numerator = &integer_chip.mul2(ctx, x_y)?;
+        let x_denominator = &integer_chip.add(ctx, a_x_square, y_square)?;
+        let x = integer_chip.div_incomplete(ctx, x_numerator, x_denominator)?;
+
+        // $2 - a x^2 - y^2 = 1 - d x^2 y^2$ which is never zero
+        let y_numerator = &integer_chip.sub(ctx, y_square, a_x_square)?;
+        let y_denominator = &integer_chip.neg(ctx, x_denominator)?;
+        let two = Integer::from_fe(C::Base::from(2), self.rns_base());
+        let y_denominator = &integer_chip.add_constant(ctx, y_denominator, &two)?;
+        let y = integer_chip.div_incomplete(ctx, y_numerator, y_denominator)?;
+
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Given an `AssignedPoint` $P$ computes P * 2^logn
+    pub fn double_n(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        logn: usize,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let mut acc = p.clone();
+        for _ in 0..logn {
+            acc = self.double(ctx, &acc)?;
+        }
+        Ok(acc)
+    }
+
+    /// Multiplies a point by the cofactor of the curve
+    pub fn mul_cofactor(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(C::COFACTOR.is_power_of_two());
+        self.double_n(ctx, p, C::COFACTOR.trailing_zeros() as usize)
+    }
+
+    /// Selects a point from the table with little endian selector bits
+    fn select_multi(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        selector: &[AssignedCondition<N>],
+        table: &[AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert_eq!(table.len(), 1 << selector.len());
+
+        let number_of_selectors = selector.len();
+        let mut reducer = table.to_vec();
+        for (i, bit) in selector.iter().enumerate() {
+            let n = 1 << (number_of_selectors - 1 - i);
+            for j in 0..n {
+                let k = 2 * j;
+                reducer[j] = self.select(ctx, bit, &reducer[k + 1], &reducer[k])?;
+            }
+        }
+        Ok(reducer[0].clone())
+    }
+
+    /// Scalar multiplication of a point on the curve
+    /// Performed with fixed window algorithm. Since addition is complete
+    /// scalar and the point are not restricted
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        scalar: &AssignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        window_size: usize,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(window_size > 0);
+        let main_gate = self.main_gate();
+
+        let mut bits = self.scalar_field_chip().decompose(ctx, scalar)?;
+        let padding = (window_size - (bits.len() % window_size)) % window_size;
+        for _ in 0..padding {
+            bits.push(main_gate.assign_constant(ctx, N::ZERO)?);
+        }
+
+        // table[i] = i * P
+        let mut table = vec![self.assign_identity(ctx)?, point.clone()];
+        for i in 2..(1 << window_size) {
+            table.push(self.add(ctx, &table[i - 1], point)?);
+        }
+
+        let mut windows = bits.chunks(window_size).rev();
+        let mut acc = self.select_multi(ctx, windows.next().unwrap(), &table)?;
+        for window in windows {
+            acc = self.double_n(ctx, &acc, window_size)?;
+            let selected = self.select_multi(ctx, window, &table)?;
+            acc = self.add(ctx, &acc, &selected)?;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ed25519, TwistedEdwardsChip, TwistedEdwardsCurve};
+    use crate::halo2;
+    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::integer::{IntegerInstructions, Range};
+    use crate::maingate;
+    use crate::EccConfig;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::{fe_to_big, mock_prover_verify};
+    use maingate::{
+        MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig, RangeInstructions,
+        RegionCtx,
+    };
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+
+    use crate::curves::bn256::Fr as BnScalar;
+    use crate::curves::pasta::Fp as PastaFp;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn new<C: TwistedEdwardsCurve, N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let (rns_base, rns_scalar) =
+                TwistedEdwardsChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    /// Reference addition
+    fn add<C: TwistedEdwardsCurve>(
+        p0: (C::Base, C::Base),
+        p1: (C::Base, C::Base),
+    ) -> (C::Base, C::Base) {
+        let t = C::d() * p0.0 * p1.0 * p0.1 * p1.1;
+        let x = (p0.0 * p1.1 + p0.1 * p1.0) * (C::Base::ONE + t).invert().unwrap();
+        let y = (p0.1 * p1.1 - C::a() * p0.0 * p1.0) * (C::Base::ONE - t).invert().unwrap();
+        (x, y)
+    }
+
+    /// Reference double-and-add multiplication
+    fn mul<C: TwistedEdwardsCurve>(p: (C::Base, C::Base), e: C::Scalar) -> (C::Base, C::Base) {
+        let e = fe_to_big(e);
+        let mut acc = (C::Base::ZERO, C::Base::ONE);
+        for i in (0..e.bits()).rev() {
+            acc = add::<C>(acc, acc);
+            if e.bit(i) {
+                acc = add::<C>(acc, p);
+            }
+        }
+        acc
+    }
+
+    /// Little endian bits of $y$ followed by the sign bit of $x$
+    fn compress<C: TwistedEdwardsCurve>(p: (C::Base, C::Base)) -> Vec<bool> {
+        let y = fe_to_big(p.1);
+        (0..C::Base::NUM_BITS as u64)
+            .map(|i| y.bit(i))
+            .chain(std::iter::once(bool::from(p.0.is_odd())))
+            .collect()
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestTwistedEdwardsChip<C: TwistedEdwardsCurve, N: PrimeField> {
+        window_size: usize,
+        _marker: PhantomData<(C, N)>,
+    }
+
+    impl<C: TwistedEdwardsCurve, N: PrimeField> Circuit<N> for TestTwistedEdwardsChip<C, N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip = TwistedEdwardsChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+            let main_gate = ecc_chip.main_gate();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let g = C::generator();
+                    let a = mul::<C>(g, C::Scalar::random(OsRng));
+                    let b = mul::<C>(g, C::Scalar::random(OsRng));
+                    let e = C::Scalar::random(OsRng);
+
+                    // test addition
+
+                    let a_assigned = &ecc_chip.assign_point(ctx, Value::known(a))?;
+                    let b_assigned = &ecc_chip.assign_point(ctx, Value::known(b))?;
+                    let c_0 = &ecc_chip.assign_point(ctx, Value::known(add::<C>(a, b)))?;
+                    let c_1 = &ecc_chip.add(ctx, a_assigned, b_assigned)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // test complete addition with identity and negation
+
+                    let identity = &ecc_chip.assign_identity(ctx)?;
+                    let c_1 = &ecc_chip.add(ctx, a_assigned, identity)?;
+                    ecc_chip.assert_equal(ctx, a_assigned, c_1)?;
+                    let a_neg = &ecc_chip.neg(ctx, a_assigned)?;
+                    let c_1 = &ecc_chip.add(ctx, a_assigned, a_neg)?;
+                    ecc_chip.assert_equal(ctx, identity, c_1)?;
+
+                    // test doubling
+
+                    let c_0 = &ecc_chip.assign_point(ctx, Value::known(add::<C>(a, a)))?;
+                    let c_1 = &ecc_chip.double(ctx, a_assigned)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+                    let c_1 = &ecc_chip.add(ctx, a_assigned, a_assigned)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // test multiplication
+
+                    let e_assigned = &scalar_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_scalar(Value::known(e)),
+                        Range::Remainder,
+                    )?;
+                    let c_0 = &ecc_chip.assign_point(ctx, Value::known(mul::<C>(a, e)))?;
+                    let c_1 = &ecc_chip.mul(ctx, a_assigned, e_assigned, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let generator = &ecc_chip.assign_generator(ctx)?;
+                    let c_0 = &ecc_chip.assign_point(ctx, Value::known(mul::<C>(g, e)))?;
+                    let c_1 = &ecc_chip.mul(ctx, generator, e_assigned, self.window_size)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // test cofactor multiplication
+
+                    let cofactor = C::Scalar::from(C::COFACTOR);
+                    let c_0 = &ecc_chip.assign_point(ctx, Value::known(mul::<C>(a, cofactor)))?;
+                    let c_1 = &ecc_chip.mul_cofactor(ctx, a_assigned)?;
+                    ecc_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // test decompression
+
+                    for point in [a, b, g] {
+                        let encoding = compress::<C>(point)
+                            .into_iter()
+                            .map(|bit| main_gate.assign_bit(ctx, Value::known(N::from(bit as u64))))
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        let c_0 = &ecc_chip.assign_point(ctx, Value::known(point))?;
+                        let c_1 = &ecc_chip.decompress(ctx, &encoding)?;
+                        ecc_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_twisted_edwards_chip() {
+        fn run<C: TwistedEdwardsCurve, N: PrimeField>() {
+            for window_size in 1..4 {
+                let circuit = TestTwistedEdwardsChip::<C, N> {
+                    window_size,
+                    _marker: PhantomData,
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+        run::<Ed25519, BnScalar>();
+        run::<Ed25519, PastaFp>();
+    }
+
+    #[test]
+    fn test_ed25519_parameters() {
+        let (x, y) = Ed25519::generator();
+        let (x_square, y_square) = (x.square(), y.square());
+        assert_eq!(
+            Ed25519::a() * x_square + y_square,
+            <Ed25519 as TwistedEdwardsCurve>::Base::ONE + Ed25519::d() * x_square * y_square
+        );
+        // Generator has the prime order
+        let order_minus_one = -<Ed25519 as TwistedEdwardsCurve>::Scalar::ONE;
+        assert_eq!(
+            add::<Ed25519>(mul::<Ed25519>((x, y), order_minus_one), (x, y)),
+            (
+                <Ed25519 as TwistedEdwardsCurve>::Base::ZERO,
+                <Ed25519 as TwistedEdwardsCurve>::Base::ONE
+            )
+        );
+        // Generator has positive, even $x$
+        assert!(bool::from(x.is_even()));
+    }
+}
@@ -5,11 +5,26 @@ use crate::maingate;
 use ecc::maingate::RegionCtx;
 use ecc::{AssignedPoint, EccConfig, GeneralEccChip};
 use halo2::arithmetic::CurveAffine;
-use halo2::halo2curves::ff::PrimeField;
-use halo2::{circuit::Value, plonk::Error};
-use integer::rns::Integer;
-use integer::{AssignedInteger, IntegerInstructions};
-use maingate::{MainGateConfig, RangeConfig};
+use halo2::circuit::Value;
+use halo2::halo2curves::ff::{Field, PrimeField};
+use halo2::halo2curves::group::Curve;
+use halo2::plonk::Error;
+use integer::rns::{Common, Integer};
+use integer::{AssignedInteger, IntegerInstructions, Range};
+use keccak::{KeccakChip, DIGEST_BYTES};
+use maingate::{
+    big_to_fe, fe_to_big, AssignedCondition, AssignedValue, MainGateConfig, MainGateInstructions,
+    RangeConfig, Term,
+};
+use transcript::{assign_integer_challenge, HasherChip};
+
+/// Bit length of random coefficients that combine signatures in batch
+/// verification
+const BATCH_COEFFICIENT_BIT_LEN: usize = 128;
+/// Number of bytes of a coordinate in Ethereum public key encoding
+const ETH_COORDINATE_BYTES: usize = 32;
+/// Number of bytes of an Ethereum address
+const ETH_ADDRESS_BYTES: usize = 20;
 
 #[derive(Clone, Debug)]
 pub struct EcdsaConfig {
@@ -92,6 +107,10 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
 impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     EcdsaChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
+    /// Verifies the signature against the public key and the message hash.
+    /// `u1 * G` is computed with fixed base multiplication so that the
+    /// message hash can be zero. Auxiliary point for `window_size = 4` and a
+    /// single pair must be assigned.
     pub fn verify(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -100,6 +119,7 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
         msg_hash: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
         let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
         let scalar_chip = ecc_chip.scalar_field_chip();
         let base_chip = ecc_chip.base_field_chip();
 
@@ -119,10 +139,14 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
         // 4. u2 = r * w (mod n)
         let u2 = scalar_chip.mul(ctx, &sig.r, &s_inv)?;
 
-        // 5. compute Q = u1*G + u2*pk
-        let e_gen = ecc_chip.assign_point(ctx, Value::known(E::generator()))?;
-        let pairs = vec![(e_gen, u1), (pk.point.clone(), u2)];
-        let q = ecc_chip.mul_batch_1d_horizontal(ctx, pairs, 4)?;
+        // 5. compute Q = u1*G + u2*pk where u2 is not zero while u1 is zero
+        // for zero message hash
+        let u1_g = ecc_chip.mul_fixed_or_identity(ctx, E::generator(), &u1, 4)?;
+        let u2_pk = ecc_chip.mul(ctx, &pk.point, &u2, 4)?;
+        let u2_pk = ecc_chip.to_point_or_identity(ctx, &u2_pk)?;
+        let q = ecc_chip.add_complete(ctx, &u1_g, &u2_pk)?;
+        main_gate.assert_zero(ctx, q.is_identity())?;
+        let q = q.point();
 
         // 6. reduce q_x in E::ScalarExt
         // assuming E::Base/E::ScalarExt have the same number of limbs
@@ -135,6 +159,324 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
 
         Ok(())
     }
+
+    /// Verifies the signature as `verify` does and additionally constrains
+    /// `s <= n / 2` so that the malleable `n - s` twin of a signature is
+    /// rejected
+    pub fn verify_low_s(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        sig: &AssignedEcdsaSig<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        msg_hash: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.verify(ctx, sig, pk, msg_hash)?;
+
+        // `s` is already in field so `s < (n - 1) / 2 + 1` means `s <= n / 2`
+        let scalar_chip = self.scalar_field_chip();
+        let half_order = fe_to_big(-E::Scalar::ONE) >> 1;
+        let bound = scalar_chip.assign_constant(ctx, big_to_fe(half_order + 1usize))?;
+        scalar_chip.assert_less_than(ctx, &sig.s, &bound)
+    }
+
+    /// Maps a digest given as big endian 32-bit words, as `Sha256Chip::digest`
+    /// returns, to the message hash integer that `verify` expects. As in SEC1
+    /// leftmost bits of the digest are taken if it is longer than the scalar
+    /// field. Words are expected to be in 32-bit range
+    pub fn msg_hash_from_digest(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        digest: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+
+        // Little endian bits of the digest
+        let mut bits = vec![];
+        for word in digest.iter().rev() {
+            bits.extend(main_gate.to_bits(ctx, word, 32)?);
+        }
+        let bit_len = std::cmp::min(bits.len(), E::Scalar::NUM_BITS as usize);
+        let bits = &bits[bits.len() - bit_len..];
+
+        let limbs = bits
+            .chunks(BIT_LEN_LIMB)
+            .map(|bits| {
+                let mut base = N::ONE;
+                let terms: Vec<Term<N>> = bits
+                    .iter()
+                    .map(|bit| {
+                        let term = Term::Assigned(bit, base);
+                        base = base.double();
+                        term
+                    })
+                    .collect();
+                main_gate.compose(ctx, &terms, N::ZERO)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        assert!(limbs.len() <= NUMBER_OF_LIMBS);
+
+        let integer = limbs
+            .iter()
+            .map(|limb| limb.value().copied())
+            .collect::<Value<Vec<N>>>()
+            .map(|mut limbs| {
+                limbs.resize(NUMBER_OF_LIMBS, N::ZERO);
+                Integer::from_limbs(&limbs.try_into().unwrap(), ecc_chip.rns_scalar())
+            });
+        let msg_hash = scalar_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+        for (i, limb) in msg_hash.limbs().iter().enumerate() {
+            match limbs.get(i) {
+                Some(expected) => main_gate.assert_equal(ctx, limb.as_ref(), expected)?,
+                None => main_gate.assert_zero(ctx, limb.as_ref())?,
+            }
+        }
+
+        Ok(msg_hash)
+    }
+
+    /// Returns Ethereum address of the public key as 20 bytes, that is the
+    /// last 20 bytes of Keccak-256 hash of the big endian coordinates. Range
+    /// chip is expected to support byte conversions of base field integers
+    pub fn eth_address(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        keccak_chip: &KeccakChip<N>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let base_chip = ecc_chip.base_field_chip();
+
+        let zero = main_gate.assign_constant(ctx, N::ZERO)?;
+        let mut bytes = vec![];
+        for coordinate in [pk.point.x(), pk.point.y()] {
+            let coordinate_bytes = base_chip.to_bytes_be(ctx, coordinate)?;
+            assert!(coordinate_bytes.len() <= ETH_COORDINATE_BYTES);
+            bytes.extend(
+                std::iter::repeat(zero.clone())
+                    .take(ETH_COORDINATE_BYTES - coordinate_bytes.len())
+                    .chain(coordinate_bytes),
+            );
+        }
+
+        let len = main_gate.assign_constant(ctx, N::from(bytes.len() as u64))?;
+        let digest = keccak_chip.keccak256(ctx, &bytes, &len)?;
+
+        Ok(digest[DIGEST_BYTES - ETH_ADDRESS_BYTES..].to_vec())
+    }
+
+    /// Recovers the public key from a signature `(r, s, v)` and the message
+    /// hash where `v` is the parity of the y coordinate of `R`.
+    ///
+    /// `R` is witnessed with x coordinate equal to `r` and y coordinate with
+    /// parity `v`, then the public key is computed as
+    /// `Q = r^-1 * (s * R - m * G)`. `R.x` is constrained to be equal to `r`
+    /// as an integer rather than modulo `n`, so recovery ids 2 and 3 for
+    /// `R.x >= n` are rejected. Zero message hash is accepted as `m * G` is
+    /// computed as a point or identity and added with complete addition.
+    /// Auxiliary point for `window_size = 4` must be assigned.
+    pub fn recover(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        sig: &AssignedEcdsaSig<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        v: &AssignedCondition<N>,
+        msg_hash: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+        let base_chip = ecc_chip.base_field_chip();
+
+        // 1. check 0 < r, s < n
+        scalar_chip.assert_not_zero(ctx, &sig.r)?;
+        scalar_chip.assert_not_zero(ctx, &sig.s)?;
+
+        // 2. reconstruct R from r and the recovery id
+        let r_point = sig.r.integer().zip(v.value()).map(|(r, v)| {
+            let x = big_to_fe::<E::Base>(r.value());
+            let y = (x.square() * x + E::a() * x + E::b()).sqrt();
+            // Invalid signatures are rejected by the constraints anyway
+            let y = Option::from(y).unwrap_or(E::Base::ZERO);
+            let y = if bool::from(y.is_odd()) == (*v == N::ONE) {
+                y
+            } else {
+                -y
+            };
+            Option::from(E::from_xy(x, y)).unwrap_or(E::generator())
+        });
+        let r_point = ecc_chip.assign_point(ctx, r_point)?;
+        // R.x == r where both are canonical so that limbs determine the values
+        let r_x = base_chip.reduce(ctx, r_point.x())?;
+        base_chip.assert_in_field(ctx, &r_x)?;
+        let r = scalar_chip.reduce(ctx, &sig.r)?;
+        scalar_chip.assert_in_field(ctx, &r)?;
+        for (r_x, r) in r_x.limbs().iter().zip(r.limbs().iter()) {
+            main_gate.assert_equal(ctx, r_x.as_ref(), r.as_ref())?;
+        }
+        let parity = base_chip.sign(ctx, r_point.y())?;
+        main_gate.assert_equal(ctx, &parity, v)?;
+
+        // 3. u1 = -m * r^(-1), u2 = s * r^(-1) (mod n)
+        let (r_inv, _) = scalar_chip.invert(ctx, &sig.r)?;
+        let u1 = scalar_chip.mul(ctx, msg_hash, &r_inv)?;
+        let u1 = scalar_chip.neg(ctx, &u1)?;
+        let u1 = scalar_chip.reduce(ctx, &u1)?;
+        let u2 = scalar_chip.mul(ctx, &sig.s, &r_inv)?;
+
+        // 4. Q = u1*G + u2*R where u2 is not zero while u1 is zero for zero
+        // message hash
+        let u1_g = ecc_chip.mul_fixed_or_identity(ctx, E::generator(), &u1, 4)?;
+        let u2_r = ecc_chip.mul(ctx, &r_point, &u2, 4)?;
+        let u2_r = ecc_chip.to_point_or_identity(ctx, &u2_r)?;
+        let point = ecc_chip.add_complete(ctx, &u1_g, &u2_r)?;
+        main_gate.assert_zero(ctx, point.is_identity())?;
+        let point = point.point().clone();
+
+        Ok(AssignedPublicKey { point })
+    }
+
+    /// Verifies a batch of signatures with a single multi-scalar
+    /// multiplication.
+    ///
+    /// For each signature the point `R = s^-1 * (m * G + r * pk)` is witnessed
+    /// and its x coordinate is checked against `r`. Then instead of checking
+    /// `s * R = m * G + r * pk` one by one, equations are combined with random
+    /// coefficients `c_i` that are squeezed from `hasher` after all inputs
+    /// and `R` points are written to it, and it is checked that
+    /// `sum(c_i * r_i * pk_i - c_i * s_i * R_i) = -sum(c_i * m_i) * G`. So no
+    /// inversion is required. Auxiliary point for the multi-scalar
+    /// multiplication must be assigned with `assign_aux_msm` for twice the
+    /// number of signatures.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch<const T: usize, const RATE: usize>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        hasher: &mut HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>,
+        batch: &[(
+            AssignedEcdsaSig<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<(), Error> {
+        assert!(!batch.is_empty());
+        let ecc_chip = self.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+        let base_chip = ecc_chip.base_field_chip();
+
+        // 1. check 0 < r, s < n and witness R for each signature
+        let mut r_points = vec![];
+        for (sig, pk, msg_hash) in batch.iter() {
+            scalar_chip.assert_not_zero(ctx, &sig.r)?;
+            scalar_chip.assert_not_zero(ctx, &sig.s)?;
+
+            let r_point = self.r_point(sig, pk, msg_hash);
+            let r_point = ecc_chip.assign_point(ctx, r_point)?;
+
+            // R.x == r (mod n)
+            let r_x_reduced_in_q = base_chip.reduce(ctx, r_point.x())?;
+            let r_x_reduced_in_r = scalar_chip.reduce_external(ctx, &r_x_reduced_in_q)?;
+            scalar_chip.assert_strict_equal(ctx, &r_x_reduced_in_r, &sig.r)?;
+
+            r_points.push(r_point);
+        }
+
+        // 2. commit to the whole batch before deriving coefficients
+        for ((sig, pk, msg_hash), r_point) in batch.iter().zip(r_points.iter()) {
+            for integer in [
+                &sig.r,
+                &sig.s,
+                msg_hash,
+                pk.point.x(),
+                pk.point.y(),
+                r_point.x(),
+                r_point.y(),
+            ] {
+                let limbs: Vec<_> = integer.limbs().iter().map(|limb| limb.into()).collect();
+                hasher.update(&limbs);
+            }
+        }
+
+        // 3. combine equations `s * R = m * G + r * pk` with random coefficients
+        let mut pairs = vec![];
+        let mut generator_scalar: Option<AssignedInteger<_, _, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> =
+            None;
+        for ((sig, pk, msg_hash), r_point) in batch.iter().zip(r_points.into_iter()) {
+            let c = self.squeeze_coefficient(ctx, hasher)?;
+
+            let c_m = scalar_chip.mul(ctx, &c, msg_hash)?;
+            generator_scalar = Some(match generator_scalar {
+                Some(acc) => scalar_chip.add(ctx, &acc, &c_m)?,
+                None => c_m,
+            });
+
+            let c_r = scalar_chip.mul(ctx, &c, &sig.r)?;
+            let c_s = scalar_chip.mul(ctx, &c, &sig.s)?;
+            let c_s = scalar_chip.neg(ctx, &c_s)?;
+            let c_s = scalar_chip.reduce(ctx, &c_s)?;
+            pairs.push((pk.point.clone(), c_r));
+            pairs.push((r_point, c_s));
+        }
+        let generator_scalar = scalar_chip.neg(ctx, &generator_scalar.unwrap())?;
+        let generator_scalar = scalar_chip.reduce(ctx, &generator_scalar)?;
+
+        // 4. check the combined equation
+        let lhs = ecc_chip.msm(ctx, pairs)?;
+        let rhs = ecc_chip.mul_fixed(ctx, E::generator(), &generator_scalar, 4)?;
+        ecc_chip.assert_equal(ctx, &lhs, &rhs)
+    }
+
+    /// Computes witness for `R = s^-1 * (m * G + r * pk)`
+    fn r_point(
+        &self,
+        sig: &AssignedEcdsaSig<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        msg_hash: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Value<E> {
+        let scalar = |integer: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>| {
+            integer
+                .integer()
+                .map(|integer| big_to_fe::<E::Scalar>(integer.value()))
+        };
+        let base = |integer: &AssignedInteger<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>| {
+            integer
+                .integer()
+                .map(|integer| big_to_fe::<E::Base>(integer.value()))
+        };
+
+        let pk = base(pk.point.x())
+            .zip(base(pk.point.y()))
+            .map(|(x, y)| E::from_xy(x, y).unwrap());
+        scalar(&sig.r)
+            .zip(scalar(&sig.s))
+            .zip(scalar(msg_hash))
+            .zip(pk)
+            .map(|(((r, s), msg_hash), pk)| {
+                let s_inv = s.invert().unwrap_or(E::Scalar::ZERO);
+                let r_point = ((E::generator() * msg_hash + pk * r) * s_inv).to_affine();
+                // Invalid signatures are rejected by the constraints anyway
+                if bool::from(r_point.is_identity()) {
+                    E::generator()
+                } else {
+                    r_point
+                }
+            })
+    }
+
+    /// Squeezes a new challenge from the hasher and returns its
+    /// `BATCH_COEFFICIENT_BIT_LEN` least significant bits as an integer in the
+    /// scalar field
+    fn squeeze_coefficient<const T: usize, const RATE: usize>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        hasher: &mut HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>,
+    ) -> Result<AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+        let challenge = hasher.hash(ctx)?;
+        assign_integer_challenge(ctx, scalar_chip, &challenge, BATCH_COEFFICIENT_BIT_LEN)
+    }
 }
 
 #[cfg(test)]
@@ -150,16 +492,27 @@ mod tests {
     use ecc::{EccConfig, GeneralEccChip};
     use halo2::arithmetic::CurveAffine;
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
     use halo2::halo2curves::{
         ff::{Field, FromUniformBytes, PrimeField},
         group::{Curve, Group},
     };
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
-    use integer::IntegerInstructions;
+    use integer::{IntegerInstructions, BYTE_BIT_LEN};
+    use keccak::{KeccakChip, KeccakConfig};
     use maingate::mock_prover_verify;
-    use maingate::{MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions};
+    use maingate::{
+        DimensionMeasurement, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions,
+    };
+    use num_bigint::BigUint as big_uint;
+    use poseidon::Spec;
     use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use sha256::{Sha256Chip, Sha256Config};
+    use sha3::Keccak256;
     use std::marker::PhantomData;
+    use transcript::{assign_integer_challenge, HasherChip};
 
     const BIT_LEN_LIMB: usize = 68;
     const NUMBER_OF_LIMBS: usize = 4;
@@ -172,13 +525,20 @@ mod tests {
 
     impl TestCircuitEcdsaVerifyConfig {
         pub fn new<C: CurveAffine, N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            Self::new_with_bit_lens::<C, N>(meta, vec![], vec![])
+        }
+
+        pub fn new_with_bit_lens<C: CurveAffine, N: PrimeField>(
+            meta: &mut ConstraintSystem<N>,
+            mut composition_bit_lens: Vec<usize>,
+            mut overflow_bit_lens: Vec<usize>,
+        ) -> Self {
             let (rns_base, rns_scalar) =
                 GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
             let main_gate_config = MainGate::<N>::configure(meta);
-            let mut overflow_bit_lens: Vec<usize> = vec![];
             overflow_bit_lens.extend(rns_base.overflow_lengths());
             overflow_bit_lens.extend(rns_scalar.overflow_lengths());
-            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+            composition_bit_lens.push(BIT_LEN_LIMB / NUMBER_OF_LIMBS);
 
             let range_config = RangeChip::<N>::configure(
                 meta,
@@ -215,6 +575,7 @@ mod tests {
 
         aux_generator: E,
         window_size: usize,
+        low_s: bool,
         _marker: PhantomData<N>,
     }
 
@@ -248,7 +609,7 @@ mod tests {
                     let ctx = &mut RegionCtx::new(region, offset);
 
                     ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
-                    ecc_chip.assign_aux(ctx, self.window_size, 2)?;
+                    ecc_chip.assign_aux(ctx, self.window_size, 1)?;
                     Ok(())
                 },
             )?;
@@ -282,7 +643,11 @@ mod tests {
                         point: pk_in_circuit,
                     };
                     let msg_hash = scalar_chip.assign_integer(ctx, msg_hash, Range::Remainder)?;
-                    ecdsa_chip.verify(ctx, &sig, &pk_assigned, &msg_hash)
+                    if self.low_s {
+                        ecdsa_chip.verify_low_s(ctx, &sig, &pk_assigned, &msg_hash)
+                    } else {
+                        ecdsa_chip.verify(ctx, &sig, &pk_assigned, &msg_hash)
+                    }
                 },
             )?;
 
@@ -294,11 +659,6 @@ mod tests {
 
     #[test]
     fn test_ecdsa_verifier() {
-        fn mod_n<C: CurveAffine>(x: C::Base) -> C::Scalar {
-            let x_big = fe_to_big(x);
-            big_to_fe(x_big)
-        }
-
         fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
             let g = C::generator();
 
@@ -306,54 +666,732 @@ mod tests {
             let sk = <C as CurveAffine>::ScalarExt::random(OsRng);
             let public_key = (g * sk).to_affine();
 
-            // Generate a valid signature
-            // Suppose `m_hash` is the message hash
-            let msg_hash = <C as CurveAffine>::ScalarExt::random(OsRng);
-
-            // Draw arandomness
-            let k = <C as CurveAffine>::ScalarExt::random(OsRng);
-            let k_inv = k.invert().unwrap();
-
-            // Calculate `r`
-            let r_point = (g * k).to_affine().coordinates().unwrap();
-            let x = r_point.x();
-            let r = mod_n::<C>(*x);
-
-            // Calculate `s`
-            let s = k_inv * (msg_hash + (r * sk));
-
-            // Sanity check. Ensure we construct a valid signature. So lets verify it
-            {
-                let s_inv = s.invert().unwrap();
-                let u_1 = msg_hash * s_inv;
-                let u_2 = r * s_inv;
-                let r_point = ((g * u_1) + (public_key * u_2))
-                    .to_affine()
-                    .coordinates()
-                    .unwrap();
-                let x_candidate = r_point.x();
-                let r_candidate = mod_n::<C>(*x_candidate);
-                assert_eq!(r, r_candidate);
+            // Generate valid signatures
+            // Suppose `m_hash` is the message hash, zero makes `u1 = 0`
+            for msg_hash in [
+                <C as CurveAffine>::ScalarExt::random(OsRng),
+                <C as CurveAffine>::ScalarExt::ZERO,
+            ] {
+                // Draw arandomness
+                let k = <C as CurveAffine>::ScalarExt::random(OsRng);
+                let k_inv = k.invert().unwrap();
+
+                // Calculate `r`
+                let r_point = (g * k).to_affine().coordinates().unwrap();
+                let x = r_point.x();
+                let r = mod_n::<C>(*x);
+
+                // Calculate `s`
+                let s = k_inv * (msg_hash + (r * sk));
+
+                // Sanity check. Ensure we construct a valid signature. So lets verify it
+                {
+                    let s_inv = s.invert().unwrap();
+                    let u_1 = msg_hash * s_inv;
+                    let u_2 = r * s_inv;
+                    let r_point = ((g * u_1) + (public_key * u_2))
+                        .to_affine()
+                        .coordinates()
+                        .unwrap();
+                    let x_candidate = r_point.x();
+                    let r_candidate = mod_n::<C>(*x_candidate);
+                    assert_eq!(r, r_candidate);
+                }
+
+                let aux_generator = C::CurveExt::random(OsRng).to_affine();
+                let circuit = TestCircuitEcdsaVerify::<C, N> {
+                    public_key: Value::known(public_key),
+                    signature: Value::known((r, s)),
+                    msg_hash: Value::known(msg_hash),
+                    aux_generator,
+                    window_size: 4,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
             }
+        }
 
-            let aux_generator = C::CurveExt::random(OsRng).to_affine();
-            let circuit = TestCircuitEcdsaVerify::<C, N> {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256k1, PastaFp>();
+        run::<Secp256k1, PastaFq>();
+        run::<Secp256r1, BnScalar>();
+        run::<Secp256r1, PastaFp>();
+        run::<Secp256r1, PastaFq>();
+    }
+
+    #[test]
+    fn test_ecdsa_verifier_low_s() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let (public_key, r, s, msg_hash) = random_signature::<C>();
+            let half_order = fe_to_big(-C::Scalar::ONE) >> 1;
+            let (low_s, high_s) = if fe_to_big(s) > half_order {
+                (-s, s)
+            } else {
+                (s, -s)
+            };
+
+            let circuit = |s: C::Scalar, low_s: bool| TestCircuitEcdsaVerify::<C, N> {
                 public_key: Value::known(public_key),
                 signature: Value::known((r, s)),
                 msg_hash: Value::known(msg_hash),
-                aux_generator,
+                aux_generator: C::CurveExt::random(OsRng).to_affine(),
                 window_size: 4,
+                low_s,
+                ..Default::default()
+            };
+
+            // both signatures are valid without low-s enforcement
+            mock_prover_verify(&circuit(high_s, false), vec![vec![]]);
+            mock_prover_verify(&circuit(low_s, true), vec![vec![]]);
+
+            // high-s twin of the valid signature is rejected
+            let circuit = circuit(high_s, true);
+            let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+            let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+            assert!(prover
+                .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                .is_err());
+        }
+
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256r1, BnScalar>();
+    }
+
+    fn mod_n<C: CurveAffine>(x: C::Base) -> C::Scalar {
+        let x_big = fe_to_big(x);
+        big_to_fe(x_big)
+    }
+
+    /// Returns public key, signature and message hash of a random valid
+    /// signature
+    fn random_signature<C: CurveAffine>() -> (C, C::Scalar, C::Scalar, C::Scalar) {
+        let msg_hash = <C as CurveAffine>::ScalarExt::random(OsRng);
+        let (public_key, r, s) = sign::<C>(msg_hash);
+        (public_key, r, s, msg_hash)
+    }
+
+    /// Returns public key and signature of the message hash with a random key
+    fn sign<C: CurveAffine>(msg_hash: C::Scalar) -> (C, C::Scalar, C::Scalar) {
+        let g = C::generator();
+        let sk = <C as CurveAffine>::ScalarExt::random(OsRng);
+        let public_key = (g * sk).to_affine();
+
+        let k = <C as CurveAffine>::ScalarExt::random(OsRng);
+        let k_inv = k.invert().unwrap();
+        let r_point = (g * k).to_affine().coordinates().unwrap();
+        let r = mod_n::<C>(*r_point.x());
+        let s = k_inv * (msg_hash + (r * sk));
+
+        (public_key, r, s)
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitEcdsaVerifySha256<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E>,
+        signature: Value<(E::Scalar, E::Scalar)>,
+        message: Vec<u8>,
+
+        aux_generator: E,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: PrimeField> Circuit<N> for TestCircuitEcdsaVerifySha256<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new_with_bit_lens::<E, N>(
+                meta,
+                Sha256Chip::<N>::composition_bit_lens(),
+                vec![],
+            )
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+            let sha256_chip = Sha256Chip::<N>::new(Sha256Config::new(
+                config.main_gate_config.clone(),
+                config.range_config.clone(),
+            ));
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, 4, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = self.signature.map(|signature| signature.0);
+                    let s = self.signature.map(|signature| signature.1);
+                    let r = ecc_chip.new_unassigned_scalar(r);
+                    let s = ecc_chip.new_unassigned_scalar(s);
+                    let sig = AssignedEcdsaSig {
+                        r: scalar_chip.assign_integer(ctx, r, Range::Remainder)?,
+                        s: scalar_chip.assign_integer(ctx, s, Range::Remainder)?,
+                    };
+                    let pk = AssignedPublicKey {
+                        point: ecc_chip.assign_point(ctx, self.public_key)?,
+                    };
+
+                    let message: Vec<Value<u8>> = self
+                        .message
+                        .iter()
+                        .map(|byte| Value::known(*byte))
+                        .collect();
+                    let message = sha256_chip.assign_bytes(ctx, &message)?;
+                    let digest = sha256_chip.digest(ctx, &message)?;
+                    let msg_hash = ecdsa_chip.msg_hash_from_digest(ctx, &digest)?;
+                    ecdsa_chip.verify(ctx, &sig, &pk, &msg_hash)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_verifier_with_sha256() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let message = b"abc".to_vec();
+            let digest = Sha256::digest(&message);
+            let msg_hash = big_to_fe(big_uint::from_bytes_be(&digest));
+            let (public_key, r, s) = sign::<C>(msg_hash);
+
+            let aux_generator = C::CurveExt::random(OsRng).to_affine();
+            let circuit = TestCircuitEcdsaVerifySha256::<C, N> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r, s)),
+                message,
+                aux_generator,
                 ..Default::default()
             };
             let instance = vec![vec![]];
             mock_prover_verify(&circuit, instance);
         }
 
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256r1, BnScalar>();
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitEcdsaBatchVerify<E: CurveAffine, N: PrimeField> {
+        signatures: Vec<(E, E::Scalar, E::Scalar, E::Scalar)>,
+        aux_generator: E,
+        // Verifies signatures one by one if not set
+        batch: bool,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: FromUniformBytes<64> + Ord> Circuit<N>
+        for TestCircuitEcdsaBatchVerify<E, N>
+    {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    if self.batch {
+                        ecc_chip.assign_aux_msm(ctx, 2 * self.signatures.len())?;
+                    } else {
+                        ecc_chip.assign_aux(ctx, 4, 1)?;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut batch = vec![];
+                    for (public_key, r, s, msg_hash) in self.signatures.iter() {
+                        let mut assign_scalar = |e: E::Scalar| {
+                            let e = ecc_chip.new_unassigned_scalar(Value::known(e));
+                            scalar_chip.assign_integer(ctx, e, Range::Remainder)
+                        };
+                        let sig = AssignedEcdsaSig {
+                            r: assign_scalar(*r)?,
+                            s: assign_scalar(*s)?,
+                        };
+                        let msg_hash = assign_scalar(*msg_hash)?;
+                        let pk = AssignedPublicKey {
+                            point: ecc_chip.assign_point(ctx, Value::known(*public_key))?,
+                        };
+                        batch.push((sig, pk, msg_hash));
+                    }
+
+                    if self.batch {
+                        let spec = Spec::<N, 5, 4>::new(8, 57);
+                        let mut hasher = HasherChip::<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, 5, 4>::new(
+                            ctx,
+                            &spec,
+                            &config.main_gate_config,
+                        )?;
+                        ecdsa_chip.verify_batch(ctx, &mut hasher, &batch)
+                    } else {
+                        for (sig, pk, msg_hash) in batch.iter() {
+                            ecdsa_chip.verify(ctx, sig, pk, msg_hash)?;
+                        }
+                        Ok(())
+                    }
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_batch_verifier() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            for number_of_signatures in [1, 3] {
+                let signatures = (0..number_of_signatures)
+                    .map(|_| random_signature::<C>())
+                    .collect();
+                let aux_generator = C::CurveExt::random(OsRng).to_affine();
+                let circuit = TestCircuitEcdsaBatchVerify::<C, N> {
+                    signatures,
+                    aux_generator,
+                    batch: true,
+                    ..Default::default()
+                };
+                let instance = vec![vec![]];
+                mock_prover_verify(&circuit, instance);
+            }
+        }
+
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::pasta::Fp as PastaFp;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256k1, PastaFp>();
+    }
+
+    #[test]
+    fn test_ecdsa_batch_verifier_invalid_signature() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let signatures: Vec<_> = (0..3).map(|_| random_signature::<C>()).collect();
+            let aux_generator = C::CurveExt::random(OsRng).to_affine();
+            let circuit = |signatures| TestCircuitEcdsaBatchVerify::<C, N> {
+                signatures,
+                aux_generator,
+                batch: true,
+                ..Default::default()
+            };
+
+            // a single bad signature fails the whole batch
+            let tampered: [fn(&mut (C, C::Scalar, C::Scalar, C::Scalar)); 3] = [
+                |(_, r, _, _)| *r += C::Scalar::ONE,
+                |(_, _, s, _)| *s += C::Scalar::ONE,
+                |(_, _, _, msg_hash)| *msg_hash += C::Scalar::ONE,
+            ];
+            for tamper in tampered {
+                let mut signatures = signatures.clone();
+                tamper(&mut signatures[1]);
+                let circuit = circuit(signatures);
+                let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+                let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+                assert!(prover
+                    .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                    .is_err());
+            }
+        }
+
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        run::<Secp256k1, BnScalar>();
+    }
+
+    #[test]
+    fn test_ecdsa_batch_verifier_row_usage() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+
+        for number_of_signatures in [4, 16] {
+            let signatures: Vec<_> = (0..number_of_signatures)
+                .map(|_| random_signature::<Secp256k1>())
+                .collect();
+            let aux_generator = <Secp256k1 as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let [naive, batch] = [false, true].map(|batch| {
+                let circuit = TestCircuitEcdsaBatchVerify::<Secp256k1, BnScalar> {
+                    signatures: signatures.clone(),
+                    aux_generator,
+                    batch,
+                    ..Default::default()
+                };
+                DimensionMeasurement::measure(&circuit)
+                    .unwrap()
+                    .advice_rows()
+            });
+            assert!(
+                batch < naive,
+                "{} signatures: naive {} rows, batch {} rows",
+                number_of_signatures,
+                naive,
+                batch
+            );
+        }
+    }
+
+    /// Recovers public key out of circuit as `r^-1 * (s * R - m * G)`
+    fn recover_public_key<C: CurveAffine>(
+        r: C::Scalar,
+        s: C::Scalar,
+        v: bool,
+        msg_hash: C::Scalar,
+    ) -> Option<C> {
+        let x: C::Base = big_to_fe(fe_to_big(r));
+        let y = Option::<C::Base>::from((x.square() * x + C::a() * x + C::b()).sqrt())?;
+        let y = if bool::from(y.is_odd()) == v { y } else { -y };
+        let r_point = Option::<C>::from(C::from_xy(x, y))?;
+        let r_inv = Option::<C::Scalar>::from(r.invert())?;
+        Some(((r_point * s - C::generator() * msg_hash) * r_inv).to_affine())
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitEcdsaRecover<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E>,
+        signature: Value<(E::Scalar, E::Scalar)>,
+        v: Value<bool>,
+        msg_hash: Value<E::Scalar>,
+
+        aux_generator: E,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: PrimeField> Circuit<N> for TestCircuitEcdsaRecover<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, 4, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = self.signature.map(|signature| signature.0);
+                    let s = self.signature.map(|signature| signature.1);
+                    let integer_r = ecc_chip.new_unassigned_scalar(r);
+                    let integer_s = ecc_chip.new_unassigned_scalar(s);
+                    let msg_hash = ecc_chip.new_unassigned_scalar(self.msg_hash);
+
+                    let sig = AssignedEcdsaSig {
+                        r: scalar_chip.assign_integer(ctx, integer_r, Range::Remainder)?,
+                        s: scalar_chip.assign_integer(ctx, integer_s, Range::Remainder)?,
+                    };
+                    let v = main_gate
+                        .assign_bit(ctx, self.v.map(|v| if v { N::ONE } else { N::ZERO }))?;
+                    let msg_hash = scalar_chip.assign_integer(ctx, msg_hash, Range::Remainder)?;
+
+                    let recovered = ecdsa_chip.recover(ctx, &sig, &v, &msg_hash)?;
+                    let public_key = ecc_chip.assign_point(ctx, self.public_key)?;
+                    ecc_chip.assert_equal(ctx, &recovered.point, &public_key)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_recover() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let (public_key, r, s, msg_hash) = random_signature::<C>();
+
+            // Find the recovery id with the reference implementation
+            let v = [false, true]
+                .into_iter()
+                .find(|v| recover_public_key::<C>(r, s, *v, msg_hash) == Some(public_key))
+                .unwrap();
+            assert_ne!(
+                recover_public_key::<C>(r, s, !v, msg_hash),
+                Some(public_key)
+            );
+
+            let aux_generator = C::CurveExt::random(OsRng).to_affine();
+            let circuit = |r, s, v, msg_hash| TestCircuitEcdsaRecover::<C, N> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r, s)),
+                v: Value::known(v),
+                msg_hash: Value::known(msg_hash),
+                aux_generator,
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit(r, s, v, msg_hash), vec![vec![]]);
+
+            // wrong recovery id, tampered signature or message hash recover
+            // another key
+            for circuit in [
+                circuit(r, s, !v, msg_hash),
+                circuit(r + C::Scalar::ONE, s, v, msg_hash),
+                circuit(r, s + C::Scalar::ONE, v, msg_hash),
+                circuit(r, s, v, msg_hash + C::Scalar::ONE),
+            ] {
+                let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+                let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+                assert!(prover
+                    .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                    .is_err());
+            }
+        }
+
         use crate::curves::bn256::Fr as BnScalar;
         use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
         use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
         run::<Secp256k1, BnScalar>();
         run::<Secp256k1, PastaFp>();
         run::<Secp256k1, PastaFq>();
+        run::<Secp256r1, BnScalar>();
+        run::<Secp256r1, PastaFp>();
+        run::<Secp256r1, PastaFq>();
+    }
+
+    #[test]
+    fn test_ecdsa_recover_zero_message_hash() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let msg_hash = C::Scalar::ZERO;
+            let (public_key, r, s) = sign::<C>(msg_hash);
+            let v = [false, true]
+                .into_iter()
+                .find(|v| recover_public_key::<C>(r, s, *v, msg_hash) == Some(public_key))
+                .unwrap();
+
+            let circuit = TestCircuitEcdsaRecover::<C, N> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r, s)),
+                v: Value::known(v),
+                msg_hash: Value::known(msg_hash),
+                aux_generator: C::CurveExt::random(OsRng).to_affine(),
+                ..Default::default()
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+        }
+
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        use crate::curves::secp256r1::Secp256r1Affine as Secp256r1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256r1, BnScalar>();
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitEthAddressConfig {
+        ecdsa_config: TestCircuitEcdsaVerifyConfig,
+        keccak_config: KeccakConfig,
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitEthAddress<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E>,
+        expected: Vec<u8>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: PrimeField> Circuit<N> for TestCircuitEthAddress<E, N> {
+        type Config = TestCircuitEthAddressConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let (rns_base, _) = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+            let mut composition_bit_lens = KeccakChip::<N>::composition_bit_lens();
+            composition_bit_lens.push(BYTE_BIT_LEN);
+            let mut overflow_bit_lens = KeccakChip::<N>::overflow_bit_lens();
+            overflow_bit_lens.extend(rns_base.byte_overflow_lengths());
+            let ecdsa_config = TestCircuitEcdsaVerifyConfig::new_with_bit_lens::<E, N>(
+                meta,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+            let keccak_config = KeccakChip::configure(
+                meta,
+                &ecdsa_config.main_gate_config,
+                &ecdsa_config.range_config,
+            );
+            TestCircuitEthAddressConfig {
+                ecdsa_config,
+                keccak_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecdsa_config.ecc_chip_config(),
+            );
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+            let keccak_chip = KeccakChip::<N>::new(config.keccak_config);
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let pk = AssignedPublicKey {
+                        point: ecc_chip.assign_point(ctx, self.public_key)?,
+                    };
+                    let address = ecdsa_chip.eth_address(ctx, &keccak_chip, &pk)?;
+                    assert_eq!(address.len(), self.expected.len());
+                    for (byte, expected) in address.iter().zip(self.expected.iter()) {
+                        main_gate.assert_equal_to_constant(ctx, byte, N::from(*expected as u64))?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.ecdsa_config.config_range(&mut layouter)?;
+            keccak_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_eth_address() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let g = C::generator();
+            let sk = <C as CurveAffine>::ScalarExt::random(OsRng);
+            let public_key = (g * sk).to_affine();
+
+            let coordinates = public_key.coordinates().unwrap();
+            let mut encoded = vec![];
+            for coordinate in [coordinates.x(), coordinates.y()] {
+                let mut bytes = coordinate.to_repr().as_ref().to_vec();
+                bytes.reverse();
+                encoded.extend(bytes);
+            }
+            let expected = Keccak256::digest(&encoded)[12..].to_vec();
+
+            let circuit = TestCircuitEthAddress::<C, N> {
+                public_key: Value::known(public_key),
+                expected,
+                ..Default::default()
+            };
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        run::<Secp256k1, BnScalar>();
     }
 }
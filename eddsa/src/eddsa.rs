@@ -0,0 +1,375 @@
+use super::integer::IntegerConfig;
+use crate::halo2;
+use crate::integer;
+use crate::maingate;
+use ecc::maingate::RegionCtx;
+use ecc::{AssignedPoint, EccConfig, TwistedEdwardsChip, TwistedEdwardsCurve};
+use halo2::halo2curves::ff::PrimeField;
+use halo2::plonk::Error;
+use integer::AssignedInteger;
+use maingate::{AssignedCondition, MainGateConfig, RangeConfig};
+
+/// Window size of scalar multiplications in verification
+const MUL_WINDOW_SIZE: usize = 4;
+
+#[derive(Clone, Debug)]
+pub struct EddsaConfig {
+    pub main_gate_config: MainGateConfig,
+    pub range_config: RangeConfig,
+}
+
+impl EddsaConfig {
+    pub fn new(range_config: RangeConfig, main_gate_config: MainGateConfig) -> Self {
+        Self {
+            range_config,
+            main_gate_config,
+        }
+    }
+
+    pub fn ecc_chip_config(&self) -> EccConfig {
+        EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+    }
+
+    pub fn integer_chip_config(&self) -> IntegerConfig {
+        IntegerConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+    }
+}
+
+/// EdDSA signature where `r` is the encoding of `R` in little endian bits
+pub struct AssignedEddsaSig<
+    S: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub r: Vec<AssignedCondition<N>>,
+    pub s: AssignedInteger<S, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+/// Public key given as its encoding in little endian bits
+pub struct AssignedPublicKey<N: PrimeField> {
+    pub encoding: Vec<AssignedCondition<N>>,
+}
+
+pub struct EddsaChip<
+    C: TwistedEdwardsCurve,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(TwistedEdwardsChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>);
+
+impl<
+        C: TwistedEdwardsCurve,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > EddsaChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub fn new(ecc_chip: TwistedEdwardsChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self(ecc_chip)
+    }
+
+    pub fn scalar_field_chip(
+        &self,
+    ) -> &integer::IntegerChip<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.0.scalar_field_chip()
+    }
+
+    fn ecc_chip(&self) -> TwistedEdwardsChip<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.0.clone()
+    }
+
+    /// Decompresses the public key
+    pub fn public_key(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pk: &AssignedPublicKey<N>,
+    ) -> Result<AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.0.decompress(ctx, &pk.encoding)
+    }
+
+    /// Verifies an EdDSA signature with the cofactored equation of RFC 8032
+    /// $[8][S]B = [8]R + [8][k]A$.
+    ///
+    /// `challenge` is $k = H(R || A || M)$ reduced modulo the group order,
+    /// which is SHA-512 for Ed25519. Hashing is not constrained here so
+    /// `challenge` must be bound to the encodings of the signature and
+    /// the public key by the caller, for example by exposing it as public
+    /// input along with the encodings.
+    pub fn verify(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        sig: &AssignedEddsaSig<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<N>,
+        challenge: &AssignedInteger<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let ecc_chip = self.ecc_chip();
+
+        // 1. decode `R` and `A` rejecting non canonical encodings
+        let r_point = ecc_chip.decompress(ctx, &sig.r)?;
+        let pk = self.public_key(ctx, pk)?;
+
+        // 2. [S]B, decomposition of `S` in scalar multiplication also
+        // enforces `S` to be less than the group order
+        let generator = ecc_chip.assign_generator(ctx)?;
+        let s_g = ecc_chip.mul(ctx, &generator, &sig.s, MUL_WINDOW_SIZE)?;
+
+        // 3. R + [k]A
+        let k_pk = ecc_chip.mul(ctx, &pk, challenge, MUL_WINDOW_SIZE)?;
+        let rhs = ecc_chip.add(ctx, &r_point, &k_pk)?;
+
+        // 4. clear small order components and check the equation
+        let lhs = ecc_chip.mul_cofactor(ctx, &s_g)?;
+        let rhs = ecc_chip.mul_cofactor(ctx, &rhs)?;
+        ecc_chip.assert_equal(ctx, &lhs, &rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssignedEddsaSig, AssignedPublicKey, EddsaChip};
+    use crate::halo2;
+    use crate::integer;
+    use crate::maingate;
+    use ecc::integer::Range;
+    use ecc::maingate::{big_to_fe, RegionCtx};
+    use ecc::{EccConfig, Ed25519, TwistedEdwardsChip, TwistedEdwardsCurve};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
+    use halo2::halo2curves::ff::{FromUniformBytes, PrimeField};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use integer::IntegerInstructions;
+    use maingate::mock_prover_verify;
+    use maingate::{
+        DimensionMeasurement, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions,
+    };
+    use num_bigint::BigUint as big_uint;
+    use sha2::{Digest, Sha512};
+    use std::marker::PhantomData;
+
+    const BIT_LEN_LIMB: usize = 68;
+    const NUMBER_OF_LIMBS: usize = 4;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitEddsaVerifyConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestCircuitEddsaVerifyConfig {
+        pub fn new<C: TwistedEdwardsCurve, N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let (rns_base, rns_scalar) =
+                TwistedEdwardsChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+            TestCircuitEddsaVerifyConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        pub fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        pub fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitEddsaVerify<C: TwistedEdwardsCurve, N: PrimeField> {
+        public_key: Vec<bool>,
+        signature: (Vec<bool>, Value<C::Scalar>),
+        challenge: Value<C::Scalar>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<C: TwistedEdwardsCurve, N: PrimeField> Circuit<N> for TestCircuitEddsaVerify<C, N> {
+        type Config = TestCircuitEddsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEddsaVerifyConfig::new::<C, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip = TwistedEdwardsChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+            let eddsa_chip = EddsaChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut assign_bits = |bits: &[bool]| {
+                        bits.iter()
+                            .map(|bit| {
+                                main_gate.assign_bit(ctx, Value::known(N::from(*bit as u64)))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()
+                    };
+                    let pk = AssignedPublicKey {
+                        encoding: assign_bits(&self.public_key)?,
+                    };
+                    let r = assign_bits(&self.signature.0)?;
+
+                    let s = scalar_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_scalar(self.signature.1),
+                        Range::Remainder,
+                    )?;
+                    let sig = AssignedEddsaSig { r, s };
+                    let challenge = scalar_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_scalar(self.challenge),
+                        Range::Remainder,
+                    )?;
+                    eddsa_chip.verify(ctx, &sig, &pk, &challenge)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Little endian bits of a byte string
+    fn to_bits(bytes: &[u8]) -> Vec<bool> {
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_ed25519_verifier() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::pasta::Fp as PastaFp;
+
+        // Test vectors from RFC 8032 section 7.1 as
+        // (public key, message, signature)
+        let vectors = [
+            (
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                 5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                 085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+                 18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ];
+
+        fn circuit<N: PrimeField>(
+            public_key: &[u8],
+            msg: &[u8],
+            signature: &[u8],
+        ) -> TestCircuitEddsaVerify<Ed25519, N> {
+            type C = Ed25519;
+            let (r, s) = signature.split_at(32);
+
+            let mut hasher = Sha512::new();
+            hasher.update(r);
+            hasher.update(public_key);
+            hasher.update(msg);
+            let challenge: <C as TwistedEdwardsCurve>::Scalar =
+                big_to_fe(big_uint::from_bytes_le(&hasher.finalize()));
+            let s: <C as TwistedEdwardsCurve>::Scalar = big_to_fe(big_uint::from_bytes_le(s));
+
+            TestCircuitEddsaVerify::<C, N> {
+                public_key: to_bits(public_key),
+                signature: (to_bits(r), Value::known(s)),
+                challenge: Value::known(challenge),
+                _marker: PhantomData,
+            }
+        }
+
+        fn run<N: FromUniformBytes<64> + Ord>(
+            public_key: &[u8],
+            other_public_key: &[u8],
+            msg: &[u8],
+            signature: &[u8],
+        ) {
+            mock_prover_verify(&circuit::<N>(public_key, msg, signature), vec![vec![]]);
+
+            // tampered R, S, message and key are rejected
+            let mut tampered_r = signature.to_vec();
+            tampered_r[0] ^= 1;
+            let mut tampered_s = signature.to_vec();
+            tampered_s[32] ^= 1;
+            let mut tampered_msg = msg.to_vec();
+            tampered_msg.push(0);
+            for circuit in [
+                circuit::<N>(public_key, msg, &tampered_r),
+                circuit::<N>(public_key, msg, &tampered_s),
+                circuit::<N>(public_key, &tampered_msg, signature),
+                circuit::<N>(other_public_key, msg, signature),
+            ] {
+                let dimension = DimensionMeasurement::measure(&circuit).unwrap();
+                let prover = MockProver::run(dimension.k(), &circuit, vec![vec![]]).unwrap();
+                assert!(prover
+                    .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+                    .is_err());
+            }
+        }
+
+        for (i, (public_key, msg, signature)) in vectors.iter().enumerate() {
+            let public_key = from_hex(public_key);
+            let other_public_key = from_hex(vectors[(i + 1) % vectors.len()].0);
+            let msg = from_hex(msg);
+            let signature = from_hex(signature);
+            run::<BnScalar>(&public_key, &other_public_key, &msg, &signature);
+            run::<PastaFp>(&public_key, &other_public_key, &msg, &signature);
+        }
+    }
+}
@@ -86,6 +86,10 @@ pub struct Dimension {
 }
 
 impl Dimension {
+    pub fn advice_rows(&self) -> u64 {
+        self.advice
+    }
+
     fn k(&self) -> u32 {
         u64::BITS
             - ([self.instance, self.advice, self.fixed]
@@ -18,6 +18,8 @@ mod assert_in_field;
 mod assert_not_zero;
 mod assert_zero;
 mod assign;
+mod bytes;
+mod compare;
 mod div;
 mod invert;
 mod mul;
@@ -44,7 +46,7 @@ impl IntegerConfig {
     }
 }
 
-/// Chip for integer instructions
+/// Chip for integer instructions. Requires the five width main gate
 #[derive(Clone, Debug)]
 pub struct IntegerChip<
     W: PrimeField,
@@ -136,6 +138,22 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Ok(decomposed)
     }
 
+    fn to_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        self.to_bytes_le_generic(ctx, integer)
+    }
+
+    fn from_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.from_bytes_le_generic(ctx, bytes)
+    }
+
     fn add(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -514,6 +532,51 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.assert_in_field_generic(ctx, a)
     }
 
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let b = &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?;
+        self.is_less_than_generic(ctx, a, b)
+    }
+
+    fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let is_less_than = self.is_less_than(ctx, a, b)?;
+        self.main_gate().assert_one(ctx, &is_less_than)
+    }
+
+    fn max(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let b = &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?;
+        let is_less_than = self.is_less_than_generic(ctx, a, b)?;
+        self.select(ctx, b, a, &is_less_than)
+    }
+
+    fn min(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let b = &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?;
+        let is_less_than = self.is_less_than_generic(ctx, a, b)?;
+        self.select(ctx, a, b, &is_less_than)
+    }
+
     fn sign(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -594,13 +657,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 mod tests {
     use super::{IntegerChip, IntegerConfig, IntegerInstructions, Range};
     use crate::rns::{Common, Integer, Rns};
-    use crate::{PrimeField, UnassignedInteger};
+    use crate::{PrimeField, UnassignedInteger, BYTE_BIT_LEN};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
+    use halo2::halo2curves::ff::FromUniformBytes;
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
     use maingate::mock_prover_verify;
     use maingate::{
-        big_to_fe, decompose_big, fe_to_big, halo2, AssignedCondition, MainGate, MainGateConfig,
-        MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        big_to_fe, decompose_big, fe_to_big, halo2, AssignedCondition, DimensionMeasurement,
+        MainGate, MainGateBuilder, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig,
+        RangeInstructions, RegionCtx,
     };
     use num_bigint::{BigUint as big_uint, RandBigInt};
     use num_traits::Zero;
@@ -719,15 +785,24 @@ mod tests {
         fn new<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
             meta: &mut ConstraintSystem<N>,
         ) -> Self {
-            let main_gate_config = MainGate::<N>::configure(meta);
+            Self::new_with_main_gate_builder::<W, N, BIT_LEN_LIMB>(meta, MainGateBuilder::default())
+        }
 
-            let overflow_bit_lens = rns::<W, N, BIT_LEN_LIMB>().overflow_lengths();
+        fn new_with_main_gate_builder<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+            meta: &mut ConstraintSystem<N>,
+            main_gate_builder: MainGateBuilder,
+        ) -> Self {
+            let main_gate_config = main_gate_builder.configure(meta);
+
+            let rns = rns::<W, N, BIT_LEN_LIMB>();
+            let mut overflow_bit_lens = rns.overflow_lengths();
+            overflow_bit_lens.extend(rns.byte_overflow_lengths());
             let composition_bit_len =
                 IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::sublimb_bit_len();
             let range_config = RangeChip::<N>::configure(
                 meta,
                 &main_gate_config,
-                vec![composition_bit_len],
+                vec![composition_bit_len, BYTE_BIT_LEN],
                 overflow_bit_lens,
             );
 
@@ -1522,6 +1597,179 @@ mod tests {
         }
     );
 
+    impl_circuit!(
+        TestCircuitBytes,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            let number_of_bytes =
+                (self.rns.wrong_modulus.bits() as usize + BYTE_BIT_LEN - 1) / BYTE_BIT_LEN;
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let max = t.new_from_big(&self.rns.wrong_modulus - 1usize);
+                    for integer in [t.rand_in_field(), t.rand_in_field(), t.zero(), max] {
+                        let mut expected = integer.value().to_bytes_le();
+                        expected.resize(number_of_bytes, 0);
+                        let assigned =
+                            integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+
+                        let bytes_le = integer_chip.to_bytes_le(ctx, &assigned)?;
+                        assert_eq!(bytes_le.len(), number_of_bytes);
+                        for (byte, expected) in bytes_le.iter().zip(expected.iter()) {
+                            main_gate.assert_equal_to_constant(
+                                ctx,
+                                byte,
+                                N::from(*expected as u64),
+                            )?;
+                        }
+                        let bytes_be = integer_chip.to_bytes_be(ctx, &assigned)?;
+                        for (byte, expected) in bytes_be.iter().zip(expected.iter().rev()) {
+                            main_gate.assert_equal_to_constant(
+                                ctx,
+                                byte,
+                                N::from(*expected as u64),
+                            )?;
+                        }
+
+                        let recomposed = integer_chip.from_bytes_le(ctx, &bytes_le)?;
+                        integer_chip.assert_strict_equal(ctx, &recomposed, &assigned)?;
+                        let recomposed = integer_chip.from_bytes_be(ctx, &bytes_be)?;
+                        integer_chip.assert_strict_equal(ctx, &recomposed, &assigned)?;
+                    }
+
+                    // Missing most significant bytes are zero
+                    let bytes = [0x01, 0x02]
+                        .iter()
+                        .map(|byte| main_gate.assign_value(ctx, Value::known(N::from(*byte))))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let expected = integer_chip.assign_constant(ctx, W::from(0x0201))?;
+                    let recomposed = integer_chip.from_bytes_le(ctx, &bytes)?;
+                    integer_chip.assert_strict_equal(ctx, &recomposed, &expected)?;
+                    let expected = integer_chip.assign_constant(ctx, W::from(0x0102))?;
+                    let recomposed = integer_chip.from_bytes_be(ctx, &bytes)?;
+                    integer_chip.assert_strict_equal(ctx, &recomposed, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitBytesInvalid<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        bytes: Vec<u64>,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> Circuit<N>
+        for TestCircuitBytesInvalid<W, N, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<W, N, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let bytes = self
+                        .bytes
+                        .iter()
+                        .map(|byte| main_gate.assign_value(ctx, Value::known(N::from(*byte))))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    integer_chip.from_bytes_le(ctx, &bytes)?;
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    }
+
+    fn assert_rejected<N: FromUniformBytes<64> + Ord, C: Circuit<N>>(circuit: &C) {
+        let dimension = DimensionMeasurement::measure(circuit).unwrap();
+        let prover = MockProver::run(dimension.k(), circuit, vec![vec![]]).unwrap();
+        assert!(prover
+            .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+            .is_err());
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitAssertLessThan<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        a: big_uint,
+        b: big_uint,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> Circuit<N>
+        for TestCircuitAssertLessThan<W, N, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<W, N, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let a = Integer::from_big(self.a.clone(), Rc::clone(&self.rns));
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = Integer::from_big(self.b.clone(), Rc::clone(&self.rns));
+                    let b = integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    integer_chip.assert_less_than(ctx, &a, &b)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    }
+
     impl_circuit!(
         TestCircuitSign,
         fn synthesize(
@@ -1557,6 +1805,141 @@ mod tests {
         }
     );
 
+    impl_circuit!(
+        TestCircuitComparison,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let max = t.new_from_big(&self.rns.wrong_modulus - 1usize);
+                    let pairs = [
+                        (t.rand_in_field(), t.rand_in_field()),
+                        (t.rand_in_field(), t.rand_in_field()),
+                        (t.zero(), max.clone()),
+                        (max.clone(), t.zero()),
+                        (max.clone(), max),
+                    ];
+                    for (a, b) in pairs {
+                        let a_value = a.value();
+                        let b_value = b.value();
+                        let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                        let b = integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+
+                        let is_less_than = integer_chip.is_less_than(ctx, &a, &b)?;
+                        let (min, max) = if a_value < b_value {
+                            main_gate.assert_one(ctx, &is_less_than)?;
+                            integer_chip.assert_less_than(ctx, &a, &b)?;
+                            (&a, &b)
+                        } else {
+                            main_gate.assert_zero(ctx, &is_less_than)?;
+                            (&b, &a)
+                        };
+
+                        let result = integer_chip.max(ctx, &a, &b)?;
+                        integer_chip.assert_strict_equal(ctx, &result, max)?;
+                        let result = integer_chip.min(ctx, &a, &b)?;
+                        integer_chip.assert_strict_equal(ctx, &result, min)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitRowUsage<
+        W: PrimeField,
+        N: PrimeField,
+        const BIT_LEN_LIMB: usize,
+        const DOUBLE_MUL: bool,
+        const POW: u64,
+    > {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    }
+
+    impl<
+            W: PrimeField,
+            N: PrimeField,
+            const BIT_LEN_LIMB: usize,
+            const DOUBLE_MUL: bool,
+            const POW: u64,
+        > Circuit<N> for TestCircuitRowUsage<W, N, BIT_LEN_LIMB, DOUBLE_MUL, POW>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let builder = MainGateBuilder::default().double_mul(DOUBLE_MUL);
+            let builder = if POW > 1 { builder.pow(POW) } else { builder };
+            TestCircuitConfig::new_with_main_gate_builder::<W, N, BIT_LEN_LIMB>(meta, builder)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+            let t = TestRNS {
+                rns: Rc::clone(&self.rns),
+            };
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = integer_chip.assign_integer(
+                        ctx,
+                        t.rand_in_field().into(),
+                        Range::Remainder,
+                    )?;
+                    let b = integer_chip.assign_integer(
+                        ctx,
+                        t.rand_in_field().into(),
+                        Range::Remainder,
+                    )?;
+
+                    let c = integer_chip.mul(ctx, &a, &b)?;
+                    let c = integer_chip.square(ctx, &c)?;
+                    let c = integer_chip.add(ctx, &c, &a)?;
+                    let (c, _) = integer_chip.div(ctx, &c, &b)?;
+                    let (c, _) = integer_chip.invert(ctx, &c)?;
+                    let c = integer_chip.reduce(ctx, &c)?;
+
+                    let cond = main_gate.assign_bit(ctx, Value::known(N::ONE))?;
+                    let selected = integer_chip.select(ctx, &c, &a, &cond)?;
+                    integer_chip.assert_equal(ctx, &selected, &c)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    }
+
     macro_rules! test_circuit_runner {
         (
             $circuit:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
@@ -1635,6 +2018,126 @@ mod tests {
         test_circuit!(TestCircuitDecomposition);
     }
     #[test]
+    fn test_integer_circuit_bytes() {
+        test_circuit!(TestCircuitBytes);
+    }
+    #[test]
+    fn test_integer_circuit_bytes_invalid() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::pasta::Fp as PastaFp;
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+
+        fn run<W: PrimeField, N: FromUniformBytes<64> + Ord>() {
+            let (rns, _): (Rns<W, N, NUMBER_OF_LIMBS, 68>, u32) = setup();
+            let rns = Rc::new(rns);
+            let number_of_bytes =
+                (rns.wrong_modulus.bits() as usize + BYTE_BIT_LEN - 1) / BYTE_BIT_LEN;
+            let to_bytes = |e: big_uint| {
+                let mut bytes: Vec<u64> = e.to_bytes_le().into_iter().map(u64::from).collect();
+                bytes.resize(number_of_bytes, 0);
+                bytes
+            };
+            let circuit = |bytes: Vec<u64>| TestCircuitBytesInvalid::<W, N, 68> {
+                rns: Rc::clone(&rns),
+                bytes,
+            };
+            let assert_bytes_rejected = |bytes: Vec<u64>| assert_rejected(&circuit(bytes));
+
+            // largest element is accepted
+            mock_prover_verify(
+                &circuit(to_bytes(&rns.wrong_modulus - 1usize)),
+                vec![vec![]],
+            );
+
+            // bytes of the modulus and above are not in field
+            assert_bytes_rejected(to_bytes(rns.wrong_modulus.clone()));
+            assert_bytes_rejected(to_bytes(&rns.wrong_modulus + 1usize));
+
+            // a byte out of range is rejected both when the byte is a single
+            // piece and when it is split across limbs
+            for i in [0, 68 / BYTE_BIT_LEN] {
+                let mut bytes = vec![0; number_of_bytes];
+                bytes[i] = 256;
+                assert_bytes_rejected(bytes);
+            }
+
+            // more bytes than the byte length of the field is an error
+            let circuit = circuit(vec![0; number_of_bytes + 1]);
+            assert!(matches!(
+                DimensionMeasurement::measure(&circuit),
+                Err(Error::Synthesis)
+            ));
+        }
+
+        run::<BnBase, BnScalar>();
+        run::<Secp256k1Base, PastaFp>();
+    }
+    #[test]
+    fn test_integer_circuit_comparison() {
+        test_circuit!(TestCircuitComparison);
+    }
+    #[test]
+    fn test_integer_circuit_assert_less_than_invalid() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::pasta::Fp as PastaFp;
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+
+        fn run<W: PrimeField, N: FromUniformBytes<64> + Ord>() {
+            let (rns, _): (Rns<W, N, NUMBER_OF_LIMBS, 68>, u32) = setup();
+            let rns = Rc::new(rns);
+            let circuit = |a: big_uint, b: big_uint| TestCircuitAssertLessThan::<W, N, 68> {
+                rns: Rc::clone(&rns),
+                a,
+                b,
+            };
+            let max = &rns.wrong_modulus - 1usize;
+            let a = OsRng.gen_biguint_below(&max);
+
+            mock_prover_verify(&circuit(a.clone(), &a + 1usize), vec![vec![]]);
+            mock_prover_verify(&circuit(big_uint::zero(), max.clone()), vec![vec![]]);
+
+            // `a >= b` is rejected
+            assert_rejected(&circuit(a.clone(), a.clone()));
+            assert_rejected(&circuit(&a + 1usize, a.clone()));
+            assert_rejected(&circuit(max.clone(), big_uint::zero()));
+            assert_rejected(&circuit(max.clone(), max));
+        }
+
+        run::<BnBase, BnScalar>();
+        run::<Secp256k1Base, PastaFp>();
+    }
+    #[test]
+    fn test_integer_row_usage_per_gate_shape() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        let rns = Rc::new(rns::<BnBase, BnScalar, 68>());
+
+        let single_mul = TestCircuitRowUsage::<BnBase, BnScalar, 68, false, 0> {
+            rns: Rc::clone(&rns),
+        };
+        let double_mul = TestCircuitRowUsage::<BnBase, BnScalar, 68, true, 0> {
+            rns: Rc::clone(&rns),
+        };
+        let pow = TestCircuitRowUsage::<BnBase, BnScalar, 68, true, 5> {
+            rns: Rc::clone(&rns),
+        };
+        mock_prover_verify(&single_mul, vec![vec![]]);
+        mock_prover_verify(&double_mul, vec![vec![]]);
+        mock_prover_verify(&pow, vec![vec![]]);
+
+        let single_mul = DimensionMeasurement::measure(&single_mul)
+            .unwrap()
+            .advice_rows();
+        let double_mul = DimensionMeasurement::measure(&double_mul)
+            .unwrap()
+            .advice_rows();
+        let pow = DimensionMeasurement::measure(&pow).unwrap().advice_rows();
+        // power term is not used by the integer chip
+        assert_eq!(pow, double_mul);
+        // select of each limb and the native value takes an extra row without
+        // the second multiplication
+        assert_eq!(single_mul, double_mul + NUMBER_OF_LIMBS as u64 + 1);
+    }
+    #[test]
     fn test_integer_circuit_sign() {
         test_circuit!(TestCircuitSign);
     }
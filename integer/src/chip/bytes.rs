@@ -0,0 +1,171 @@
+use super::IntegerChip;
+use crate::{AssignedInteger, AssignedLimb, PrimeField, BYTE_BIT_LEN};
+use halo2::plonk::Error;
+use maingate::{
+    big_to_fe, fe_to_big, halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx,
+    Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+
+/// Returns terms that compose a value from little endian pieces
+fn piece_terms<N: PrimeField>(pieces: &[(AssignedValue<N>, usize)]) -> Vec<Term<'_, N>> {
+    let mut base = N::ONE;
+    pieces
+        .iter()
+        .map(|(piece, width)| {
+            let term = Term::Assigned(piece, base);
+            base *= N::from(1 << width);
+            term
+        })
+        .collect()
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Number of bytes of an integer in the field
+    fn number_of_bytes(&self) -> usize {
+        (self.rns.wrong_modulus.bits() as usize + BYTE_BIT_LEN - 1) / BYTE_BIT_LEN
+    }
+
+    /// Assigns little endian pieces of `value` with given bit lengths. Each
+    /// piece is ranged and their composition is constrained to be `value`
+    fn split_into_pieces(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: &AssignedValue<N>,
+        widths: &[usize],
+    ) -> Result<Vec<(AssignedValue<N>, usize)>, Error> {
+        let mut offset = 0;
+        let pieces = widths
+            .iter()
+            .map(|&width| {
+                let piece = value.value().map(|value| {
+                    let mask = (big_uint::one() << width) - 1usize;
+                    big_to_fe((fe_to_big(*value) >> offset) & mask)
+                });
+                offset += width;
+                let piece = self.range_chip.assign(ctx, piece, BYTE_BIT_LEN, width)?;
+                Ok((piece, width))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let terms: Vec<Term<N>> = piece_terms(&pieces)
+            .into_iter()
+            .chain(std::iter::once(Term::assigned_to_sub(value)))
+            .collect();
+        self.main_gate().assert_zero_sum(ctx, &terms, N::ZERO)?;
+
+        Ok(pieces)
+    }
+
+    pub(super) fn to_bytes_le_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        let main_gate = self.main_gate();
+
+        // Bytes are taken from the same limbs that are asserted to be in
+        // field so that the encoding is canonical
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.assert_in_field_generic(ctx, a)?;
+
+        let mut pieces = vec![];
+        for (limb, widths) in a.limbs().iter().zip(self.rns.byte_pieces()) {
+            pieces.extend(self.split_into_pieces(ctx, limb.as_ref(), &widths)?);
+        }
+
+        // Pieces don't cross byte boundaries so they are grouped into bytes
+        // in order
+        let mut bytes = vec![];
+        let mut byte = vec![];
+        let mut bit_len = 0;
+        for (i, (piece, width)) in pieces.iter().enumerate() {
+            byte.push((piece.clone(), *width));
+            bit_len += width;
+            if bit_len == BYTE_BIT_LEN || i == pieces.len() - 1 {
+                bytes.push(if byte.len() == 1 {
+                    piece.clone()
+                } else {
+                    main_gate.compose(ctx, &piece_terms(&byte), N::ZERO)?
+                });
+                byte.clear();
+                bit_len = 0;
+            }
+        }
+        assert_eq!(bytes.len(), self.number_of_bytes());
+
+        Ok(bytes)
+    }
+
+    pub(super) fn from_bytes_le_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if bytes.len() > self.number_of_bytes() {
+            return Err(Error::Synthesis);
+        }
+        let main_gate = self.main_gate();
+        let zero = main_gate.assign_constant(ctx, N::ZERO)?;
+
+        // Bytes are split into pieces that fall into a single limb. A byte
+        // that is a single piece is ranged as is
+        let byte_pieces = self.rns.byte_pieces();
+        let mut widths = byte_pieces.iter().flatten();
+        let mut pieces = vec![];
+        for i in 0..self.number_of_bytes() {
+            let mut byte_widths = vec![];
+            while byte_widths.iter().sum::<usize>() < BYTE_BIT_LEN {
+                match widths.next() {
+                    Some(width) => byte_widths.push(*width),
+                    None => break,
+                }
+            }
+
+            match bytes.get(i) {
+                None => pieces.extend(byte_widths.iter().map(|width| (zero.clone(), *width))),
+                Some(byte) if byte_widths == [BYTE_BIT_LEN] => {
+                    let ranged = self.range_chip.assign(
+                        ctx,
+                        byte.value().copied(),
+                        BYTE_BIT_LEN,
+                        BYTE_BIT_LEN,
+                    )?;
+                    main_gate.assert_equal(ctx, &ranged, byte)?;
+                    pieces.push((byte.clone(), BYTE_BIT_LEN));
+                }
+                Some(byte) => pieces.extend(self.split_into_pieces(ctx, byte, &byte_widths)?),
+            }
+        }
+
+        let mut pieces = pieces.into_iter();
+        let limbs = byte_pieces
+            .iter()
+            .map(|widths| {
+                let limb_pieces: Vec<_> = pieces.by_ref().take(widths.len()).collect();
+                let max_val = (big_uint::one() << widths.iter().sum::<usize>()) - 1usize;
+                let limb = if limb_pieces.is_empty() {
+                    zero.clone()
+                } else {
+                    main_gate.compose(ctx, &piece_terms(&limb_pieces), N::ZERO)?
+                };
+                Ok(AssignedLimb::from(limb, max_val))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let terms: Vec<Term<N>> = limbs
+            .iter()
+            .zip(self.rns.left_shifters.iter())
+            .map(|(limb, sh)| Term::Assigned(limb.as_ref(), *sh))
+            .collect();
+        let native = main_gate.compose(ctx, &terms, N::ZERO)?;
+        let integer = self.new_assigned_integer(&limbs.try_into().unwrap(), native);
+        self.assert_in_field_generic(ctx, &integer)?;
+
+        Ok(integer)
+    }
+}
@@ -0,0 +1,81 @@
+use super::IntegerChip;
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{
+    halo2, AssignedCondition, AssignedValue, CombinationOptionCommon, MainGateInstructions,
+    RangeInstructions, RegionCtx, Term,
+};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn is_less_than_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        // Constraints for `NUMBER_OF_LIMBS = 4`
+        // 0 = -c_0 + a_0 - b_0 + r_0 * R
+        // 0 = -c_1 + a_1 - b_1 + r_1 * R - r_0
+        // 0 = -c_2 + a_2 - b_2 + r_2 * R - r_1
+        // 0 = -c_3 + a_3 - b_3 + r_3 * R - r_2
+        //
+        // Result limbs are in limb range so the last borrow is set if and only
+        // if `a < b` given that input limbs are in limb range too
+
+        let main_gate = self.main_gate();
+        let range_chip = self.range_chip();
+
+        let a_integer = a.integer();
+        let b_integer = b.integer();
+        let comparision_witness = a_integer.zip(b_integer).map(|(a, b)| a.compare(&b));
+
+        // all result limbs including the most significant one are in limb range
+        let result = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let c_i = comparision_witness.as_ref().map(|r| r.result.limb(i).fe());
+                range_chip.assign(ctx, c_i, Self::sublimb_bit_len(), BIT_LEN_LIMB)
+            })
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // assert borrow values are bits
+        let borrow = comparision_witness.as_ref().map(|r| r.borrow);
+        let borrow = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let b_i = borrow.map(|borrow| if borrow[i] { N::ONE } else { N::ZERO });
+                main_gate.assign_bit(ctx, b_i)
+            })
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        let left_shifter = self.rns.left_shifter(1);
+        let one = N::ONE;
+
+        // Witness layout:
+        // | A   | B   | C   | D   | E       |
+        // | --- | --- | --- | --- | ------- |
+        // | c_0 | a_0 | b_0 | r_0 | -       |
+        // | c_i | a_i | b_i | r_i | r_(i-1) |
+
+        for i in 0..NUMBER_OF_LIMBS {
+            main_gate.apply(
+                ctx,
+                [
+                    Term::Assigned(&result[i], -one),
+                    Term::Assigned(a.limb(i), one),
+                    Term::Assigned(b.limb(i), -one),
+                    Term::Assigned(&borrow[i], left_shifter),
+                    if i == 0 {
+                        Term::Zero
+                    } else {
+                        Term::Assigned(&borrow[i - 1], -one)
+                    },
+                ],
+                N::ZERO,
+                CombinationOptionCommon::OneLinerAdd.into(),
+            )?;
+        }
+
+        Ok(borrow[NUMBER_OF_LIMBS - 1].clone())
+    }
+}
@@ -0,0 +1,635 @@
+use crate::chip::IntegerChip;
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::bn256::{Fq, Fq2};
+use crate::halo2::halo2curves::ff::{Field, PrimeField};
+use crate::halo2::plonk::Error;
+use crate::instructions::{IntegerInstructions, Range};
+use crate::maingate::{big_to_fe, AssignedCondition, MainGate, RegionCtx};
+use crate::rns::{Common, Integer};
+use crate::AssignedInteger;
+
+/// Quadratic extension $W[u] / (u^2 - \beta)$ of a prime field $W$ where
+/// $\beta$ is a quadratic non residue
+pub trait QuadraticExtension: Field {
+    /// Prime field that is extended
+    type Base: PrimeField;
+
+    /// Returns the non residue $\beta$
+    fn non_residue() -> Self::Base;
+
+    /// Returns the element $c_0 + c_1 * u$
+    fn from_coeffs(c0: Self::Base, c1: Self::Base) -> Self;
+
+    /// Returns coefficients $(c_0, c_1)$ of the element
+    fn coeffs(&self) -> (Self::Base, Self::Base);
+}
+
+/// BN254 $F_{q^2} = F_q[u] / (u^2 + 1)$
+impl QuadraticExtension for Fq2 {
+    type Base = Fq;
+
+    fn non_residue() -> Fq {
+        -Fq::ONE
+    }
+
+    fn from_coeffs(c0: Fq, c1: Fq) -> Self {
+        Fq2 { c0, c1 }
+    }
+
+    fn coeffs(&self) -> (Fq, Fq) {
+        (self.c0, self.c1)
+    }
+}
+
+/// Assigned element of a quadratic extension as $c_0 + c_1 * u$
+#[derive(Debug, Clone)]
+pub struct AssignedFq2<
+    F: QuadraticExtension,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    c0: AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    c1: AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<
+        F: QuadraticExtension,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedFq2` given its coefficients
+    pub fn new(
+        c0: AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns coefficient $c_0$
+    pub fn c0(&self) -> &AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c0
+    }
+
+    /// Returns coefficient $c_1$
+    pub fn c1(&self) -> &AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c1
+    }
+
+    /// Returns the witness value of the element
+    pub fn value(&self) -> Value<F> {
+        self.c0
+            .integer()
+            .zip(self.c1.integer())
+            .map(|(c0, c1)| F::from_coeffs(big_to_fe(c0.value()), big_to_fe(c1.value())))
+    }
+}
+
+/// Constaints arithmetic of a quadratic extension field over `IntegerChip`
+#[derive(Clone, Debug)]
+pub struct Fq2Chip<
+    F: QuadraticExtension,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    integer_chip: IntegerChip<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<
+        F: QuadraticExtension,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > Fq2Chip<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns `Fq2Chip` given the `IntegerChip` of the base field
+    pub fn new(integer_chip: IntegerChip<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self { integer_chip }
+    }
+
+    /// Returns `IntegerChip` of the base field
+    pub fn integer_chip(&self) -> &IntegerChip<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.integer_chip
+    }
+
+    /// Returns `MainGate` of the chip
+    pub fn main_gate(&self) -> &MainGate<N> {
+        self.integer_chip.main_gate()
+    }
+
+    fn to_integer(&self, e: F::Base) -> Integer<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(e, self.integer_chip.rns())
+    }
+
+    /// Assigns an element with range check of coefficients
+    pub fn assign_element(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: Value<F>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (c0, c1) = e
+            .map(|e| {
+                let (c0, c1) = e.coeffs();
+                (self.to_integer(c0), self.to_integer(c1))
+            })
+            .unzip();
+        let c0 = self
+            .integer_chip
+            .assign_integer(ctx, c0.into(), Range::Remainder)?;
+        let c1 = self
+            .integer_chip
+            .assign_integer(ctx, c1.into(), Range::Remainder)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: F,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (c0, c1) = e.coeffs();
+        let c0 = self.integer_chip.assign_constant(ctx, c0)?;
+        let c1 = self.integer_chip.assign_constant(ctx, c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Adds 2 elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.add(ctx, &a.c0, &b.c0)?;
+        let c1 = self.integer_chip.add(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Adds a constant
+    pub fn add_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: F,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (b0, b1) = b.coeffs();
+        let c0 = self
+            .integer_chip
+            .add_constant(ctx, &a.c0, &self.to_integer(b0))?;
+        let c1 = self
+            .integer_chip
+            .add_constant(ctx, &a.c1, &self.to_integer(b1))?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Substracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.sub(ctx, &a.c0, &b.c0)?;
+        let c1 = self.integer_chip.sub(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Substracts `b_0` and `b_1` from `a`
+    pub fn sub_sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b_0: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b_1: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.sub_sub(ctx, &a.c0, &b_0.c0, &b_1.c0)?;
+        let c1 = self.integer_chip.sub_sub(ctx, &a.c1, &b_0.c1, &b_1.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Multiplies an element by -1
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.neg(ctx, &a.c0)?;
+        let c1 = self.integer_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Multiplies an element by 2
+    pub fn mul2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.mul2(ctx, &a.c0)?;
+        let c1 = self.integer_chip.mul2(ctx, &a.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Multiplies an element by 3
+    pub fn mul3(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.mul3(ctx, &a.c0)?;
+        let c1 = self.integer_chip.mul3(ctx, &a.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Returns the conjugate $c_0 - c_1 * u$
+    pub fn conjugate(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c1 = self.integer_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFq2::new(a.c0.clone(), c1))
+    }
+
+    /// Applies Frobenius endomorphism $a^{p^{power}}$. Since
+    /// $u^p = \beta^{(p - 1) / 2} * u = -u$ for a non residue $\beta$ it is
+    /// conjugation for odd powers and identity for even powers
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if power % 2 == 1 {
+            self.conjugate(ctx, a)
+        } else {
+            Ok(a.clone())
+        }
+    }
+
+    /// Multiplies a base field element by the non residue $\beta$
+    fn mul_by_non_residue(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let non_residue = F::non_residue();
+        if non_residue == -F::Base::ONE {
+            self.integer_chip.neg(ctx, a)
+        } else {
+            self.integer_chip
+                .mul_constant(ctx, a, &self.to_integer(non_residue))
+        }
+    }
+
+    /// Multiplies 2 elements with Karatsuba method
+    /// $c_0 = a_0 b_0 + \beta a_1 b_1$
+    /// $c_1 = (a_0 + a_1)(b_0 + b_1) - a_0 b_0 - a_1 b_1$
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.integer_chip;
+        let v0 = &ch.mul(ctx, &a.c0, &b.c0)?;
+        let v1 = &ch.mul(ctx, &a.c1, &b.c1)?;
+
+        let beta_v1 = &self.mul_by_non_residue(ctx, v1)?;
+        let c0 = ch.add(ctx, v0, beta_v1)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let b_sum = &ch.add(ctx, &b.c0, &b.c1)?;
+        let t = &ch.mul(ctx, a_sum, b_sum)?;
+        let c1 = ch.sub_sub(ctx, t, v0, v1)?;
+
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Squares an element with complex squaring
+    /// $c_0 = (a_0 + a_1)(a_0 + \beta a_1) - a_0 a_1 - \beta a_0 a_1$
+    /// $c_1 = 2 a_0 a_1$
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.integer_chip;
+        let v0 = &ch.mul(ctx, &a.c0, &a.c1)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let beta_a1 = &self.mul_by_non_residue(ctx, &a.c1)?;
+        let a_beta_sum = &ch.add(ctx, &a.c0, beta_a1)?;
+        let t = &ch.mul(ctx, a_sum, a_beta_sum)?;
+        let beta_v0 = &self.mul_by_non_residue(ctx, v0)?;
+        let c0 = ch.sub_sub(ctx, t, v0, beta_v0)?;
+        let c1 = ch.mul2(ctx, v0)?;
+
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Multiplies an element by a base field element
+    pub fn mul_by_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<F::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.mul(ctx, &a.c0, b)?;
+        let c1 = self.integer_chip.mul(ctx, &a.c1, b)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Multiplies an element by a constant
+    pub fn mul_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: F,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.integer_chip;
+        let (b0, b1) = b.coeffs();
+
+        let a0_b0 = &ch.mul_constant(ctx, &a.c0, &self.to_integer(b0))?;
+        let a1_b1 = &ch.mul_constant(ctx, &a.c1, &self.to_integer(b1 * F::non_residue()))?;
+        let c0 = ch.add(ctx, a0_b0, a1_b1)?;
+
+        let a0_b1 = &ch.mul_constant(ctx, &a.c0, &self.to_integer(b1))?;
+        let a1_b0 = &ch.mul_constant(ctx, &a.c1, &self.to_integer(b0))?;
+        let c1 = ch.add(ctx, a0_b1, a1_b0)?;
+
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Inverts an element. Assumes the input is not zero otherwise no valid
+    /// witness exists
+    pub fn invert_incomplete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let inv = a.value().map(|a| a.invert().unwrap_or(F::ZERO));
+        let inv = self.assign_element(ctx, inv)?;
+
+        // a * a^-1 = 1
+        let one = self.mul(ctx, a, &inv)?;
+        let c0 = self
+            .integer_chip
+            .add_constant(ctx, &one.c0, &self.to_integer(-F::Base::ONE))?;
+        self.integer_chip.assert_zero(ctx, &c0)?;
+        self.integer_chip.assert_zero(ctx, &one.c1)?;
+
+        Ok(inv)
+    }
+
+    /// Divides `a` by `b`. Assumes denominator is not zero otherwise no valid
+    /// witness exists
+    pub fn div_incomplete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let b_inv = &self.invert_incomplete(ctx, b)?;
+        self.mul(ctx, a, b_inv)
+    }
+
+    /// Reduces coefficients of an element
+    pub fn reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.reduce(ctx, &a.c0)?;
+        let c1 = self.integer_chip.reduce(ctx, &a.c1)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+
+    /// Constraints two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.integer_chip.assert_equal(ctx, &a.c0, &b.c0)?;
+        self.integer_chip.assert_equal(ctx, &a.c1, &b.c1)
+    }
+
+    /// Constraints an element to be zero
+    pub fn assert_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.integer_chip.assert_zero(ctx, &a.c0)?;
+        self.integer_chip.assert_zero(ctx, &a.c1)
+    }
+
+    /// Returns `a` if `cond` is set otherwise returns `b`
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedFq2<F, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.select(ctx, &a.c0, &b.c0, cond)?;
+        let c1 = self.integer_chip.select(ctx, &a.c1, &b.c1, cond)?;
+        Ok(AssignedFq2::new(c0, c1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fq2Chip, QuadraticExtension};
+    use crate::chip::{IntegerChip, IntegerConfig};
+    use crate::curves::bn256::{Fq, Fq2, Fr as BnScalar};
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::instructions::{IntegerInstructions, Range};
+    use crate::maingate::{
+        mock_prover_verify, modulus, MainGate, MainGateConfig, RangeChip, RangeConfig,
+        RangeInstructions, RegionCtx,
+    };
+    use crate::rns::{Integer, Rns};
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+    use std::rc::Rc;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: RangeConfig,
+        main_gate_config: MainGateConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn new<N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let main_gate_config = MainGate::<N>::configure(meta);
+
+            let overflow_bit_lens =
+                Rns::<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct().overflow_lengths();
+            let composition_bit_len =
+                IntegerChip::<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::sublimb_bit_len();
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                vec![composition_bit_len],
+                overflow_bit_lens,
+            );
+
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn integer_chip_config(&self) -> IntegerConfig {
+            IntegerConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitFq2<N: PrimeField> {
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestCircuitFq2<N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let rns = Rc::new(Rns::<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct());
+            let integer_chip = IntegerChip::new(config.integer_chip_config(), Rc::clone(&rns));
+            let fq2_chip = Fq2Chip::<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(integer_chip);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = Fq2::random(OsRng);
+                    let b = Fq2::random(OsRng);
+                    let k = Fq::random(OsRng);
+
+                    let a_assigned = &fq2_chip.assign_element(ctx, Value::known(a))?;
+                    let b_assigned = &fq2_chip.assign_element(ctx, Value::known(b))?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a + b))?;
+                    let c_1 = &fq2_chip.add(ctx, a_assigned, b_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a - b))?;
+                    let c_1 = &fq2_chip.sub(ctx, a_assigned, b_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a * b))?;
+                    let c_1 = &fq2_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a.square()))?;
+                    let c_1 = &fq2_chip.square(ctx, a_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a * b))?;
+                    let c_1 = &fq2_chip.mul_constant(ctx, a_assigned, b)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a + b))?;
+                    let c_1 = &fq2_chip.add_constant(ctx, a_assigned, b)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let k_assigned = &fq2_chip.integer_chip().assign_integer(
+                        ctx,
+                        Value::known(Integer::from_fe(k, Rc::clone(&rns))).into(),
+                        Range::Remainder,
+                    )?;
+                    let c_0 = &fq2_chip
+                        .assign_element(ctx, Value::known(a * Fq2::from_coeffs(k, Fq::ZERO)))?;
+                    let c_1 = &fq2_chip.mul_by_base(ctx, a_assigned, k_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(a.invert().unwrap()))?;
+                    let c_1 = &fq2_chip.invert_incomplete(ctx, a_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 =
+                        &fq2_chip.assign_element(ctx, Value::known(a * b.invert().unwrap()))?;
+                    let c_1 = &fq2_chip.div_incomplete(ctx, a_assigned, b_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let (a0, a1) = a.coeffs();
+                    let conjugate = Fq2::from_coeffs(a0, -a1);
+                    let c_0 = &fq2_chip.assign_element(ctx, Value::known(conjugate))?;
+                    let c_1 = &fq2_chip.conjugate(ctx, a_assigned)?;
+                    fq2_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // Frobenius is exponentiation to the characteristic
+                    let p = modulus::<Fq>().to_u64_digits();
+                    let mut expected = a;
+                    for power in 1..4 {
+                        expected = expected.pow_vartime(&p);
+                        let c_0 = &fq2_chip.assign_element(ctx, Value::known(expected))?;
+                        let c_1 = &fq2_chip.frobenius_map(ctx, a_assigned, power)?;
+                        fq2_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+
+                    let zero = &fq2_chip.sub(ctx, a_assigned, a_assigned)?;
+                    fq2_chip.assert_zero(ctx, zero)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fq2_chip() {
+        let circuit = TestCircuitFq2::<BnScalar> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        let circuit = TestCircuitFq2::<PastaFp> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+}
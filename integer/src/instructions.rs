@@ -1,5 +1,5 @@
 use super::{AssignedInteger, UnassignedInteger};
-use crate::maingate::{halo2, AssignedCondition, RegionCtx};
+use crate::maingate::{halo2, AssignedCondition, AssignedValue, RegionCtx};
 use crate::rns::Integer;
 use halo2::plonk::Error;
 use maingate::halo2::halo2curves::ff::PrimeField;
@@ -50,6 +50,46 @@ pub trait IntegerInstructions<
         integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedCondition<N>>, Error>;
 
+    /// Decomposes an [`AssignedInteger`] into its little endian bytes. Integer
+    /// is asserted to be in field so that the encoding is canonical and each
+    /// byte is ranged.
+    fn to_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error>;
+
+    /// Decomposes an [`AssignedInteger`] into its big endian bytes.
+    fn to_bytes_be(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        let mut bytes = self.to_bytes_le(ctx, integer)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+
+    /// Composes an [`AssignedInteger`] from little endian bytes. Bytes are
+    /// ranged and the result is asserted to be in field. Missing most
+    /// significant bytes are taken as zero. Returns `Error::Synthesis` if
+    /// there are more bytes than the byte length of the field.
+    fn from_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Composes an [`AssignedInteger`] from big endian bytes.
+    fn from_bytes_be(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let bytes: Vec<AssignedValue<N>> = bytes.iter().rev().cloned().collect();
+        self.from_bytes_le(ctx, &bytes)
+    }
+
     /// Adds 2 [`AssignedInteger`].
     fn add(
         &self,
@@ -267,6 +307,41 @@ pub trait IntegerInstructions<
         input: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Returns `1` if `a < b` and `0` otherwise. Integers are compared by
+    /// their limb values so inputs are expected to be asserted in field to
+    /// compare them as field elements
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Constraints that `a < b` where integers are compared by their limb
+    /// values
+    fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
+    /// Returns the greater one of two [`AssignedInteger`]s
+    fn max(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Returns the lesser one of two [`AssignedInteger`]s
+    fn min(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Given an [`AssignedCondition`] returns picks one of two
     /// [`AssignedInteger`]
     fn select(
@@ -21,6 +21,8 @@ use halo2::halo2curves as curves;
 
 /// Chip for integer constaints
 pub mod chip;
+/// Quadratic extension field arithmetic over integer chip
+pub mod fq2;
 /// Commoon instructions for integer operations and assignments
 pub mod instructions;
 /// Residue number system construction and utilities
@@ -31,6 +33,11 @@ pub mod rns;
 /// limb and it is decomposed to four 17-bit limbs.
 pub const NUMBER_OF_LOOKUP_LIMBS: usize = 4;
 
+/// Bit length of bytes that integers are converted from and to. `RangeChip`
+/// is expected to have it as a composition bit length along with
+/// `Rns::byte_overflow_lengths` for byte conversions
+pub const BYTE_BIT_LEN: usize = 8;
+
 /// AssignedLimb is a limb of an non native integer
 #[derive(Debug, Clone)]
 pub struct AssignedLimb<F: PrimeField> {
@@ -1,4 +1,4 @@
-use crate::NUMBER_OF_LOOKUP_LIMBS;
+use crate::{BYTE_BIT_LEN, NUMBER_OF_LOOKUP_LIMBS};
 use halo2::circuit::Value;
 use maingate::halo2::halo2curves::ff::PrimeField;
 use maingate::{big_to_fe, compose, decompose_big, fe_to_big, halo2, modulus};
@@ -629,6 +629,39 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             sign_aux,
         ]
     }
+
+    /// Returns bit lengths of pieces that each limb of an integer in the
+    /// field is split into in byte conversions. Pieces don't cross byte
+    /// boundaries so that a byte is composed of one or two pieces
+    pub(crate) fn byte_pieces(&self) -> Vec<Vec<usize>> {
+        let bit_len = self.wrong_modulus.bits() as usize;
+        (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let end = std::cmp::min((i + 1) * BIT_LEN_LIMB, bit_len);
+                let mut pos = i * BIT_LEN_LIMB;
+                let mut pieces = vec![];
+                while pos < end {
+                    let width = std::cmp::min(BYTE_BIT_LEN - pos % BYTE_BIT_LEN, end - pos);
+                    pieces.push(width);
+                    pos += width;
+                }
+                pieces
+            })
+            .collect()
+    }
+
+    /// Computes the overflow lengths that byte conversions must support
+    pub fn byte_overflow_lengths(&self) -> Vec<usize> {
+        let mut lengths: Vec<usize> = self
+            .byte_pieces()
+            .into_iter()
+            .flatten()
+            .filter(|width| *width != BYTE_BIT_LEN)
+            .collect();
+        lengths.sort_unstable();
+        lengths.dedup();
+        lengths
+    }
 }
 
 /// Limb of an [`Integer`].
@@ -947,6 +980,39 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ComparisionWitness { result, borrow }
     }
 
+    // Returns comparision witnesses of `self - other` where the last borrow is
+    // set if `self < other`
+    pub(crate) fn compare(
+        &self,
+        other: &Self,
+    ) -> ComparisionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let mut borrow = [false; NUMBER_OF_LIMBS];
+
+        let mut prev_borrow = big_uint::zero();
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(other.limbs.iter())
+            .zip(borrow.iter_mut())
+            .map(|((limb, other_limb), borrow)| {
+                let limb = limb.value();
+                let other_limb = &other_limb.value() + prev_borrow.clone();
+                let cur_borrow = limb < other_limb;
+                *borrow = cur_borrow;
+                let cur_borrow = bool_to_big(cur_borrow) << BIT_LEN_LIMB;
+                let res_limb = (limb + cur_borrow) - other_limb;
+                prev_borrow = bool_to_big(*borrow);
+
+                big_to_fe(res_limb)
+            })
+            .collect::<Vec<N>>()
+            .try_into()
+            .unwrap();
+
+        let result = Integer::from_limbs(&limbs, Rc::clone(&self.rns));
+        ComparisionWitness { result, borrow }
+    }
+
     /// Construct a new integer that equals to the modulus and its max limb
     /// values are higher than the given max values
     pub(crate) fn subtracion_aux(
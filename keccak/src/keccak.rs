@@ -0,0 +1,609 @@
+use crate::halo2::circuit::{Layouter, Value};
+use crate::halo2::halo2curves::ff::PrimeField;
+use crate::halo2::plonk::{ConstraintSystem, Error};
+use crate::maingate::{
+    AssignedValue, LookupChip, LookupConfig, LookupInstructions, LookupTable, MainGate,
+    MainGateConfig, MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+    Term,
+};
+
+/// Number of bytes in a digest
+pub const DIGEST_BYTES: usize = 32;
+/// Number of message bytes absorbed per permutation
+pub const RATE_BYTES: usize = 136;
+
+/// Bitwise operations are looked up on chunks of this bit length
+const CHUNK_BIT_LEN: usize = 4;
+const CHUNK_MASK: u64 = (1 << CHUNK_BIT_LEN) - 1;
+const LANE_BIT_LEN: usize = 64;
+const LANE_CHUNKS: usize = LANE_BIT_LEN / CHUNK_BIT_LEN;
+const STATE_LANES: usize = 25;
+
+/// Indexes of the tables in the lookup chip
+const XOR_TABLE: usize = 0;
+const CHI_TABLE: usize = 1;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of lanes indexed as `[x][y]`
+const ROTATIONS: [[usize; 5]; 5] = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+];
+
+/// Little endian chunks of a 64-bit lane
+type Lane<F> = Vec<AssignedValue<F>>;
+
+type Operation = fn(u64, u64, u64) -> u64;
+
+fn xor3(a: u64, b: u64, c: u64) -> u64 {
+    a ^ b ^ c
+}
+
+fn chi(a: u64, b: u64, c: u64) -> u64 {
+    a ^ (!b & c & CHUNK_MASK)
+}
+
+fn fe_to_u64<F: PrimeField>(e: &F) -> u64 {
+    u64::from_le_bytes(e.to_repr().as_ref()[..8].try_into().unwrap())
+}
+
+/// Returns table of the operation over all chunk triples as rows of
+/// `[a, b, c, operation(a, b, c)]`
+fn operation_table<F: PrimeField>(operation: Operation) -> LookupTable<F> {
+    let rows = (0..1 << (3 * CHUNK_BIT_LEN))
+        .map(|i: u64| {
+            let [a, b, c] = [0, 1, 2].map(|j| (i >> (j * CHUNK_BIT_LEN)) & CHUNK_MASK);
+            [a, b, c, operation(a, b, c)].map(F::from).to_vec()
+        })
+        .collect();
+    LookupTable::new(rows)
+}
+
+/// Returns terms that compose a lane from its chunks
+fn lane_terms<F: PrimeField>(lane: &[AssignedValue<F>]) -> Vec<Term<'_, F>> {
+    lane.iter()
+        .enumerate()
+        .map(|(i, chunk)| Term::Assigned(chunk, F::from(1 << (CHUNK_BIT_LEN * i))))
+        .collect()
+}
+
+/// Configuration for [`KeccakChip`]
+#[derive(Clone, Debug)]
+pub struct KeccakConfig {
+    main_gate_config: MainGateConfig,
+    range_config: RangeConfig,
+    lookup_config: LookupConfig,
+}
+
+/// `KeccakChip` constrains Keccak-256 hash of byte messages. Lanes of the
+/// state are kept as 4-bit chunks and `xor` and `chi` steps are looked up
+/// chunk by chunk
+#[derive(Clone, Debug)]
+pub struct KeccakChip<F: PrimeField> {
+    main_gate: MainGate<F>,
+    range_chip: RangeChip<F>,
+    lookup_chip: LookupChip<F>,
+}
+
+impl<F: PrimeField> KeccakChip<F> {
+    /// Creates new chip from the given config
+    pub fn new(config: KeccakConfig) -> Self {
+        Self {
+            main_gate: MainGate::new(config.main_gate_config.clone()),
+            range_chip: RangeChip::new(config.range_config.clone()),
+            lookup_chip: LookupChip::new(
+                config.lookup_config,
+                vec![operation_table(xor3), operation_table(chi)],
+            ),
+        }
+    }
+
+    /// Configures lookup of bitwise operations on the first four columns of
+    /// the main gate. Range config is expected to be configured with
+    /// `composition_bit_lens` and `overflow_bit_lens`
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        main_gate_config: &MainGateConfig,
+        range_config: &RangeConfig,
+    ) -> KeccakConfig {
+        let advices = main_gate_config.advice_columns();
+        let lookup_config = LookupChip::configure(meta, advices[..4].to_vec());
+
+        KeccakConfig {
+            main_gate_config: main_gate_config.clone(),
+            range_config: range_config.clone(),
+            lookup_config,
+        }
+    }
+
+    /// Returns composition bit lenghts that range chip should be configured
+    /// with
+    pub fn composition_bit_lens() -> Vec<usize> {
+        vec![CHUNK_BIT_LEN]
+    }
+
+    /// Returns overflow bit lenghts that range chip should be configured with
+    pub fn overflow_bit_lens() -> Vec<usize> {
+        (1..CHUNK_BIT_LEN).collect()
+    }
+
+    /// Returns main gate
+    pub fn main_gate(&self) -> &MainGate<F> {
+        &self.main_gate
+    }
+
+    /// Returns range chip
+    pub fn range_chip(&self) -> &RangeChip<F> {
+        &self.range_chip
+    }
+
+    /// Loads table of bitwise operations. Table of the range chip should be
+    /// loaded separately
+    pub fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.lookup_chip.load_table(layouter)
+    }
+
+    /// Constrains Keccak-256 digest of the first `len` bytes of the message
+    /// and returns it as bytes. Number of given bytes is the maximum length
+    /// of the message and bytes from `len` on are ignored. Padding is placed
+    /// with respect to `len` and digest of the block that the padded message
+    /// ends in is selected. Bytes of the message don't need to be ranged
+    /// since they are ranged once padded
+    pub fn keccak256(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bytes: &[AssignedValue<F>],
+        len: &AssignedValue<F>,
+    ) -> Result<[AssignedValue<F>; DIGEST_BYTES], Error> {
+        let main_gate = self.main_gate();
+        let max_len = bytes.len();
+        let number_of_blocks = max_len / RATE_BYTES + 1;
+        let zero = main_gate.assign_constant(ctx, F::ZERO)?;
+
+        // Padded message is `bytes[..len] || 0x01 || 0x00 .. || 0x80` where
+        // `0x80` is the last byte of the block that `len` falls into. `ends`
+        // counts positions that are equal to `len` so far, bytes are kept
+        // while it is zero
+        let mut ends = zero.clone();
+        let mut ends_before_block = zero.clone();
+        let mut block_flags = vec![];
+        let mut chunks = vec![];
+        for i in 0..number_of_blocks * RATE_BYTES {
+            let is_end = (i <= max_len)
+                .then(|| {
+                    let diff = main_gate.add_constant(ctx, len, -F::from(i as u64))?;
+                    main_gate.is_zero(ctx, &diff)
+                })
+                .transpose()?;
+            if let Some(is_end) = is_end.as_ref() {
+                ends = main_gate.add(ctx, &ends, is_end)?;
+            }
+            let dropped = bytes
+                .get(i)
+                .map(|byte| main_gate.mul(ctx, &ends, byte))
+                .transpose()?;
+            let block_flag = ((i + 1) % RATE_BYTES == 0)
+                .then(|| main_gate.sub(ctx, &ends, &ends_before_block))
+                .transpose()?;
+            if let Some(block_flag) = block_flag.as_ref() {
+                ends_before_block = ends.clone();
+                block_flags.push(block_flag.clone());
+            }
+
+            let terms: Vec<Term<F>> = bytes
+                .get(i)
+                .map(Term::assigned_to_add)
+                .into_iter()
+                .chain(dropped.as_ref().map(Term::assigned_to_sub))
+                .chain(is_end.as_ref().map(Term::assigned_to_add))
+                .chain(
+                    block_flag
+                        .as_ref()
+                        .map(|block_flag| Term::Assigned(block_flag, F::from(0x80))),
+                )
+                .collect();
+            if terms.is_empty() {
+                chunks.extend([zero.clone(), zero.clone()]);
+                continue;
+            }
+
+            // Decomposition ranges the padded byte and so the message byte
+            // if it is kept
+            let byte = main_gate.compose(ctx, &terms, F::ZERO)?;
+            let (decomposed, nibbles) =
+                self.range_chip
+                    .decompose(ctx, byte.value().copied(), CHUNK_BIT_LEN, 8)?;
+            main_gate.assert_equal(ctx, &decomposed, &byte)?;
+            chunks.extend(nibbles);
+        }
+        // Exactly one position is equal to `len` so that `len <= max_len`
+        main_gate.assert_one(ctx, &ends)?;
+
+        let zeros: Lane<F> = vec![zero; LANE_CHUNKS];
+        let mut state: Vec<Lane<F>> = vec![zeros.clone(); STATE_LANES];
+        let mut digest: Option<Vec<AssignedValue<F>>> = None;
+        for (block, block_flag) in chunks.chunks(2 * RATE_BYTES).zip(block_flags.iter()) {
+            for (lane, message) in state.iter_mut().zip(block.chunks(LANE_CHUNKS)) {
+                *lane = self.xor(ctx, lane, message, &zeros)?;
+            }
+            state = self.permute(ctx, state, &zeros)?;
+
+            let block_digest = self.squeeze(ctx, &state)?;
+            digest = Some(match digest {
+                None => block_digest
+                    .iter()
+                    .map(|byte| main_gate.mul(ctx, block_flag, byte))
+                    .collect::<Result<_, Error>>()?,
+                Some(digest) => block_digest
+                    .iter()
+                    .zip(digest.iter())
+                    .map(|(byte, acc)| main_gate.mul_add(ctx, block_flag, byte, acc))
+                    .collect::<Result<_, Error>>()?,
+            });
+        }
+
+        Ok(digest.unwrap().try_into().unwrap())
+    }
+
+    /// Applies Keccak-f[1600] permutation to the state
+    fn permute(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        mut state: Vec<Lane<F>>,
+        zeros: &[AssignedValue<F>],
+    ) -> Result<Vec<Lane<F>>, Error> {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta is folded as `a[x, y] ^ c[x - 1] ^ rot(c[x + 1], 1)`
+            let c = (0..5)
+                .map(|x| {
+                    let t = self.xor(ctx, &state[x], &state[x + 5], &state[x + 10])?;
+                    self.xor(ctx, &t, &state[x + 15], &state[x + 20])
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let c_rotated = c
+                .iter()
+                .map(|lane| self.rotate(ctx, lane, 1))
+                .collect::<Result<Vec<_>, Error>>()?;
+            let a = state
+                .iter()
+                .enumerate()
+                .map(|(i, lane)| {
+                    let x = i % 5;
+                    self.xor(ctx, lane, &c[(x + 4) % 5], &c_rotated[(x + 1) % 5])
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            // Rho and pi
+            let mut b = vec![vec![]; STATE_LANES];
+            for (x, rotations) in ROTATIONS.iter().enumerate() {
+                for (y, rotation) in rotations.iter().enumerate() {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                        self.rotate(ctx, &a[x + 5 * y], *rotation)?;
+                }
+            }
+
+            // Chi
+            state = (0..STATE_LANES)
+                .map(|i| {
+                    let (x, y) = (i % 5, i / 5);
+                    self.chi(ctx, &b[i], &b[(x + 1) % 5 + 5 * y], &b[(x + 2) % 5 + 5 * y])
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            // Iota
+            let round_constant = (0..LANE_CHUNKS)
+                .map(|i| {
+                    let chunk = (round_constant >> (CHUNK_BIT_LEN * i)) & CHUNK_MASK;
+                    self.main_gate.assign_constant(ctx, F::from(chunk))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            state[0] = self.xor(ctx, &state[0], &round_constant, zeros)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Returns first `DIGEST_BYTES` bytes of the state
+    fn squeeze(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        state: &[Lane<F>],
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let chunks: Vec<&AssignedValue<F>> = state.iter().flatten().collect();
+        chunks[..2 * DIGEST_BYTES]
+            .chunks(2)
+            .map(|nibbles| {
+                self.main_gate.compose(
+                    ctx,
+                    &[
+                        Term::assigned_to_add(nibbles[0]),
+                        Term::Assigned(nibbles[1], F::from(1 << CHUNK_BIT_LEN)),
+                    ],
+                    F::ZERO,
+                )
+            })
+            .collect()
+    }
+
+    /// Rotates a lane to the left. Rotations by multiples of chunk bit length
+    /// only reorder chunks, otherwise the lane is split at the rotation point
+    /// and rotated lane is decomposed into chunks again
+    fn rotate(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        lane: &[AssignedValue<F>],
+        rotation: usize,
+    ) -> Result<Lane<F>, Error> {
+        if rotation % CHUNK_BIT_LEN == 0 {
+            let shift = rotation / CHUNK_BIT_LEN;
+            return Ok((0..LANE_CHUNKS)
+                .map(|i| lane[(i + LANE_CHUNKS - shift) % LANE_CHUNKS].clone())
+                .collect());
+        }
+
+        // lane = high * 2^(64 - r) + low
+        // rotated = low * 2^r + high
+        let low_bit_len = LANE_BIT_LEN - rotation;
+        let terms = lane_terms(lane);
+        let value = Term::compose(&terms, F::ZERO).map(|value| fe_to_u64(&value));
+        let low = self.range_chip.assign(
+            ctx,
+            value.map(|value| F::from(value & ((1 << low_bit_len) - 1))),
+            CHUNK_BIT_LEN,
+            low_bit_len,
+        )?;
+        let high = self.range_chip.assign(
+            ctx,
+            value.map(|value| F::from(value >> low_bit_len)),
+            CHUNK_BIT_LEN,
+            rotation,
+        )?;
+        let terms: Vec<Term<F>> = terms
+            .into_iter()
+            .chain([
+                Term::assigned_to_sub(&low),
+                Term::Assigned(&high, -F::from(1 << low_bit_len)),
+            ])
+            .collect();
+        self.main_gate.assert_zero_sum(ctx, &terms, F::ZERO)?;
+
+        let (rotated, chunks) = self.range_chip.decompose(
+            ctx,
+            value.map(|value| F::from(value.rotate_left(rotation as u32))),
+            CHUNK_BIT_LEN,
+            LANE_BIT_LEN,
+        )?;
+        self.main_gate.assert_zero_sum(
+            ctx,
+            &[
+                Term::assigned_to_sub(&rotated),
+                Term::Assigned(&low, F::from(1 << rotation)),
+                Term::assigned_to_add(&high),
+            ],
+            F::ZERO,
+        )?;
+
+        Ok(chunks)
+    }
+
+    fn xor(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &[AssignedValue<F>],
+        b: &[AssignedValue<F>],
+        c: &[AssignedValue<F>],
+    ) -> Result<Lane<F>, Error> {
+        self.lookup_lanes(ctx, XOR_TABLE, [a, b, c], xor3)
+    }
+
+    /// Returns `a ^ (!b & c)`
+    fn chi(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &[AssignedValue<F>],
+        b: &[AssignedValue<F>],
+        c: &[AssignedValue<F>],
+    ) -> Result<Lane<F>, Error> {
+        self.lookup_lanes(ctx, CHI_TABLE, [a, b, c], chi)
+    }
+
+    /// Applies the operation to lanes chunk by chunk. Operands and the result
+    /// of each chunk are looked up in the table of the operation and
+    /// operands are copied from the given lanes
+    fn lookup_lanes(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        table_id: usize,
+        lanes: [&[AssignedValue<F>]; 3],
+        operation: Operation,
+    ) -> Result<Lane<F>, Error> {
+        let [a, b, c] = lanes;
+        a.iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| {
+                let out = a.value().zip(b.value()).zip(c.value()).map(|((a, b), c)| {
+                    F::from(operation(fe_to_u64(a), fe_to_u64(b), fe_to_u64(c)))
+                });
+                let inputs = [
+                    a.value().copied(),
+                    b.value().copied(),
+                    c.value().copied(),
+                    out,
+                ];
+                let mut assigned = self.lookup_chip.lookup(ctx, table_id, &inputs)?;
+                for (assigned, operand) in assigned.iter().zip([a, b, c]) {
+                    ctx.constrain_equal(assigned.cell(), operand.cell())?;
+                }
+                Ok(assigned.swap_remove(3))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeccakChip, KeccakConfig, DIGEST_BYTES, RATE_BYTES};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::PrimeField;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        mock_prover_verify, MainGate, MainGateInstructions, RangeChip, RangeInstructions, RegionCtx,
+    };
+    use rand_core::{OsRng, RngCore};
+    use sha3::{Digest, Keccak256};
+    use std::marker::PhantomData;
+
+    fn from_hex(e: &str) -> [u8; DIGEST_BYTES] {
+        (0..e.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&e[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        keccak_config: KeccakConfig,
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuit<F: PrimeField> {
+        // Bytes from `len` on are garbage that should be ignored
+        message: Vec<u8>,
+        len: usize,
+        expected: [u8; DIGEST_BYTES],
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let range_config = RangeChip::<F>::configure(
+                meta,
+                &main_gate_config,
+                KeccakChip::<F>::composition_bit_lens(),
+                KeccakChip::<F>::overflow_bit_lens(),
+            );
+            let keccak_config = KeccakChip::configure(meta, &main_gate_config, &range_config);
+            TestCircuitConfig { keccak_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let keccak_chip = KeccakChip::<F>::new(config.keccak_config);
+            let main_gate = keccak_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let message = self
+                        .message
+                        .iter()
+                        .map(|byte| {
+                            main_gate.assign_value(ctx, Value::known(F::from(*byte as u64)))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let len =
+                        main_gate.assign_value(ctx, Value::known(F::from(self.len as u64)))?;
+                    let digest = keccak_chip.keccak256(ctx, &message, &len)?;
+                    for (byte, expected) in digest.iter().zip(self.expected.iter()) {
+                        main_gate.assert_equal_to_constant(ctx, byte, F::from(*expected as u64))?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            keccak_chip.load_table(&mut layouter)?;
+            keccak_chip.range_chip().load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run(message: &[u8], len: usize, expected: [u8; DIGEST_BYTES]) {
+        let circuit = TestCircuit::<Fr> {
+            message: message.to_vec(),
+            len,
+            expected,
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_keccak256_vectors() {
+        run(
+            b"",
+            0,
+            from_hex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+        );
+        run(
+            b"abc",
+            3,
+            from_hex("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        );
+    }
+
+    #[test]
+    fn test_keccak256_variable_length() {
+        // Messages of different lengths share the same maximum length that
+        // spans two blocks
+        let mut message = vec![0u8; RATE_BYTES];
+        OsRng.fill_bytes(&mut message);
+        for len in [0, 3, RATE_BYTES - 1, RATE_BYTES] {
+            let expected = Keccak256::digest(&message[..len])
+                .as_slice()
+                .try_into()
+                .unwrap();
+            run(&message, len, expected);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! `keccak` constrains Keccak-256 hash of variable length messages on top of
+//! `MainGate` and `RangeChip`
+
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+
+mod keccak;
+
+pub use keccak::*;
+pub use maingate;
+pub use maingate::halo2;
+
+#[cfg(test)]
+use halo2::halo2curves as curves;
@@ -1008,6 +1008,54 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
         Ok(bits)
     }
 
+    /// Same as `to_bits` with `F::NUM_BITS` bits where the decomposition is
+    /// also constrained to be less than the modulus. Otherwise bits of
+    /// `composed + modulus` would satisfy `to_bits` when it fits in
+    /// `F::NUM_BITS` bits
+    fn to_bits_canonical(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        composed: &AssignedValue<F>,
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        let bits = self.to_bits(ctx, composed, F::NUM_BITS as usize)?;
+        self.assert_canonical_bits(ctx, &bits)?;
+        Ok(bits)
+    }
+
+    /// Given `F::NUM_BITS` little endian bits enforces that the value they
+    /// compose is less than the modulus. Bits are expected to be already
+    /// constrained to be `0` or `1`
+    fn assert_canonical_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bits: &[AssignedCondition<F>],
+    ) -> Result<(), Error> {
+        assert_eq!(bits.len(), F::NUM_BITS as usize);
+
+        // Compare against `modulus - 1` starting from the most significant
+        // bit where `eq` is set while prefixes are equal. Where bit of the
+        // bound is zero bit of the value must be zero if prefixes are equal
+        let bound = decompose(-F::ONE, F::NUM_BITS as usize, 1);
+        let mut eq: Option<AssignedCondition<F>> = None;
+        for (bit, bound) in bits.iter().zip(bound.iter()).rev() {
+            match (eq.as_ref(), *bound == F::ONE) {
+                (None, true) => eq = Some(bit.clone()),
+                (None, false) => self.assert_zero(ctx, bit)?,
+                (Some(eq_prev), true) => eq = Some(self.and(ctx, eq_prev, bit)?),
+                (Some(eq_prev), false) => {
+                    self.apply(
+                        ctx,
+                        [Term::assigned_to_mul(eq_prev), Term::assigned_to_mul(bit)],
+                        F::ZERO,
+                        CombinationOptionCommon::OneLinerMul.into(),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Assigns a new witness composed of given array of terms
     /// `result = constant + term_0 + term_1 + ... `
     /// where `term_i = a_i * q_i`
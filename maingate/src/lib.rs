@@ -9,11 +9,13 @@ use halo2wrong::halo2::circuit::AssignedCell;
 
 #[macro_use]
 mod instructions;
+mod lookup;
 mod main_gate;
 mod range;
 
 pub use halo2wrong::{halo2, utils::*, RegionCtx};
 pub use instructions::{CombinationOptionCommon, MainGateInstructions, Term};
+pub use lookup::*;
 pub use main_gate::*;
 pub use range::*;
 
@@ -0,0 +1,370 @@
+use crate::halo2::circuit::Chip;
+use crate::halo2::circuit::Layouter;
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::ff::PrimeField;
+use crate::halo2::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+use crate::halo2::plonk::{Selector, TableColumn};
+use crate::halo2::poly::Rotation;
+use crate::AssignedValue;
+use halo2wrong::RegionCtx;
+
+/// Fixed table of equal width rows. Lookups are checked against its rows
+#[derive(Clone, Debug)]
+pub struct LookupTable<F: PrimeField> {
+    rows: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> LookupTable<F> {
+    /// Creates a table from given rows. All rows are expected to have the
+    /// same width
+    pub fn new(rows: Vec<Vec<F>>) -> Self {
+        assert!(!rows.is_empty(), "At least one row is expected");
+        let width = rows[0].len();
+        assert!(width > 0, "At least one column is expected");
+        assert!(rows.iter().all(|row| row.len() == width));
+        Self { rows }
+    }
+
+    /// Creates a single column table with values in `[0, size)`
+    pub fn range(size: usize) -> Self {
+        Self::new((0..size).map(|e| vec![F::from(e as u64)]).collect())
+    }
+
+    /// Number of columns of the table
+    pub fn width(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    /// Number of rows of the table
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+/// Lookup configuration
+#[derive(Clone, Debug)]
+pub struct LookupConfig {
+    s_lookup: Selector,
+    tag: Column<Fixed>,
+    inputs: Vec<Column<Advice>>,
+    t_tag: TableColumn,
+    t_values: Vec<TableColumn>,
+}
+
+/// Instructions that constrain a tuple to be a row of one of the tables
+pub trait LookupInstructions<F: PrimeField>: Chip<F> {
+    /// Assigns inputs in a single row and constrains them to be a row of the
+    /// table with the given id. Assigned inputs can be copied to other cells
+    fn lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        table_id: usize,
+        inputs: &[Value<F>],
+    ) -> Result<Vec<AssignedValue<F>>, Error>;
+
+    /// Load tables in sythnesis time
+    fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error>;
+}
+
+/// ['LookupChip'] checks tuples against user defined fixed tables. A table is
+/// identified by its index in the list that the chip is created with
+#[derive(Clone, Debug)]
+pub struct LookupChip<F: PrimeField> {
+    config: LookupConfig,
+    tables: Vec<LookupTable<F>>,
+}
+
+impl<F: PrimeField> Chip<F> for LookupChip<F> {
+    type Config = LookupConfig;
+    type Loaded = ();
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> LookupInstructions<F> for LookupChip<F> {
+    fn lookup(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        table_id: usize,
+        inputs: &[Value<F>],
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let table = &self.tables[table_id];
+        assert_eq!(inputs.len(), table.width());
+
+        ctx.enable(self.config.s_lookup)?;
+        ctx.assign_fixed(|| "lookup tag", self.config.tag, Self::tag(table_id))?;
+        // unused input columns are zero as table rows are padded with zeros
+        let assigned = self
+            .config
+            .inputs
+            .iter()
+            .zip(
+                inputs
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::repeat(Value::known(F::ZERO))),
+            )
+            .map(|(column, value)| ctx.assign_advice(|| "lookup input", *column, value))
+            .collect::<Result<Vec<AssignedValue<F>>, Error>>()?;
+        ctx.next();
+
+        Ok(assigned.into_iter().take(inputs.len()).collect())
+    }
+
+    fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "",
+            |mut table| {
+                let mut offset = 0;
+
+                // zero row for disabled lookups
+                let zero_row = vec![F::ZERO; self.config.t_values.len()];
+                for (tag, row) in std::iter::once((F::ZERO, &zero_row)).chain(
+                    self.tables
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(id, t)| t.rows.iter().map(move |row| (Self::tag(id), row))),
+                ) {
+                    table.assign_cell(
+                        || "table tag",
+                        self.config.t_tag,
+                        offset,
+                        || Value::known(tag),
+                    )?;
+                    for (i, column) in self.config.t_values.iter().enumerate() {
+                        let value = row.get(i).cloned().unwrap_or(F::ZERO);
+                        table.assign_cell(
+                            || "table value",
+                            *column,
+                            offset,
+                            || Value::known(value),
+                        )?;
+                    }
+                    offset += 1;
+                }
+
+                Ok(())
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> LookupChip<F> {
+    /// Given config and tables creates new chip. Tables are expected to be
+    /// no wider than the number of configured input columns
+    pub fn new(config: LookupConfig, tables: Vec<LookupTable<F>>) -> Self {
+        assert!(tables
+            .iter()
+            .all(|table| table.width() <= config.inputs.len()));
+        Self { config, tables }
+    }
+
+    /// Configures lookup argument over given input columns and returns the
+    /// resulting config. Input columns can be shared with other gates such
+    /// as columns of [`crate::MainGateConfig`]
+    pub fn configure(meta: &mut ConstraintSystem<F>, inputs: Vec<Column<Advice>>) -> LookupConfig {
+        assert!(!inputs.is_empty(), "At least one input column is expected");
+
+        let s_lookup = meta.complex_selector();
+        let tag = meta.fixed_column();
+        let t_tag = meta.lookup_table_column();
+        let t_values: Vec<TableColumn> =
+            inputs.iter().map(|_| meta.lookup_table_column()).collect();
+
+        for column in inputs.iter() {
+            meta.enable_equality(*column);
+        }
+
+        meta.lookup("lookup", |meta| {
+            let selector = meta.query_selector(s_lookup);
+            let tag = meta.query_fixed(tag, Rotation::cur());
+            std::iter::once((selector.clone() * tag, t_tag))
+                .chain(inputs.iter().zip(t_values.iter()).map(|(input, t_value)| {
+                    let input = meta.query_advice(*input, Rotation::cur());
+                    (selector.clone() * input, *t_value)
+                }))
+                .collect()
+        });
+
+        LookupConfig {
+            s_lookup,
+            tag,
+            inputs,
+            t_tag,
+            t_values,
+        }
+    }
+
+    /// Tag zero is reserved for disabled lookups
+    fn tag(table_id: usize) -> F {
+        F::from(table_id as u64 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use halo2wrong::halo2::circuit::Value;
+    use halo2wrong::RegionCtx;
+
+    use super::{LookupChip, LookupConfig, LookupInstructions, LookupTable};
+    use crate::curves::{ff::PrimeField, pasta::Fp};
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use crate::halo2::dev::MockProver;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::main_gate::{MainGate, MainGateConfig};
+    use crate::MainGateInstructions;
+
+    const XOR: usize = 0;
+    const BITS: usize = 1;
+    const SBOX: usize = 2;
+    const RANGE: usize = 3;
+
+    // PRESENT 4-bit sbox
+    const SBOX_VALUES: [u64; 16] = [
+        0xc, 0x5, 0x6, 0xb, 0x9, 0x0, 0xa, 0xd, 0x3, 0xe, 0xf, 0x8, 0x4, 0x7, 0x1, 0x2,
+    ];
+    const RANGE_SIZE: usize = 100;
+
+    fn tables<F: PrimeField>() -> Vec<LookupTable<F>> {
+        let xor = (0..16)
+            .flat_map(|a| (0..16).map(move |b| vec![a, b, a ^ b]))
+            .map(|row| row.into_iter().map(F::from).collect())
+            .collect();
+        let bits = (0..16)
+            .map(|a| {
+                std::iter::once(a)
+                    .chain((0..4).map(|i| (a >> i) & 1))
+                    .map(F::from)
+                    .collect()
+            })
+            .collect();
+        let sbox = SBOX_VALUES
+            .iter()
+            .enumerate()
+            .map(|(a, b)| vec![F::from(a as u64), F::from(*b)])
+            .collect();
+        vec![
+            LookupTable::new(xor),
+            LookupTable::new(bits),
+            LookupTable::new(sbox),
+            LookupTable::range(RANGE_SIZE),
+        ]
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        lookup_config: LookupConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn new<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> Self {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let lookup_config = LookupChip::<F>::configure(meta, main_gate_config.advice_columns());
+            Self {
+                main_gate_config,
+                lookup_config,
+            }
+        }
+
+        fn main_gate<F: PrimeField>(&self) -> MainGate<F> {
+            MainGate::<F>::new(self.main_gate_config.clone())
+        }
+
+        fn lookup_chip<F: PrimeField>(&self) -> LookupChip<F> {
+            LookupChip::<F>::new(self.lookup_config.clone(), tables())
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuit {
+        lookups: Vec<(usize, Vec<u64>)>,
+    }
+
+    impl Circuit<Fp> for TestCircuit {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let lookup_chip = config.lookup_chip();
+            let main_gate = config.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for (table_id, inputs) in self.lookups.iter() {
+                        let inputs: Vec<Value<Fp>> =
+                            inputs.iter().map(|e| Value::known(Fp::from(*e))).collect();
+                        let assigned = lookup_chip.lookup(ctx, *table_id, &inputs)?;
+
+                        // looked up cells are usable in main gate
+                        for (assigned, input) in assigned.iter().zip(inputs.iter()) {
+                            let copied = main_gate.assign_value(ctx, *input)?;
+                            main_gate.assert_equal(ctx, assigned, &copied)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            lookup_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_lookup_circuit() {
+        let k = 9;
+        let run = |lookups: Vec<(usize, Vec<u64>)>| {
+            let circuit = TestCircuit { lookups };
+            let prover = match MockProver::run(k, &circuit, vec![vec![]]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            prover.verify()
+        };
+
+        let lookups = vec![
+            (XOR, vec![0x3, 0x5, 0x6]),
+            (XOR, vec![0xf, 0xf, 0x0]),
+            (BITS, vec![0xa, 0, 1, 0, 1]),
+            (SBOX, vec![0x0, 0xc]),
+            (SBOX, vec![0xf, 0x2]),
+            (RANGE, vec![0]),
+            (RANGE, vec![RANGE_SIZE as u64 - 1]),
+        ];
+        assert_eq!(run(lookups), Ok(()));
+
+        assert!(run(vec![(XOR, vec![0x3, 0x5, 0x7])]).is_err());
+        assert!(run(vec![(BITS, vec![0xa, 1, 0, 1, 0])]).is_err());
+        assert!(run(vec![(RANGE, vec![RANGE_SIZE as u64])]).is_err());
+        // rows of one table do not satisfy another
+        assert!(run(vec![(SBOX, vec![0x3, 0x5])]).is_err());
+    }
+}
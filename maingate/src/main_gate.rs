@@ -7,10 +7,20 @@
 //! q_e_next * e +
 //! public_input +
 //! q_constant = 0
+//!
+//! Alternative shapes are configured with [`MainGateBuilder`]. Four width
+//! shape drops column `e` and rotates `d` instead, `q_mul_cd * c * d` term can
+//! be left out and `q_pow * a^degree` term can be added for S-boxes.
+//!
+//! Only [`MainGate`] itself is generic over the width. [`crate::RangeChip`]
+//! and the chips built on top of it such as integer and ECC chips use
+//! `MainGate<F>` and therefore require the five width shape, while the second
+//! multiplication and the power term can still be varied for them. The four
+//! width shape is not supported for those chips.
 
 use crate::halo2::circuit::{Chip, Layouter};
 use crate::halo2::halo2curves::ff::PrimeField;
-use crate::halo2::plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Instance};
+use crate::halo2::plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance};
 use crate::halo2::poly::Rotation;
 use crate::instructions::{CombinationOptionCommon, MainGateInstructions, Term};
 use crate::{AssignedCondition, AssignedValue};
@@ -67,18 +77,19 @@ pub struct MainGateConfig {
     pub(crate) b: Column<Advice>,
     pub(crate) c: Column<Advice>,
     pub(crate) d: Column<Advice>,
-    pub(crate) e: Column<Advice>,
+    pub(crate) e: Option<Column<Advice>>,
 
     pub(crate) sa: Column<Fixed>,
     pub(crate) sb: Column<Fixed>,
     pub(crate) sc: Column<Fixed>,
     pub(crate) sd: Column<Fixed>,
-    pub(crate) se: Column<Fixed>,
+    pub(crate) se: Option<Column<Fixed>>,
 
     pub(crate) se_next: Column<Fixed>,
 
     pub(crate) s_mul_ab: Column<Fixed>,
-    pub(crate) s_mul_cd: Column<Fixed>,
+    pub(crate) s_mul_cd: Option<Column<Fixed>>,
+    pub(crate) s_pow: Option<(Column<Fixed>, u64)>,
 
     pub(crate) s_constant: Column<Fixed>,
     /// column for public input
@@ -86,20 +97,180 @@ pub struct MainGateConfig {
 }
 
 impl MainGateConfig {
-    /// Returns advice columns of `MainGateConfig`
+    /// Returns advice columns of the five width `MainGateConfig`. Panics if
+    /// the config is of the four width shape, see
+    /// [`MainGateConfig::advice_columns`] for any shape
+    #[deprecated(note = "panics for the four width shape, use `advice_columns` instead")]
     pub fn advices(&self) -> [Column<Advice>; WIDTH] {
-        [self.a, self.b, self.c, self.d, self.e]
+        let e = self.e.expect("Four width main gate has no column e");
+        [self.a, self.b, self.c, self.d, e]
+    }
+
+    /// Returns advice columns of `MainGateConfig` of any width
+    pub fn advice_columns(&self) -> Vec<Column<Advice>> {
+        [self.a, self.b, self.c, self.d]
+            .into_iter()
+            .chain(self.e)
+            .collect()
+    }
+
+    /// Returns number of advice columns
+    pub fn width(&self) -> usize {
+        self.advice_columns().len()
+    }
+
+    /// Returns fixed columns that scale advice columns
+    fn coeffs(&self) -> Vec<Column<Fixed>> {
+        [self.sa, self.sb, self.sc, self.sd]
+            .into_iter()
+            .chain(self.se)
+            .collect()
     }
 }
 
-/// MainGate implements instructions with [`MainGateConfig`]
+/// Builder for alternative shapes of the main gate. Default shape is the five
+/// width gate with two multiplications
 #[derive(Clone, Debug)]
-pub struct MainGate<F: PrimeField> {
+pub struct MainGateBuilder {
+    width: usize,
+    double_mul: bool,
+    pow_degree: Option<u64>,
+}
+
+impl Default for MainGateBuilder {
+    fn default() -> Self {
+        Self {
+            width: WIDTH,
+            double_mul: true,
+            pow_degree: None,
+        }
+    }
+}
+
+impl MainGateBuilder {
+    /// Sets number of advice columns which is either `4` or `5`. Four width
+    /// shape is only usable with `MainGate<F, 4>` directly
+    pub fn width(mut self, width: usize) -> Self {
+        assert!(width == 4 || width == 5, "Unsupported main gate width");
+        self.width = width;
+        self
+    }
+
+    /// Enables or disables `q_mul_cd * c * d` term
+    pub fn double_mul(mut self, double_mul: bool) -> Self {
+        self.double_mul = double_mul;
+        self
+    }
+
+    /// Adds `q_pow * a^degree` term
+    pub fn pow(mut self, degree: u64) -> Self {
+        assert!(
+            degree > 1,
+            "Power term is expected to be at least quadratic"
+        );
+        self.pow_degree = Some(degree);
+        self
+    }
+
+    /// Configures polynomial relationships of the shape and returns the
+    /// resulting config
+    pub fn configure<F: PrimeField>(&self, meta: &mut ConstraintSystem<F>) -> MainGateConfig {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let d = meta.advice_column();
+        let e = (self.width == 5).then(|| meta.advice_column());
+
+        let sa = meta.fixed_column();
+        let sb = meta.fixed_column();
+        let sc = meta.fixed_column();
+        let sd = meta.fixed_column();
+        let se = (self.width == 5).then(|| meta.fixed_column());
+
+        let s_mul_ab = meta.fixed_column();
+        let s_mul_cd = self.double_mul.then(|| meta.fixed_column());
+        let s_pow = self.pow_degree.map(|degree| (meta.fixed_column(), degree));
+
+        let se_next = meta.fixed_column();
+        let s_constant = meta.fixed_column();
+
+        let instance = meta.instance_column();
+
+        let config = MainGateConfig {
+            a,
+            b,
+            c,
+            d,
+            e,
+            sa,
+            sb,
+            sc,
+            sd,
+            se,
+            se_next,
+            s_constant,
+            s_mul_ab,
+            s_mul_cd,
+            s_pow,
+            instance,
+        };
+
+        let advices = config.advice_columns();
+        let coeffs = config.coeffs();
+        for advice in advices.iter() {
+            meta.enable_equality(*advice);
+        }
+        meta.enable_equality(instance);
+
+        meta.create_gate("main_gate", |meta| {
+            // rotation applies to the last column
+            let next = meta.query_advice(*advices.last().unwrap(), Rotation::next());
+            let advices: Vec<Expression<F>> = advices
+                .iter()
+                .map(|advice| meta.query_advice(*advice, Rotation::cur()))
+                .collect();
+
+            let se_next = meta.query_fixed(se_next, Rotation::cur());
+            let s_mul_ab = meta.query_fixed(s_mul_ab, Rotation::cur());
+            let s_constant = meta.query_fixed(s_constant, Rotation::cur());
+
+            let mut expression =
+                advices
+                    .iter()
+                    .zip(coeffs.iter())
+                    .fold(s_constant, |acc, (advice, coeff)| {
+                        acc + advice.clone() * meta.query_fixed(*coeff, Rotation::cur())
+                    })
+                    + advices[0].clone() * advices[1].clone() * s_mul_ab
+                    + se_next * next;
+
+            if let Some(s_mul_cd) = s_mul_cd {
+                let s_mul_cd = meta.query_fixed(s_mul_cd, Rotation::cur());
+                expression = expression + advices[2].clone() * advices[3].clone() * s_mul_cd;
+            }
+
+            if let Some((s_pow, degree)) = s_pow {
+                let s_pow = meta.query_fixed(s_pow, Rotation::cur());
+                let pow = (1..degree).fold(advices[0].clone(), |acc, _| acc * advices[0].clone());
+                expression = expression + pow * s_pow;
+            }
+
+            vec![expression]
+        });
+
+        config
+    }
+}
+
+/// MainGate implements instructions with [`MainGateConfig`]. `W` is expected
+/// to be the width of the config
+#[derive(Clone, Debug)]
+pub struct MainGate<F: PrimeField, const W: usize = WIDTH> {
     config: MainGateConfig,
     _marker: PhantomData<F>,
 }
 
-impl<F: PrimeField> Chip<F> for MainGate<F> {
+impl<F: PrimeField, const W: usize> Chip<F> for MainGate<F, W> {
     type Config = MainGateConfig;
     type Loaded = ();
 
@@ -122,6 +293,8 @@ pub enum CombinationOption<F: PrimeField> {
     /// Activates both multiplication gate and combines the result to the next
     /// row
     CombineToNextDoubleMul(F),
+    /// Activates the power term with the given coefficient
+    OneLinerPow(F),
 }
 
 impl<F: PrimeField> From<CombinationOptionCommon<F>> for CombinationOption<F> {
@@ -130,7 +303,7 @@ impl<F: PrimeField> From<CombinationOptionCommon<F>> for CombinationOption<F> {
     }
 }
 
-impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
+impl<F: PrimeField, const W: usize> MainGateInstructions<F, W> for MainGate<F, W> {
     type CombinationOption = CombinationOption<F>;
     type MainGateColumn = MainGateColumn;
 
@@ -150,19 +323,30 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
         unassigned: Value<F>,
         column: MainGateColumn,
     ) -> Result<AssignedValue<F>, Error> {
-        let column = match column {
-            MainGateColumn::A => self.config.a,
-            MainGateColumn::B => self.config.b,
-            MainGateColumn::C => self.config.c,
-            MainGateColumn::D => self.config.d,
-            MainGateColumn::E => self.config.e,
-        };
+        let column = *self
+            .config
+            .advice_columns()
+            .get(column as usize)
+            .expect("Column is not in the main gate");
         let cell = ctx.assign_advice(|| "assign value", column, unassigned)?;
         // proceed to the next row
         self.no_operation(ctx)?;
         Ok(cell)
     }
 
+    fn assign_to_acc(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        unassigned: Value<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        // accumulator is the rotated column which is `d` in the four width shape
+        let column = match self.config.e {
+            Some(_) => MainGateColumn::E,
+            None => MainGateColumn::D,
+        };
+        self.assign_to_column(ctx, unassigned, column)
+    }
+
     fn sub_sub_with_constant(
         &self,
         ctx: &mut RegionCtx<'_, F>,
@@ -199,15 +383,6 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
         b: &AssignedValue<F>,
         cond: &AssignedCondition<F>,
     ) -> Result<AssignedValue<F>, Error> {
-        // We should satisfy the equation below with bit asserted condition flag
-        // c (a-b) + b - res = 0
-        // cond * a - cond * b + b - res = 0
-
-        // Witness layout:
-        // | A   | B   | C | D   | E  |
-        // | --- | --- | - | --- | ---|
-        // | c   | a   | c | b   | res|
-
         let res = a
             .value()
             .zip(b.value())
@@ -221,6 +396,46 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
                 }
             });
 
+        if W < WIDTH || self.config.s_mul_cd.is_none() {
+            // Without the second multiplication difference goes to another row
+            // a - b - dif = 0
+            // cond * dif + b - res = 0
+            let dif = a.value().zip(b.value()).map(|(a, b)| *a - *b);
+            let dif = &self.apply(
+                ctx,
+                [
+                    Term::assigned_to_add(a),
+                    Term::assigned_to_sub(b),
+                    Term::unassigned_to_sub(dif),
+                ],
+                F::ZERO,
+                CombinationOptionCommon::OneLinerAdd.into(),
+            )?[2];
+
+            return Ok(self
+                .apply(
+                    ctx,
+                    [
+                        Term::assigned_to_mul(cond),
+                        Term::assigned_to_mul(dif),
+                        Term::assigned_to_add(b),
+                        Term::unassigned_to_sub(res),
+                    ],
+                    F::ZERO,
+                    CombinationOptionCommon::OneLinerMul.into(),
+                )?
+                .swap_remove(3));
+        }
+
+        // We should satisfy the equation below with bit asserted condition flag
+        // c (a-b) + b - res = 0
+        // cond * a - cond * b + b - res = 0
+
+        // Witness layout:
+        // | A   | B   | C | D   | E  |
+        // | --- | --- | - | --- | ---|
+        // | c   | a   | c | b   | res|
+
         let mut assigned = self.apply(
             ctx,
             [
@@ -302,82 +517,60 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
         option: CombinationOption<F>,
     ) -> Result<Vec<AssignedValue<F>>, Error> {
         let terms = terms.into_iter().collect::<Vec<_>>();
-        debug_assert!(terms.len() <= WIDTH);
-
-        let assigned = [
-            self.config.a,
-            self.config.b,
-            self.config.c,
-            self.config.d,
-            self.config.e,
-        ]
-        .into_iter()
-        .zip([
-            self.config.sa,
-            self.config.sb,
-            self.config.sc,
-            self.config.sd,
-            self.config.se,
-        ])
-        .zip(terms.iter().chain(iter::repeat(&Term::Zero)))
-        .enumerate()
-        .map(|(idx, ((coeff, base), term))| {
-            let assigned = ctx.assign_advice(|| format!("coeff_{idx}"), coeff, term.coeff())?;
-            ctx.assign_fixed(|| format!("base_{idx}"), base, term.base())?;
-            Ok(assigned)
-        })
-        .collect::<Result<Vec<_>, Error>>()?;
+        assert!(
+            terms.len() <= W,
+            "Number of terms exceeds the main gate width"
+        );
+
+        let assigned = self
+            .config
+            .advice_columns()
+            .into_iter()
+            .zip(self.config.coeffs())
+            .zip(terms.iter().chain(iter::repeat(&Term::Zero)))
+            .enumerate()
+            .map(|(idx, ((coeff, base), term))| {
+                let assigned = ctx.assign_advice(|| format!("coeff_{idx}"), coeff, term.coeff())?;
+                ctx.assign_fixed(|| format!("base_{idx}"), base, term.base())?;
+                Ok(assigned)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
 
         ctx.assign_fixed(|| "s_constant", self.config.s_constant, constant)?;
 
-        // Given specific option configure multiplication and rotation gates
-        match option {
+        // Given specific option configure multiplication, power and rotation
+        // gates as `(s_mul_ab, s_mul_cd, s_pow, se_next)`
+        let (s_mul_ab, s_mul_cd, s_pow, se_next) = match option {
             CombinationOption::Common(option) => match option {
                 // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
                 // a * b +
                 // q_e_next * e +
                 // q_constant = 0
-                CombinationOptionCommon::CombineToNextMul(next) => {
-                    ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ONE)?;
-                    ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-                    ctx.assign_fixed(|| "se_next", self.config.se_next, next)?;
-                }
+                CombinationOptionCommon::CombineToNextMul(next) => (F::ONE, F::ZERO, F::ZERO, next),
 
                 // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
                 // q_mul_ab * a * b +
                 // q_e_next * e +
                 // q_constant = 0
                 CombinationOptionCommon::CombineToNextScaleMul(next, n) => {
-                    ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, n)?;
-                    ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-                    ctx.assign_fixed(|| "se_next", self.config.se_next, next)?;
+                    (n, F::ZERO, F::ZERO, next)
                 }
 
                 // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
                 // q_e_next * e +
                 // q_constant = 0
                 CombinationOptionCommon::CombineToNextAdd(next) => {
-                    ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ZERO)?;
-                    ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-                    ctx.assign_fixed(|| "se_next", self.config.se_next, next)?;
+                    (F::ZERO, F::ZERO, F::ZERO, next)
                 }
 
                 // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
                 // q_mul_ab * a * b +
                 // q_constant = 0
-                CombinationOptionCommon::OneLinerMul => {
-                    ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ONE)?;
-                    ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-                    ctx.assign_fixed(|| "se_next", self.config.se_next, F::ZERO)?;
-                }
+                CombinationOptionCommon::OneLinerMul => (F::ONE, F::ZERO, F::ZERO, F::ZERO),
 
                 // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
                 // q_constant = 0
-                CombinationOptionCommon::OneLinerAdd => {
-                    ctx.assign_fixed(|| "se_next", self.config.se_next, F::ZERO)?;
-                    ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ZERO)?;
-                    ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-                }
+                CombinationOptionCommon::OneLinerAdd => (F::ZERO, F::ZERO, F::ZERO, F::ZERO),
             },
 
             // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
@@ -385,23 +578,38 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
             // q_mul_cd * c * d +
             // q_e_next * e +
             // q_constant = 0
-            CombinationOption::CombineToNextDoubleMul(next) => {
-                ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ONE)?;
-                ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ONE)?;
-                ctx.assign_fixed(|| "se_next", self.config.se_next, next)?;
-            }
+            CombinationOption::CombineToNextDoubleMul(next) => (F::ONE, F::ONE, F::ZERO, next),
 
             // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
             // q_mul_ab * a * b +
             // q_mul_cd * c * d +
             // q_constant = 0
-            CombinationOption::OneLinerDoubleMul(e) => {
-                ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ONE)?;
-                ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, e)?;
-                ctx.assign_fixed(|| "se_next", self.config.se_next, F::ZERO)?;
-            }
+            CombinationOption::OneLinerDoubleMul(e) => (F::ONE, e, F::ZERO, F::ZERO),
+
+            // q_a * a + q_b * b + q_c * c + q_d * d + q_e * e +
+            // q_pow * a^degree +
+            // q_constant = 0
+            CombinationOption::OneLinerPow(e) => (F::ZERO, F::ZERO, e, F::ZERO),
         };
 
+        ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, s_mul_ab)?;
+        ctx.assign_fixed(|| "se_next", self.config.se_next, se_next)?;
+        match self.config.s_mul_cd {
+            Some(column) => {
+                ctx.assign_fixed(|| "s_mul_cd", column, s_mul_cd)?;
+            }
+            None => assert!(
+                s_mul_cd == F::ZERO,
+                "Main gate has no second multiplication"
+            ),
+        }
+        match self.config.s_pow {
+            Some((column, _)) => {
+                ctx.assign_fixed(|| "s_pow", column, s_pow)?;
+            }
+            None => assert!(s_pow == F::ZERO, "Main gate has no power term"),
+        }
+
         // If given witness is already assigned apply copy constains
         for (term, rhs) in terms.iter().zip(assigned.iter()) {
             if let Term::Assigned(lhs, _) = term {
@@ -416,123 +624,97 @@ impl<F: PrimeField> MainGateInstructions<F, WIDTH> for MainGate<F> {
 
     /// Skip this row without any operation
     fn no_operation(&self, ctx: &mut RegionCtx<'_, F>) -> Result<(), Error> {
-        ctx.assign_fixed(|| "s_mul_ab", self.config.s_mul_ab, F::ZERO)?;
-        ctx.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, F::ZERO)?;
-        ctx.assign_fixed(|| "sc", self.config.sc, F::ZERO)?;
-        ctx.assign_fixed(|| "sa", self.config.sa, F::ZERO)?;
-        ctx.assign_fixed(|| "sb", self.config.sb, F::ZERO)?;
-        ctx.assign_fixed(|| "sd", self.config.sd, F::ZERO)?;
-        ctx.assign_fixed(|| "se", self.config.se, F::ZERO)?;
-        ctx.assign_fixed(|| "se_next", self.config.se_next, F::ZERO)?;
-        ctx.assign_fixed(|| "s_constant", self.config.s_constant, F::ZERO)?;
+        let fixed = self
+            .config
+            .coeffs()
+            .into_iter()
+            .chain([
+                self.config.s_mul_ab,
+                self.config.se_next,
+                self.config.s_constant,
+            ])
+            .chain(self.config.s_mul_cd)
+            .chain(self.config.s_pow.map(|(column, _)| column));
+        for column in fixed {
+            ctx.assign_fixed(|| "no operation", column, F::ZERO)?;
+        }
         ctx.next();
         Ok(())
     }
 }
 
-impl<F: PrimeField> MainGate<F> {
+impl<F: PrimeField, const W: usize> MainGate<F, W> {
     /// Create new main gate with given config
     pub fn new(config: MainGateConfig) -> Self {
+        assert_eq!(config.width(), W, "Config width doesn't match the gate");
         MainGate {
             config,
             _marker: PhantomData,
         }
     }
 
-    /// Configures polynomial relationships and returns the resuiting config
+    /// Configures polynomial relationships of the default shape and returns
+    /// the resuiting config
     pub fn configure(meta: &mut ConstraintSystem<F>) -> MainGateConfig {
-        let a = meta.advice_column();
-        let b = meta.advice_column();
-        let c = meta.advice_column();
-        let d = meta.advice_column();
-        let e = meta.advice_column();
-
-        let sa = meta.fixed_column();
-        let sb = meta.fixed_column();
-        let sc = meta.fixed_column();
-        let sd = meta.fixed_column();
-        let se = meta.fixed_column();
-
-        let s_mul_ab = meta.fixed_column();
-        let s_mul_cd = meta.fixed_column();
-
-        let se_next = meta.fixed_column();
-        let s_constant = meta.fixed_column();
-
-        let instance = meta.instance_column();
-
-        meta.enable_equality(a);
-        meta.enable_equality(b);
-        meta.enable_equality(c);
-        meta.enable_equality(d);
-        meta.enable_equality(e);
-        meta.enable_equality(instance);
-
-        meta.create_gate("main_gate", |meta| {
-            let a = meta.query_advice(a, Rotation::cur());
-            let b = meta.query_advice(b, Rotation::cur());
-            let c = meta.query_advice(c, Rotation::cur());
-            let d = meta.query_advice(d, Rotation::cur());
-            let e_next = meta.query_advice(e, Rotation::next());
-            let e = meta.query_advice(e, Rotation::cur());
-
-            let sa = meta.query_fixed(sa, Rotation::cur());
-            let sb = meta.query_fixed(sb, Rotation::cur());
-            let sc = meta.query_fixed(sc, Rotation::cur());
-            let sd = meta.query_fixed(sd, Rotation::cur());
-            let se = meta.query_fixed(se, Rotation::cur());
-
-            let se_next = meta.query_fixed(se_next, Rotation::cur());
-
-            let s_mul_ab = meta.query_fixed(s_mul_ab, Rotation::cur());
-            let s_mul_cd = meta.query_fixed(s_mul_cd, Rotation::cur());
-
-            let s_constant = meta.query_fixed(s_constant, Rotation::cur());
+        MainGateBuilder::default().configure(meta)
+    }
 
-            vec![
-                a.clone() * sa
-                    + b.clone() * sb
-                    + c.clone() * sc
-                    + d.clone() * sd
-                    + e * se
-                    + a * b * s_mul_ab
-                    + c * d * s_mul_cd
-                    + se_next * e_next
-                    + s_constant,
-            ]
-        });
+    /// Returns `a^exponent`. Uses a single row if the gate has the power term
+    /// of the same degree and falls back to multiplications otherwise
+    pub fn pow(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        exponent: u64,
+    ) -> Result<AssignedValue<F>, Error> {
+        assert!(exponent > 0, "Exponent is expected to be positive");
+        let res = a.value().map(|a| a.pow([exponent]));
+
+        if matches!(self.config.s_pow, Some((_, degree)) if degree == exponent) {
+            return Ok(self
+                .apply(
+                    ctx,
+                    [Term::assigned_to_mul(a), Term::unassigned_to_sub(res)],
+                    F::ZERO,
+                    CombinationOption::OneLinerPow(F::ONE),
+                )?
+                .swap_remove(1));
+        }
 
-        MainGateConfig {
-            a,
-            b,
-            c,
-            d,
-            e,
-            sa,
-            sb,
-            sc,
-            sd,
-            se,
-            se_next,
-            s_constant,
-            s_mul_ab,
-            s_mul_cd,
-            instance,
+        // square and multiply
+        let mut acc: Option<AssignedValue<F>> = None;
+        let mut base = a.clone();
+        let mut exponent = exponent;
+        loop {
+            if exponent & 1 == 1 {
+                acc = Some(match acc {
+                    Some(acc) => self.mul(ctx, &acc, &base)?,
+                    None => base.clone(),
+                });
+            }
+            exponent >>= 1;
+            if exponent == 0 {
+                break;
+            }
+            base = self.mul(ctx, &base, &base)?;
         }
+        Ok(acc.unwrap())
     }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::{MainGate, MainGateConfig, Term};
+    use super::{MainGate, MainGateBuilder, MainGateConfig, Term};
     use crate::curves::{ff::PrimeField, pasta::Fp};
     use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
     use crate::halo2::dev::MockProver;
     use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
     use crate::main_gate::{CombinationOptionCommon, MainGateInstructions};
     use crate::AssignedCondition;
-    use halo2wrong::utils::{big_to_fe, decompose};
+    use halo2wrong::utils::{
+        big_to_fe, decompose, decompose_big, modulus, power_of_two, DimensionMeasurement,
+    };
     use halo2wrong::RegionCtx;
     use rand_core::OsRng;
     use std::marker::PhantomData;
@@ -1452,6 +1634,106 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct TestCircuitCanonicalBits<F: PrimeField> {
+        _marker: PhantomData<F>,
+        // Bits are witnessed from this integer which might exceed the modulus
+        value: num_bigint::BigUint,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuitCanonicalBits<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = config.main_gate::<F>();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let number_of_bits = F::NUM_BITS as usize;
+                    let bits = decompose_big::<F>(self.value.clone(), number_of_bits, 1)
+                        .into_iter()
+                        .map(|bit| main_gate.assign_bit(ctx, Value::known(bit)))
+                        .collect::<Result<Vec<AssignedCondition<F>>, Error>>()?;
+                    let terms = bits
+                        .iter()
+                        .enumerate()
+                        .map(|(i, bit)| Term::Assigned(bit, power_of_two(i)))
+                        .collect::<Vec<Term<F>>>();
+                    let composed = main_gate.compose(ctx, &terms, F::ZERO)?;
+
+                    // Both decompositions compose to the same value
+                    let expected = big_to_fe::<F>(self.value.clone());
+                    main_gate.assert_equal_to_constant(ctx, &composed, expected)?;
+                    main_gate.assert_canonical_bits(ctx, &bits)?;
+
+                    let canonical = main_gate.to_bits_canonical(ctx, &composed)?;
+                    for (bit, expected) in
+                        canonical.iter().zip(decompose(expected, number_of_bits, 1))
+                    {
+                        main_gate.assert_equal_to_constant(ctx, bit, expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_canonical_bits() {
+        use num_bigint::{BigUint as big_uint, RandomBits};
+        use rand::Rng;
+
+        const K: u32 = 11;
+        let modulus = modulus::<Fp>();
+        let run = |value: big_uint| {
+            let circuit = TestCircuitCanonicalBits::<Fp> {
+                _marker: PhantomData,
+                value,
+            };
+            let prover = match MockProver::run(K, &circuit, vec![vec![]]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            prover.verify()
+        };
+
+        // Values whose `value + modulus` fits in `NUM_BITS` bits has an
+        // alternative decomposition which must be rejected
+        let bit_len = Fp::NUM_BITS as u64 - 2;
+        for _ in 0..10 {
+            let value: big_uint = OsRng.sample(RandomBits::new(bit_len));
+            assert_eq!(run(value.clone()), Ok(()));
+            assert!(run(value + &modulus).is_err());
+        }
+
+        assert_eq!(run(modulus.clone() - 1usize), Ok(()));
+        assert_eq!(run(big_uint::from(0usize)), Ok(()));
+        assert!(run(modulus.clone()).is_err());
+    }
+
     #[derive(Default)]
     struct TestCircuitComposition<F: PrimeField> {
         _marker: PhantomData<F>,
@@ -1609,4 +1891,144 @@ mod tests {
 
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[derive(Default)]
+    struct TestCircuitShape<F: PrimeField, const W: usize, const DOUBLE_MUL: bool, const POW: u64> {
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField, const W: usize, const DOUBLE_MUL: bool, const POW: u64> Circuit<F>
+        for TestCircuitShape<F, W, DOUBLE_MUL, POW>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let builder = MainGateBuilder::default().width(W).double_mul(DOUBLE_MUL);
+            let builder = if POW > 1 { builder.pow(POW) } else { builder };
+            let main_gate_config = builder.configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F, W>::new(config.main_gate_config);
+
+            let rand = || -> F { F::random(OsRng) };
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = rand();
+                    let b = rand();
+                    let a = main_gate.assign_value(ctx, Value::known(a))?;
+                    let b = main_gate.assign_value(ctx, Value::known(b))?;
+
+                    for cond in [F::ZERO, F::ONE] {
+                        let cond = main_gate.assign_bit(ctx, Value::known(cond))?;
+                        let selected = main_gate.select(ctx, &a, &b, &cond)?;
+                        let expected = a
+                            .value()
+                            .zip(b.value())
+                            .zip(cond.value())
+                            .map(|((a, b), cond)| if *cond == F::ONE { *a } else { *b });
+                        let expected = main_gate.assign_value(ctx, expected)?;
+                        main_gate.assert_equal(ctx, &selected, &expected)?;
+                    }
+
+                    for exponent in [1, 2, 3, 5, 7] {
+                        let expected = a.value().map(|a| a.pow([exponent]));
+                        let expected = main_gate.assign_value(ctx, expected)?;
+                        let result = main_gate.pow(ctx, &a, exponent)?;
+                        main_gate.assert_equal(ctx, &expected, &result)?;
+                    }
+
+                    let sum = a.value().zip(b.value()).map(|(a, b)| *a + *b);
+                    main_gate.apply(
+                        ctx,
+                        [Term::assigned_to_add(&a), Term::assigned_to_add(&b)],
+                        F::ZERO,
+                        CombinationOptionCommon::CombineToNextAdd(-F::ONE).into(),
+                    )?;
+                    let sum = main_gate.assign_to_acc(ctx, sum)?;
+                    let expected = main_gate.add(ctx, &a, &b)?;
+                    main_gate.assert_equal(ctx, &sum, &expected)?;
+
+                    let ab = main_gate.mul(ctx, &a, &b)?;
+                    let b_inv = main_gate.invert_unsafe(ctx, &b)?;
+                    let result = main_gate.mul(ctx, &ab, &b_inv)?;
+                    main_gate.assert_equal(ctx, &a, &result)?;
+
+                    for number_of_terms in 1..20 {
+                        let constant = rand();
+                        let terms = (0..number_of_terms)
+                            .map(|_| (Term::Unassigned(Value::known(rand()), rand())))
+                            .collect::<Vec<Term<F>>>();
+                        let expected = Term::compose(&terms, constant);
+                        let expected = main_gate.assign_value(ctx, expected)?;
+                        let result = main_gate.compose(ctx, &terms, constant)?;
+                        main_gate.assert_equal(ctx, &expected, &result)?;
+
+                        let terms = terms
+                            .into_iter()
+                            .chain(std::iter::once(Term::assigned_to_sub(&result)))
+                            .collect::<Vec<Term<F>>>();
+                        main_gate.assert_zero_sum(ctx, &terms, constant)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_shapes() {
+        fn run<const W: usize, const DOUBLE_MUL: bool, const POW: u64>() -> usize {
+            const K: u32 = 10;
+            let circuit = TestCircuitShape::<Fp, W, DOUBLE_MUL, POW>::default();
+            let public_inputs = vec![vec![]];
+            let prover = match MockProver::run(K, &circuit, public_inputs) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            assert_eq!(prover.verify(), Ok(()));
+
+            DimensionMeasurement::measure(&circuit)
+                .unwrap()
+                .advice_rows() as usize
+        }
+
+        let default = run::<5, true, 0>();
+        let single_mul = run::<5, false, 0>();
+        let pow = run::<5, true, 5>();
+        let narrow = run::<4, false, 0>();
+
+        // each of two selects takes an extra row without the second
+        // multiplication
+        assert_eq!(single_mul, default + 2);
+        // fifth power takes a single row instead of three multiplications
+        assert_eq!(pow, default - 2);
+        // with one less column compositions of 1 to 19 terms take 15 more rows
+        // and zero sum assertions of 2 to 20 terms take another 15 rows
+        assert_eq!(narrow, single_mul + 30);
+        // second multiplication is not used by the four width shape and cube
+        // takes a single row instead of two multiplications
+        assert_eq!(run::<4, true, 3>(), narrow - 1);
+        assert_eq!(run::<4, false, 5>(), narrow - 2);
+    }
 }
@@ -7,11 +7,11 @@ use crate::halo2::plonk::{ConstraintSystem, Error, Expression};
 use crate::halo2::plonk::{Selector, TableColumn};
 use crate::halo2::poly::Rotation;
 use crate::instructions::{MainGateInstructions, Term};
-use crate::AssignedValue;
+use crate::{AssignedCondition, AssignedValue};
 use halo2wrong::halo2::plonk::Advice;
 use halo2wrong::halo2::plonk::Column;
 use halo2wrong::halo2::plonk::Fixed;
-use halo2wrong::utils::decompose;
+use halo2wrong::utils::{big_to_fe, decompose, fe_to_big};
 use halo2wrong::RegionCtx;
 use num_integer::Integer;
 use std::collections::BTreeMap;
@@ -78,6 +78,66 @@ pub trait RangeInstructions<F: PrimeField>: Chip<F> {
         bit_len: usize,
     ) -> Result<(AssignedValue<F>, Vec<AssignedValue<F>>), Error>;
 
+    /// Returns `1` if `a < b` and `0` otherwise where both values are
+    /// expected to be in `bit_len` bits
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<AssignedCondition<F>, Error>;
+
+    /// Returns `1` if `a < constant` and `0` otherwise where both values are
+    /// expected to be in `bit_len` bits
+    fn is_less_than_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        constant: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<AssignedCondition<F>, Error>;
+
+    /// Constrains `a < b` where `b` is expected to be in `bit_len` bits.
+    /// `a` is ranged in `bit_len` bits here so it doesn't have to be ranged
+    /// by the caller
+    fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error>;
+
+    /// Constrains `lo <= a < hi` where bounds are expected to be in `bit_len`
+    /// bits
+    fn assert_in_range(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        lo: F,
+        hi: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error>;
+
+    /// Returns quotient and remainder of `a` divided by a non zero constant.
+    /// Both are ranged in `bit_len` bits that `a` is expected to be in and
+    /// remainder is constrained to be less than the divisor. Divisor must not
+    /// exceed `2^bit_len` since remainder is bounded with a `bit_len` bits
+    /// range check
+    fn div_rem(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        divisor: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(AssignedValue<F>, AssignedValue<F>), Error>;
+
     /// Load table in sythnesis time
     fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error>;
 }
@@ -154,6 +214,116 @@ impl<F: PrimeField> RangeInstructions<F> for RangeChip<F> {
             })
     }
 
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        let terms = [Term::assigned_to_add(a), Term::assigned_to_sub(b)];
+        let is_not_negative = self.is_not_negative(ctx, &terms, F::ZERO, limb_bit_len, bit_len)?;
+        self.main_gate().not(ctx, &is_not_negative)
+    }
+
+    fn is_less_than_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        constant: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        let terms = [Term::assigned_to_add(a)];
+        let is_not_negative =
+            self.is_not_negative(ctx, &terms, -constant, limb_bit_len, bit_len)?;
+        self.main_gate().not(ctx, &is_not_negative)
+    }
+
+    fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        b: &AssignedValue<F>,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error> {
+        // `b - a - 1` would wrap around the modulus unless `a < b` given
+        // that `a` is in `bit_len` bits
+        self.assert_in_bit_len(
+            ctx,
+            &[Term::assigned_to_add(a)],
+            F::ZERO,
+            limb_bit_len,
+            bit_len,
+        )?;
+        let terms = [Term::assigned_to_add(b), Term::assigned_to_sub(a)];
+        self.assert_in_bit_len(ctx, &terms, -F::ONE, limb_bit_len, bit_len)
+    }
+
+    fn assert_in_range(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        lo: F,
+        hi: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error> {
+        self.assert_in_bit_len(ctx, &[Term::assigned_to_add(a)], -lo, limb_bit_len, bit_len)?;
+        self.assert_in_bit_len(
+            ctx,
+            &[Term::assigned_to_sub(a)],
+            hi - F::ONE,
+            limb_bit_len,
+            bit_len,
+        )
+    }
+
+    fn div_rem(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        divisor: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(AssignedValue<F>, AssignedValue<F>), Error> {
+        let divisor_big = fe_to_big(divisor);
+        assert!(divisor != F::ZERO);
+        // `divisor - 1 - remainder` must fit in `bit_len` bits for any remainder
+        assert!((&divisor_big - 1u32).bits() as usize <= bit_len);
+        // `quotient * divisor + remainder` must not wrap around the modulus
+        assert!(bit_len + (divisor_big.bits() as usize) < F::NUM_BITS as usize);
+
+        let quotient_remainder = a
+            .value()
+            .map(|a| fe_to_big(*a).div_rem(&divisor_big))
+            .map(|(quotient, remainder)| [quotient, remainder].map(big_to_fe::<F>));
+        let quotient = self.assign(ctx, quotient_remainder.map(|e| e[0]), limb_bit_len, bit_len)?;
+        let remainder =
+            self.assign(ctx, quotient_remainder.map(|e| e[1]), limb_bit_len, bit_len)?;
+
+        self.assert_in_bit_len(
+            ctx,
+            &[Term::assigned_to_sub(&remainder)],
+            divisor - F::ONE,
+            limb_bit_len,
+            bit_len,
+        )?;
+        self.main_gate().assert_zero_sum(
+            ctx,
+            &[
+                Term::Assigned(&quotient, divisor),
+                Term::assigned_to_add(&remainder),
+                Term::assigned_to_sub(a),
+            ],
+            F::ZERO,
+        )?;
+
+        Ok((quotient, remainder))
+    }
+
     fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
         layouter.assign_table(
             || "",
@@ -373,6 +543,62 @@ impl<F: PrimeField> RangeChip<F> {
         });
     }
 
+    /// Constrains composition of the terms to be in `bit_len` bits
+    fn assert_in_bit_len(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        terms: &[Term<F>],
+        constant: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error> {
+        let composed = Term::compose(terms, constant);
+        let composed = self.assign(ctx, composed, limb_bit_len, bit_len)?;
+        let terms: Vec<Term<F>> = terms
+            .iter()
+            .cloned()
+            .chain(std::iter::once(Term::assigned_to_sub(&composed)))
+            .collect();
+        self.main_gate().assert_zero_sum(ctx, &terms, constant)
+    }
+
+    /// Returns `1` if composition of the terms is not negative and `0`
+    /// otherwise where composition is expected to be in `(-2^bit_len,
+    /// 2^bit_len)`. Composition is shifted by `2^bit_len` and its bit at
+    /// `bit_len` is the result
+    fn is_not_negative(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        terms: &[Term<F>],
+        constant: F,
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<AssignedCondition<F>, Error> {
+        assert!(bit_len + 1 < F::NUM_BITS as usize);
+        let shift = F::from(2).pow([bit_len as u64]);
+        let shifted = Term::compose(terms, constant + shift).map(|e| decompose(e, 2, bit_len));
+
+        let low = self.assign(
+            ctx,
+            shifted.as_ref().map(|limbs| limbs[0]),
+            limb_bit_len,
+            bit_len,
+        )?;
+        let result = self
+            .main_gate()
+            .assign_bit(ctx, shifted.map(|limbs| limbs[1]))?;
+
+        let terms: Vec<Term<F>> = terms
+            .iter()
+            .cloned()
+            .chain([Term::assigned_to_sub(&low), Term::Assigned(&result, -shift)])
+            .collect();
+        self.main_gate()
+            .assert_zero_sum(ctx, &terms, constant + shift)?;
+
+        Ok(result)
+    }
+
     fn bases(&self, limb_bit_len: usize) -> &[F] {
         self.bases
             .get(&limb_bit_len)
@@ -537,4 +763,171 @@ mod tests {
         };
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[derive(Clone, Debug)]
+    enum Comparison {
+        IsLessThan(u64, u64, u64),
+        IsLessThanConstant(u64, u64, u64),
+        AssertLessThan(u64, u64),
+        // `a` is given negated and is not ranged
+        AssertLessThanNegated(u64, u64),
+        AssertInRange(u64, u64, u64),
+        DivRem(u64, u64, u64, u64),
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitComparison {
+        comparisons: Vec<Comparison>,
+    }
+
+    impl TestCircuitComparison {
+        const LIMB_BIT_LEN: usize = 8;
+        const BIT_LEN: usize = 11;
+    }
+
+    impl Circuit<Fp> for TestCircuitComparison {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            TestCircuitConfig::new(meta, vec![Self::LIMB_BIT_LEN], vec![3])
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let range_chip = config.range_chip();
+            let main_gate = config.main_gate();
+            let (limb_bit_len, bit_len) = (Self::LIMB_BIT_LEN, Self::BIT_LEN);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let assign = |ctx: &mut RegionCtx<'_, Fp>, value: u64| {
+                        range_chip.assign(ctx, Value::known(Fp::from(value)), limb_bit_len, bit_len)
+                    };
+
+                    for comparison in self.comparisons.iter() {
+                        match *comparison {
+                            Comparison::IsLessThan(a, b, expected) => {
+                                let a = assign(ctx, a)?;
+                                let b = assign(ctx, b)?;
+                                let result =
+                                    range_chip.is_less_than(ctx, &a, &b, limb_bit_len, bit_len)?;
+                                main_gate.assert_equal_to_constant(
+                                    ctx,
+                                    &result,
+                                    Fp::from(expected),
+                                )?;
+                            }
+                            Comparison::IsLessThanConstant(a, constant, expected) => {
+                                let a = assign(ctx, a)?;
+                                let result = range_chip.is_less_than_constant(
+                                    ctx,
+                                    &a,
+                                    Fp::from(constant),
+                                    limb_bit_len,
+                                    bit_len,
+                                )?;
+                                main_gate.assert_equal_to_constant(
+                                    ctx,
+                                    &result,
+                                    Fp::from(expected),
+                                )?;
+                            }
+                            Comparison::AssertLessThan(a, b) => {
+                                let a = assign(ctx, a)?;
+                                let b = assign(ctx, b)?;
+                                range_chip.assert_less_than(ctx, &a, &b, limb_bit_len, bit_len)?;
+                            }
+                            Comparison::AssertLessThanNegated(a, b) => {
+                                let a = main_gate.assign_value(ctx, Value::known(-Fp::from(a)))?;
+                                let b = assign(ctx, b)?;
+                                range_chip.assert_less_than(ctx, &a, &b, limb_bit_len, bit_len)?;
+                            }
+                            Comparison::AssertInRange(a, lo, hi) => {
+                                let a = assign(ctx, a)?;
+                                range_chip.assert_in_range(
+                                    ctx,
+                                    &a,
+                                    Fp::from(lo),
+                                    Fp::from(hi),
+                                    limb_bit_len,
+                                    bit_len,
+                                )?;
+                            }
+                            Comparison::DivRem(a, divisor, quotient, remainder) => {
+                                let a = assign(ctx, a)?;
+                                let (q, r) = range_chip.div_rem(
+                                    ctx,
+                                    &a,
+                                    Fp::from(divisor),
+                                    limb_bit_len,
+                                    bit_len,
+                                )?;
+                                main_gate.assert_equal_to_constant(ctx, &q, Fp::from(quotient))?;
+                                main_gate.assert_equal_to_constant(ctx, &r, Fp::from(remainder))?;
+                            }
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_range_comparison() {
+        let k: u32 = (TestCircuitComparison::LIMB_BIT_LEN + 1) as u32;
+        let run = |comparisons: Vec<Comparison>| {
+            let circuit = TestCircuitComparison { comparisons };
+            let prover = match MockProver::run(k, &circuit, vec![vec![]]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            prover.verify()
+        };
+
+        let comparisons = vec![
+            Comparison::IsLessThan(3, 5, 1),
+            Comparison::IsLessThan(5, 3, 0),
+            Comparison::IsLessThan(5, 5, 0),
+            Comparison::IsLessThan(0, 2047, 1),
+            Comparison::IsLessThan(2047, 0, 0),
+            Comparison::IsLessThanConstant(3, 5, 1),
+            Comparison::IsLessThanConstant(5, 5, 0),
+            Comparison::IsLessThanConstant(2047, 0, 0),
+            Comparison::AssertLessThan(3, 5),
+            Comparison::AssertLessThan(2046, 2047),
+            Comparison::AssertInRange(10, 10, 11),
+            Comparison::AssertInRange(2046, 0, 2047),
+            Comparison::DivRem(2047, 10, 204, 7),
+            Comparison::DivRem(5, 7, 0, 5),
+            Comparison::DivRem(2047, 2048, 0, 2047),
+        ];
+        assert_eq!(run(comparisons), Ok(()));
+
+        assert!(run(vec![Comparison::AssertLessThan(5, 5)]).is_err());
+        assert!(run(vec![Comparison::AssertLessThan(6, 5)]).is_err());
+        // `b - a - 1` is in range but `a` is not
+        assert!(run(vec![Comparison::AssertLessThanNegated(1, 5)]).is_err());
+        assert!(run(vec![Comparison::AssertInRange(11, 10, 11)]).is_err());
+        assert!(run(vec![Comparison::AssertInRange(9, 10, 11)]).is_err());
+    }
 }
@@ -0,0 +1,356 @@
+use crate::fq6::{frobenius_coeff, AssignedFq6, Fq6Chip};
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::bn256::{Fq, Fq12, Fq2};
+use crate::halo2::halo2curves::ff::{Field, PrimeField};
+use crate::halo2::plonk::Error;
+use crate::integer::fq2::{AssignedFq2, Fq2Chip};
+use crate::integer::AssignedInteger;
+use crate::maingate::RegionCtx;
+
+/// Assigned element of BN254 $F_{q^{12}} = F_{q^6}[w] / (w^2 - v)$ as
+/// $c_0 + c_1 * w$
+#[derive(Debug, Clone)]
+pub struct AssignedFq12<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    pub(crate) c0: AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub(crate) c1: AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedFq12` given its coefficients
+    pub fn new(
+        c0: AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns the witness value of the element
+    pub fn value(&self) -> Value<Fq12> {
+        self.c0
+            .value()
+            .zip(self.c1.value())
+            .map(|(c0, c1)| Fq12 { c0, c1 })
+    }
+}
+
+/// Constaints arithmetic of BN254 $F_{q^{12}}$ over `Fq6Chip`
+#[derive(Clone, Debug)]
+pub struct Fq12Chip<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    fq6_chip: Fq6Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Fq12Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns `Fq12Chip` given the `Fq6Chip`
+    pub fn new(fq6_chip: Fq6Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self { fq6_chip }
+    }
+
+    /// Returns `Fq6Chip` of the tower
+    pub fn fq6_chip(&self) -> &Fq6Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fq6_chip
+    }
+
+    /// Returns `Fq2Chip` of the tower
+    pub fn fq2_chip(&self) -> &Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.fq6_chip.fq2_chip()
+    }
+
+    /// Assigns an element with range check of coefficients
+    pub fn assign_element(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: Value<Fq12>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fq6_chip.assign_element(ctx, e.map(|e| e.c0))?;
+        let c1 = self.fq6_chip.assign_element(ctx, e.map(|e| e.c1))?;
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: Fq12,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fq6_chip.assign_constant(ctx, e.c0)?;
+        let c1 = self.fq6_chip.assign_constant(ctx, e.c1)?;
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Multiplies 2 elements with Karatsuba method
+    /// $c_0 = a_0 b_0 + v a_1 b_1$
+    /// $c_1 = (a_0 + a_1)(b_0 + b_1) - a_0 b_0 - a_1 b_1$
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq6_chip;
+        let t0 = &ch.mul(ctx, &a.c0, &b.c0)?;
+        let t1 = &ch.mul(ctx, &a.c1, &b.c1)?;
+
+        let v_t1 = &ch.mul_by_non_residue(ctx, t1)?;
+        let c0 = ch.add(ctx, t0, v_t1)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let b_sum = &ch.add(ctx, &b.c0, &b.c1)?;
+        let t = &ch.mul(ctx, a_sum, b_sum)?;
+        let c1 = ch.sub_sub(ctx, t, t0, t1)?;
+
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Squares an element with complex squaring
+    /// $c_0 = (a_0 + a_1)(a_0 + v a_1) - a_0 a_1 - v a_0 a_1$
+    /// $c_1 = 2 a_0 a_1$
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq6_chip;
+        let t = &ch.mul(ctx, &a.c0, &a.c1)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let v_a1 = &ch.mul_by_non_residue(ctx, &a.c1)?;
+        let a_v_sum = &ch.add(ctx, &a.c0, v_a1)?;
+        let u = &ch.mul(ctx, a_sum, a_v_sum)?;
+        let v_t = &ch.mul_by_non_residue(ctx, t)?;
+        let c0 = ch.sub_sub(ctx, u, t, v_t)?;
+        let c1 = ch.mul2(ctx, t)?;
+
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Multiplies an element by a sparse element $a + b * w + c * v * w$
+    /// which is the form of line functions in the Miller loop
+    pub fn mul_by_034(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        a: &AssignedInteger<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq6_chip;
+
+        // (f_0 + f_1 w)(a + m w) = (f_0 a + f_1 m v) + (f_1 a + f_0 m) w
+        // where m = b + c v
+        let f0_a = &ch.mul_by_base(ctx, &f.c0, a)?;
+        let f1_m = &ch.mul_by_01(ctx, &f.c1, b, c)?;
+        let f1_m_v = &ch.mul_by_non_residue(ctx, f1_m)?;
+        let c0 = ch.add(ctx, f0_a, f1_m_v)?;
+
+        let f1_a = &ch.mul_by_base(ctx, &f.c1, a)?;
+        let f0_m = &ch.mul_by_01(ctx, &f.c0, b, c)?;
+        let c1 = ch.add(ctx, f1_a, f0_m)?;
+
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Returns the conjugate $c_0 - c_1 * w$ which is $a^{p^6}$
+    pub fn conjugate(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c1 = self.fq6_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFq12::new(a.c0.clone(), c1))
+    }
+
+    /// Applies Frobenius endomorphism $a^{p^{power}}$ where
+    /// $w^{p^{power}} = \xi^{(p^{power} - 1) / 6} * w$
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq6_chip;
+        let c0 = ch.frobenius_map(ctx, &a.c0, power)?;
+        let c1 = &ch.frobenius_map(ctx, &a.c1, power)?;
+        let c1 = ch.mul_by_fq2_constant(ctx, c1, frobenius_coeff(power, 6))?;
+        Ok(AssignedFq12::new(c0, c1))
+    }
+
+    /// Inverts an element. Assumes the input is not zero otherwise no valid
+    /// witness exists
+    pub fn invert_incomplete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let inv = a.value().map(|a| a.invert().unwrap_or(Fq12::ZERO));
+        let inv = self.assign_element(ctx, inv)?;
+
+        // a * a^-1 = 1
+        let one = &self.mul(ctx, a, &inv)?;
+        self.assert_one(ctx, one)?;
+
+        Ok(inv)
+    }
+
+    /// Constraints two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.fq6_chip.assert_equal(ctx, &a.c0, &b.c0)?;
+        self.fq6_chip.assert_equal(ctx, &a.c1, &b.c1)
+    }
+
+    /// Constraints an element to be one
+    pub fn assert_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let one = &self.assign_constant(ctx, Fq12::ONE)?;
+        self.assert_equal(ctx, a, one)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fq12Chip;
+    use crate::curves::bn256::{Fq, Fq12, Fq2, Fq6, Fr as BnScalar};
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::fq6::tests::TestCircuitConfig;
+    use crate::fq6::Fq6Chip;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::integer::rns::Integer;
+    use crate::integer::{IntegerInstructions, Range};
+    use crate::maingate::{mock_prover_verify, modulus, RegionCtx};
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitFq12<N: PrimeField> {
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestCircuitFq12<N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let fq12_chip = Fq12Chip::new(Fq6Chip::new(config.fq2_chip()));
+            let fq2_chip = fq12_chip.fq2_chip();
+            let integer_chip = fq2_chip.integer_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = Fq12::random(OsRng);
+                    let b = Fq12::random(OsRng);
+
+                    let a_assigned = &fq12_chip.assign_element(ctx, Value::known(a))?;
+                    let b_assigned = &fq12_chip.assign_element(ctx, Value::known(b))?;
+
+                    let c_0 = &fq12_chip.assign_element(ctx, Value::known(a * b))?;
+                    let c_1 = &fq12_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fq12_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq12_chip.assign_element(ctx, Value::known(a.square()))?;
+                    let c_1 = &fq12_chip.square(ctx, a_assigned)?;
+                    fq12_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq12_chip.assign_element(ctx, Value::known(a.invert().unwrap()))?;
+                    let c_1 = &fq12_chip.invert_incomplete(ctx, a_assigned)?;
+                    fq12_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // Frobenius is exponentiation to the characteristic and
+                    // conjugation is the Frobenius map of power 6
+                    let p = modulus::<Fq>().to_u64_digits();
+                    let mut expected = a;
+                    for power in 1..4 {
+                        expected = expected.pow_vartime(&p);
+                        let c_0 = &fq12_chip.assign_element(ctx, Value::known(expected))?;
+                        let c_1 = &fq12_chip.frobenius_map(ctx, a_assigned, power)?;
+                        fq12_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+                    let conjugate = Fq12 {
+                        c0: a.c0,
+                        c1: -a.c1,
+                    };
+                    let c_0 = &fq12_chip.assign_element(ctx, Value::known(conjugate))?;
+                    let c_1 = &fq12_chip.conjugate(ctx, a_assigned)?;
+                    fq12_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // sparse multiplication with a line
+                    let (l0, l1, l2) = (Fq::random(OsRng), Fq2::random(OsRng), Fq2::random(OsRng));
+                    let line = Fq12 {
+                        c0: Fq6 {
+                            c0: Fq2 {
+                                c0: l0,
+                                c1: Fq::ZERO,
+                            },
+                            c1: Fq2::ZERO,
+                            c2: Fq2::ZERO,
+                        },
+                        c1: Fq6 {
+                            c0: l1,
+                            c1: l2,
+                            c2: Fq2::ZERO,
+                        },
+                    };
+                    let l0 = &integer_chip.assign_integer(
+                        ctx,
+                        Value::known(Integer::from_fe(l0, integer_chip.rns())).into(),
+                        Range::Remainder,
+                    )?;
+                    let l1 = &fq2_chip.assign_element(ctx, Value::known(l1))?;
+                    let l2 = &fq2_chip.assign_element(ctx, Value::known(l2))?;
+                    let c_0 = &fq12_chip.assign_element(ctx, Value::known(a * line))?;
+                    let c_1 = &fq12_chip.mul_by_034(ctx, a_assigned, l0, l1, l2)?;
+                    fq12_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fq12_chip() {
+        let circuit = TestCircuitFq12::<BnScalar> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        let circuit = TestCircuitFq12::<PastaFp> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+}
@@ -0,0 +1,572 @@
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::bn256::{Fq, Fq2, Fq6};
+use crate::halo2::halo2curves::ff::{Field, PrimeField};
+use crate::halo2::plonk::Error;
+use crate::integer::fq2::{AssignedFq2, Fq2Chip};
+use crate::integer::AssignedInteger;
+use crate::maingate::{modulus, RegionCtx};
+
+/// Cubic non residue $\xi = 9 + u$ that defines
+/// $F_{q^6} = F_{q^2}[v] / (v^3 - \xi)$
+pub(crate) fn non_residue() -> Fq2 {
+    Fq2 {
+        c0: Fq::from(9),
+        c1: Fq::ONE,
+    }
+}
+
+/// Returns $\xi^{(p^{power} - 1) / divisor}$ which scales coefficients of
+/// the tower under Frobenius map
+pub(crate) fn frobenius_coeff(power: usize, divisor: u64) -> Fq2 {
+    let exponent = (modulus::<Fq>().pow(power as u32) - 1u64) / divisor;
+    non_residue().pow_vartime(exponent.to_u64_digits())
+}
+
+/// Assigned element of BN254 $F_{q^6}$ as $c_0 + c_1 * v + c_2 * v^2$
+#[derive(Debug, Clone)]
+pub struct AssignedFq6<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    pub(crate) c0: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub(crate) c1: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub(crate) c2: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedFq6` given its coefficients
+    pub fn new(
+        c0: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c2: AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns the witness value of the element
+    pub fn value(&self) -> Value<Fq6> {
+        self.c0
+            .value()
+            .zip(self.c1.value())
+            .zip(self.c2.value())
+            .map(|((c0, c1), c2)| Fq6 { c0, c1, c2 })
+    }
+}
+
+/// Constaints arithmetic of BN254 $F_{q^6}$ over `Fq2Chip`
+#[derive(Clone, Debug)]
+pub struct Fq6Chip<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    fq2_chip: Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Fq6Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns `Fq6Chip` given the `Fq2Chip`
+    pub fn new(fq2_chip: Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self { fq2_chip }
+    }
+
+    /// Returns `Fq2Chip` of the tower
+    pub fn fq2_chip(&self) -> &Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fq2_chip
+    }
+
+    /// Assigns an element with range check of coefficients
+    pub fn assign_element(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: Value<Fq6>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fq2_chip.assign_element(ctx, e.map(|e| e.c0))?;
+        let c1 = self.fq2_chip.assign_element(ctx, e.map(|e| e.c1))?;
+        let c2 = self.fq2_chip.assign_element(ctx, e.map(|e| e.c2))?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        e: Fq6,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fq2_chip.assign_constant(ctx, e.c0)?;
+        let c1 = self.fq2_chip.assign_constant(ctx, e.c1)?;
+        let c2 = self.fq2_chip.assign_constant(ctx, e.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Adds 2 elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.add(ctx, &a.c0, &b.c0)?;
+        let c1 = ch.add(ctx, &a.c1, &b.c1)?;
+        let c2 = ch.add(ctx, &a.c2, &b.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Substracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.sub(ctx, &a.c0, &b.c0)?;
+        let c1 = ch.sub(ctx, &a.c1, &b.c1)?;
+        let c2 = ch.sub(ctx, &a.c2, &b.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Substracts `b_0` and `b_1` from `a`
+    pub fn sub_sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b_0: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b_1: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.sub_sub(ctx, &a.c0, &b_0.c0, &b_1.c0)?;
+        let c1 = ch.sub_sub(ctx, &a.c1, &b_0.c1, &b_1.c1)?;
+        let c2 = ch.sub_sub(ctx, &a.c2, &b_0.c2, &b_1.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by -1
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.neg(ctx, &a.c0)?;
+        let c1 = ch.neg(ctx, &a.c1)?;
+        let c2 = ch.neg(ctx, &a.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by 2
+    pub fn mul2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.mul2(ctx, &a.c0)?;
+        let c1 = ch.mul2(ctx, &a.c1)?;
+        let c2 = ch.mul2(ctx, &a.c2)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an $F_{q^2}$ element by the non residue $\xi$
+    fn mul_fq2_by_non_residue(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.fq2_chip.mul_constant(ctx, a, non_residue())
+    }
+
+    /// Multiplies an element by $v$ where $v^3 = \xi$
+    pub fn mul_by_non_residue(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.mul_fq2_by_non_residue(ctx, &a.c2)?;
+        Ok(AssignedFq6::new(c0, a.c0.clone(), a.c1.clone()))
+    }
+
+    /// Multiplies 2 elements with Karatsuba method
+    /// $c_0 = a_0 b_0 + \xi ((a_1 + a_2)(b_1 + b_2) - a_1 b_1 - a_2 b_2)$
+    /// $c_1 = (a_0 + a_1)(b_0 + b_1) - a_0 b_0 - a_1 b_1 + \xi a_2 b_2$
+    /// $c_2 = (a_0 + a_2)(b_0 + b_2) - a_0 b_0 - a_2 b_2 + a_1 b_1$
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let t0 = &ch.mul(ctx, &a.c0, &b.c0)?;
+        let t1 = &ch.mul(ctx, &a.c1, &b.c1)?;
+        let t2 = &ch.mul(ctx, &a.c2, &b.c2)?;
+
+        let a_sum = &ch.add(ctx, &a.c1, &a.c2)?;
+        let b_sum = &ch.add(ctx, &b.c1, &b.c2)?;
+        let t = &ch.mul(ctx, a_sum, b_sum)?;
+        let t = &ch.sub_sub(ctx, t, t1, t2)?;
+        let t = &self.mul_fq2_by_non_residue(ctx, t)?;
+        let c0 = ch.add(ctx, t0, t)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let b_sum = &ch.add(ctx, &b.c0, &b.c1)?;
+        let t = &ch.mul(ctx, a_sum, b_sum)?;
+        let t = &ch.sub_sub(ctx, t, t0, t1)?;
+        let xi_t2 = &self.mul_fq2_by_non_residue(ctx, t2)?;
+        let c1 = ch.add(ctx, t, xi_t2)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c2)?;
+        let b_sum = &ch.add(ctx, &b.c0, &b.c2)?;
+        let t = &ch.mul(ctx, a_sum, b_sum)?;
+        let t = &ch.sub_sub(ctx, t, t0, t2)?;
+        let c2 = ch.add(ctx, t, t1)?;
+
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Squares an element with the same method as in `mul`
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let t0 = &ch.square(ctx, &a.c0)?;
+        let t1 = &ch.square(ctx, &a.c1)?;
+        let t2 = &ch.square(ctx, &a.c2)?;
+
+        let a_sum = &ch.add(ctx, &a.c1, &a.c2)?;
+        let t = &ch.square(ctx, a_sum)?;
+        let t = &ch.sub_sub(ctx, t, t1, t2)?;
+        let t = &self.mul_fq2_by_non_residue(ctx, t)?;
+        let c0 = ch.add(ctx, t0, t)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c1)?;
+        let t = &ch.square(ctx, a_sum)?;
+        let t = &ch.sub_sub(ctx, t, t0, t1)?;
+        let xi_t2 = &self.mul_fq2_by_non_residue(ctx, t2)?;
+        let c1 = ch.add(ctx, t, xi_t2)?;
+
+        let a_sum = &ch.add(ctx, &a.c0, &a.c2)?;
+        let t = &ch.square(ctx, a_sum)?;
+        let t = &ch.sub_sub(ctx, t, t0, t2)?;
+        let c2 = ch.add(ctx, t, t1)?;
+
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by a sparse element $b_0 + b_1 * v$
+    pub fn mul_by_01(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b0: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b1: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+
+        // c_0 = a_0 b_0 + \xi a_2 b_1
+        let t = &ch.mul(ctx, &a.c0, b0)?;
+        let u = &ch.mul(ctx, &a.c2, b1)?;
+        let u = &self.mul_fq2_by_non_residue(ctx, u)?;
+        let c0 = ch.add(ctx, t, u)?;
+
+        // c_1 = a_0 b_1 + a_1 b_0
+        let t = &ch.mul(ctx, &a.c0, b1)?;
+        let u = &ch.mul(ctx, &a.c1, b0)?;
+        let c1 = ch.add(ctx, t, u)?;
+
+        // c_2 = a_1 b_1 + a_2 b_0
+        let t = &ch.mul(ctx, &a.c1, b1)?;
+        let u = &ch.mul(ctx, &a.c2, b0)?;
+        let c2 = ch.add(ctx, t, u)?;
+
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by a base field element
+    pub fn mul_by_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.mul_by_base(ctx, &a.c0, b)?;
+        let c1 = ch.mul_by_base(ctx, &a.c1, b)?;
+        let c2 = ch.mul_by_base(ctx, &a.c2, b)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Multiplies each coefficient by a constant of $F_{q^2}$
+    pub fn mul_by_fq2_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: Fq2,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let c0 = ch.mul_constant(ctx, &a.c0, b)?;
+        let c1 = ch.mul_constant(ctx, &a.c1, b)?;
+        let c2 = ch.mul_constant(ctx, &a.c2, b)?;
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Applies Frobenius endomorphism $a^{p^{power}}$ where
+    /// $v^{p^{power}} = \xi^{(p^{power} - 1) / 3} * v$
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq2_chip;
+        let gamma = frobenius_coeff(power, 3);
+
+        let c0 = ch.frobenius_map(ctx, &a.c0, power)?;
+        let c1 = &ch.frobenius_map(ctx, &a.c1, power)?;
+        let c1 = ch.mul_constant(ctx, c1, gamma)?;
+        let c2 = &ch.frobenius_map(ctx, &a.c2, power)?;
+        let c2 = ch.mul_constant(ctx, c2, gamma.square())?;
+
+        Ok(AssignedFq6::new(c0, c1, c2))
+    }
+
+    /// Constraints two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFq6<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let ch = &self.fq2_chip;
+        ch.assert_equal(ctx, &a.c0, &b.c0)?;
+        ch.assert_equal(ctx, &a.c1, &b.c1)?;
+        ch.assert_equal(ctx, &a.c2, &b.c2)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{non_residue, Fq6Chip};
+    use crate::curves::bn256::{Fq, Fq2, Fq6, Fr as BnScalar};
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::integer::fq2::Fq2Chip;
+    use crate::integer::rns::{Integer, Rns};
+    use crate::integer::{IntegerChip, IntegerConfig, IntegerInstructions, Range};
+    use crate::maingate::{
+        mock_prover_verify, modulus, MainGate, MainGateConfig, RangeChip, RangeConfig,
+        RangeInstructions, RegionCtx,
+    };
+    use ecc::EccConfig;
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+    use std::rc::Rc;
+
+    pub(crate) const NUMBER_OF_LIMBS: usize = 4;
+    pub(crate) const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    pub(crate) struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestCircuitConfig {
+        pub(crate) fn new<N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let rns_base = Rns::<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let rns_scalar = Rns::<BnScalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        pub(crate) fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        pub(crate) fn fq2_chip<N: PrimeField>(
+            &self,
+        ) -> Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            let integer_chip = IntegerChip::new(
+                IntegerConfig::new(self.range_config.clone(), self.main_gate_config.clone()),
+                Rc::new(Rns::construct()),
+            );
+            Fq2Chip::new(integer_chip)
+        }
+
+        pub(crate) fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitFq6<N: PrimeField> {
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestCircuitFq6<N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let fq6_chip = Fq6Chip::new(config.fq2_chip());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = Fq6::random(OsRng);
+                    let b = Fq6::random(OsRng);
+                    let k = Fq::random(OsRng);
+
+                    let a_assigned = &fq6_chip.assign_element(ctx, Value::known(a))?;
+                    let b_assigned = &fq6_chip.assign_element(ctx, Value::known(b))?;
+
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a * b))?;
+                    let c_1 = &fq6_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a.square()))?;
+                    let c_1 = &fq6_chip.square(ctx, a_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a + b))?;
+                    let c_1 = &fq6_chip.add(ctx, a_assigned, b_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a - b))?;
+                    let c_1 = &fq6_chip.sub(ctx, a_assigned, b_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // multiplication by v
+                    let v = Fq6 {
+                        c0: Fq2::ZERO,
+                        c1: Fq2::ONE,
+                        c2: Fq2::ZERO,
+                    };
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a * v))?;
+                    let c_1 = &fq6_chip.mul_by_non_residue(ctx, a_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // sparse multiplication
+                    let sparse = Fq6 {
+                        c0: b.c0,
+                        c1: b.c1,
+                        c2: Fq2::ZERO,
+                    };
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(a * sparse))?;
+                    let c_1 =
+                        &fq6_chip.mul_by_01(ctx, a_assigned, &b_assigned.c0, &b_assigned.c1)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // multiplication by base field element
+                    let k_assigned = &fq6_chip.fq2_chip().integer_chip().assign_integer(
+                        ctx,
+                        Value::known(Integer::from_fe(
+                            k,
+                            fq6_chip.fq2_chip().integer_chip().rns(),
+                        ))
+                        .into(),
+                        Range::Remainder,
+                    )?;
+                    let scaled = Fq6 {
+                        c0: a.c0
+                            * Fq2 {
+                                c0: k,
+                                c1: Fq::ZERO,
+                            },
+                        c1: a.c1
+                            * Fq2 {
+                                c0: k,
+                                c1: Fq::ZERO,
+                            },
+                        c2: a.c2
+                            * Fq2 {
+                                c0: k,
+                                c1: Fq::ZERO,
+                            },
+                    };
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(scaled))?;
+                    let c_1 = &fq6_chip.mul_by_base(ctx, a_assigned, k_assigned)?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    let scaled = Fq6 {
+                        c0: a.c0 * non_residue(),
+                        c1: a.c1 * non_residue(),
+                        c2: a.c2 * non_residue(),
+                    };
+                    let c_0 = &fq6_chip.assign_element(ctx, Value::known(scaled))?;
+                    let c_1 = &fq6_chip.mul_by_fq2_constant(ctx, a_assigned, non_residue())?;
+                    fq6_chip.assert_equal(ctx, c_0, c_1)?;
+
+                    // Frobenius is exponentiation to the characteristic
+                    let p = modulus::<Fq>().to_u64_digits();
+                    let mut expected = a;
+                    for power in 1..4 {
+                        expected = expected.pow_vartime(&p);
+                        let c_0 = &fq6_chip.assign_element(ctx, Value::known(expected))?;
+                        let c_1 = &fq6_chip.frobenius_map(ctx, a_assigned, power)?;
+                        fq6_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fq6_chip() {
+        let circuit = TestCircuitFq6::<BnScalar> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        let circuit = TestCircuitFq6::<PastaFp> {
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+}
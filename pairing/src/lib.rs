@@ -0,0 +1,10 @@
+pub mod fq12;
+pub mod fq6;
+pub mod pairing;
+
+pub(crate) use ecc::halo2;
+pub(crate) use ecc::integer;
+pub(crate) use ecc::maingate;
+
+#[cfg(test)]
+use halo2::halo2curves as curves;
@@ -0,0 +1,626 @@
+use crate::fq12::{AssignedFq12, Fq12Chip};
+use crate::fq6::{frobenius_coeff, Fq6Chip};
+use crate::halo2::halo2curves::bn256::{Fq, Fq12, Fq2, G1Affine, G2Affine};
+use crate::halo2::halo2curves::ff::Field;
+use crate::halo2::plonk::Error;
+use crate::integer::fq2::{AssignedFq2, Fq2Chip};
+use crate::integer::{AssignedInteger, IntegerInstructions};
+use crate::maingate::RegionCtx;
+use ecc::{AssignedG2Point, AssignedPoint, EccConfig, G2Chip, GeneralEccChip};
+
+/// BN254 curve parameter $x$ where $p = 36x^4 + 36x^3 + 24x^2 + 6x + 1$
+const BN_X: u64 = 4965661367192848881;
+
+/// Returns non adjacent form of the optimal ate loop count $6x + 2$ starting
+/// from the least significant digit
+fn six_x_plus_2_naf() -> Vec<i8> {
+    let mut n = 6 * BN_X as u128 + 2;
+    let mut naf = vec![];
+    while n > 0 {
+        let digit = if n & 1 == 1 { 2 - (n % 4) as i8 } else { 0 };
+        match digit {
+            1 => n -= 1,
+            -1 => n += 1,
+            _ => {}
+        }
+        naf.push(digit);
+        n >>= 1;
+    }
+    naf
+}
+
+/// G1 point prepared for line evaluations as $(-x_P, y_P)$
+type PreparedG1<N, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> = (
+    AssignedInteger<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    AssignedInteger<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+);
+
+/// Constaints BN254 optimal ate pairing.
+///
+/// G2 points are on the D-type sextic twist
+/// $y^2 = x^3 + 3 / \xi$ and are untwisted into $E(F_{q^{12}})$ with
+/// $(x, y) \to (x * w^2, y * w^3)$. Points are expected to be in the prime
+/// order subgroups and not to be the point at infinity.
+#[derive(Clone, Debug)]
+pub struct PairingChip<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    g1_chip: GeneralEccChip<G1Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    g2_chip: G2Chip<G2Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    fq12_chip: Fq12Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    PairingChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Return `PairingChip` from `EccConfig`
+    pub fn new(config: EccConfig) -> Self {
+        let g1_chip = GeneralEccChip::new(config.clone());
+        let g2_chip = G2Chip::new(config);
+        let fq12_chip = Fq12Chip::new(Fq6Chip::new(g2_chip.fq2_chip().clone()));
+        Self {
+            g1_chip,
+            g2_chip,
+            fq12_chip,
+        }
+    }
+
+    /// Returns `GeneralEccChip` of G1 that can be used to assign G1 points
+    pub fn g1_chip(&self) -> &GeneralEccChip<G1Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.g1_chip
+    }
+
+    /// Returns `G2Chip` of G2 that can be used to assign G2 points
+    pub fn g2_chip(&self) -> &G2Chip<G2Affine, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.g2_chip
+    }
+
+    /// Returns `Fq12Chip` of the target group
+    pub fn fq12_chip(&self) -> &Fq12Chip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fq12_chip
+    }
+
+    fn fq2_chip(&self) -> &Fq2Chip<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.g2_chip.fq2_chip()
+    }
+
+    /// Returns slope of the tangent line at $T$
+    fn tangent_slope(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        // lambda = 3 * t_x^2 / 2 * t_y
+        let x_square = &ch.square(ctx, t.x())?;
+        let numerator = &ch.mul3(ctx, x_square)?;
+        let denominator = &ch.mul2(ctx, t.y())?;
+        ch.div_incomplete(ctx, numerator, denominator)
+    }
+
+    /// Returns slope of the line through $T$ and $Q$
+    fn chord_slope(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        // lambda = q_y - t_y / q_x - t_x
+        let numerator = &ch.sub(ctx, q.y(), t.y())?;
+        let denominator = &ch.sub(ctx, q.x(), t.x())?;
+        ch.div_incomplete(ctx, numerator, denominator)
+    }
+
+    /// Returns constant term $\lambda * t_x - t_y$ of the line with slope
+    /// $\lambda$ through $T$
+    fn line_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        lambda: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        let t_lambda = &ch.mul(ctx, lambda, t.x())?;
+        ch.sub(ctx, t_lambda, t.y())
+    }
+
+    /// Returns the third intersection of the line with the curve negated
+    /// given the slope $\lambda$ and the constant term $c$ of the line through
+    /// $T$ and a point with $x$ coordinate `x`
+    fn next_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        x: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        lambda: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        // r_x = lambda^2 - t_x - x
+        let lambda_square = &ch.square(ctx, lambda)?;
+        let r_x = ch.sub_sub(ctx, lambda_square, t.x(), x)?;
+        // r_y = lambda * (t_x - r_x) - t_y = c - lambda * r_x
+        let t = &ch.mul(ctx, lambda, &r_x)?;
+        let r_y = ch.sub(ctx, c, t)?;
+        Ok(AssignedG2Point::new(r_x, r_y))
+    }
+
+    /// Multiplies `f` by the line with slope $\lambda$ and constant term $c$
+    /// evaluated at $P$ which is
+    /// $y_P - \lambda * x_P * w + c * v * w$
+    fn mul_by_line(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &PreparedG1<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        lambda: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedFq2<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (neg_x, y) = p;
+        let b = &self.fq2_chip().mul_by_base(ctx, lambda, neg_x)?;
+        self.fq12_chip.mul_by_034(ctx, f, y, b, c)
+    }
+
+    /// Doubles $T$ and updates `f` with the tangent line
+    fn double_step(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &PreparedG1<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let lambda = &self.tangent_slope(ctx, t)?;
+        let c = &self.line_constant(ctx, t, lambda)?;
+        let f = self.mul_by_line(ctx, f, p, lambda, c)?;
+        let t = self.next_point(ctx, t, t.x(), lambda, c)?;
+        Ok((f, t))
+    }
+
+    /// Adds $Q$ to $T$ and updates `f` with the line through them
+    fn add_step(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &PreparedG1<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let lambda = &self.chord_slope(ctx, t, q)?;
+        let c = &self.line_constant(ctx, t, lambda)?;
+        let f = self.mul_by_line(ctx, f, p, lambda, c)?;
+        let t = self.next_point(ctx, t, q.x(), lambda, c)?;
+        Ok((f, t))
+    }
+
+    /// Applies Frobenius endomorphism of power `power` to a point of the
+    /// twist as $\psi^{-1} \circ \pi_p \circ \psi$, that is
+    /// $(\bar{x} * \xi^{(p^k - 1) / 3}, \bar{y} * \xi^{(p^k - 1) / 2})$
+    fn twisted_frobenius(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        q: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = self.fq2_chip();
+        let x = &ch.frobenius_map(ctx, q.x(), power)?;
+        let x = ch.mul_constant(ctx, x, frobenius_coeff(power, 3))?;
+        let y = &ch.frobenius_map(ctx, q.y(), power)?;
+        let y = ch.mul_constant(ctx, y, frobenius_coeff(power, 2))?;
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    /// Computes product of Miller loops of optimal ate pairing for each pair
+    /// sharing squarings of the accumulator. Result is meaningful only after
+    /// `final_exponentiation`
+    pub fn miller_loop(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pairs: &[(
+            &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        assert!(!pairs.is_empty());
+        let integer_chip = self.fq2_chip().integer_chip();
+
+        let ps = pairs
+            .iter()
+            .map(|(p, _)| Ok((integer_chip.neg(ctx, p.x())?, p.y().clone())))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let qs = pairs.iter().map(|(_, q)| (*q).clone()).collect::<Vec<_>>();
+        let neg_qs = qs
+            .iter()
+            .map(|q| self.g2_chip.neg(ctx, q))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut f = self.fq12_chip.assign_constant(ctx, Fq12::ONE)?;
+        let mut ts = qs.clone();
+
+        // Most significant digit is one and it is consumed by setting T = Q
+        for (i, digit) in six_x_plus_2_naf().into_iter().rev().skip(1).enumerate() {
+            if i != 0 {
+                f = self.fq12_chip.square(ctx, &f)?;
+            }
+            for (j, p) in ps.iter().enumerate() {
+                let (f_next, t_next) = self.double_step(ctx, &f, &ts[j], p)?;
+                (f, ts[j]) = (f_next, t_next);
+                let q = match digit {
+                    1 => &qs[j],
+                    -1 => &neg_qs[j],
+                    _ => continue,
+                };
+                let (f_next, t_next) = self.add_step(ctx, &f, &ts[j], q, p)?;
+                (f, ts[j]) = (f_next, t_next);
+            }
+        }
+
+        // Final additions of Q_1 = \pi(Q) and -Q_2 = -\pi^2(Q)
+        for (j, p) in ps.iter().enumerate() {
+            let q_1 = &self.twisted_frobenius(ctx, &qs[j], 1)?;
+            let (f_next, t_next) = self.add_step(ctx, &f, &ts[j], q_1, p)?;
+            (f, ts[j]) = (f_next, t_next);
+
+            let q_2 = &self.twisted_frobenius(ctx, &qs[j], 2)?;
+            let q_2 = &self.g2_chip.neg(ctx, q_2)?;
+            // Resulting point is not needed, only the line is evaluated
+            let lambda = &self.chord_slope(ctx, &ts[j], q_2)?;
+            let c = &self.line_constant(ctx, &ts[j], lambda)?;
+            f = self.mul_by_line(ctx, &f, p, lambda, c)?;
+        }
+
+        Ok(f)
+    }
+
+    /// Given $a$ computes $a^x$ with square and multiply
+    fn exp_by_x(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq12_chip;
+        let top_bit = 63 - BN_X.leading_zeros() as usize;
+        let mut acc = a.clone();
+        for i in (0..top_bit).rev() {
+            acc = ch.square(ctx, &acc)?;
+            if (BN_X >> i) & 1 == 1 {
+                acc = ch.mul(ctx, &acc, a)?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Computes $f^{(p^{12} - 1) / r}$
+    ///
+    /// Easy part $(p^6 - 1)(p^2 + 1)$ is computed with a conjugation, an
+    /// inversion and a Frobenius map. Hard part $(p^4 - p^2 + 1) / r$ follows
+    /// the addition chain of Scott et al. in "On the Final Exponentiation for
+    /// Calculating Pairings on Ordinary Elliptic Curves" where conjugation
+    /// stands for inversion in the cyclotomic subgroup.
+    pub fn final_exponentiation(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ch = &self.fq12_chip;
+
+        // r = f^{(p^6 - 1)(p^2 + 1)}
+        let f_inv = &ch.invert_incomplete(ctx, f)?;
+        let f_conjugate = &ch.conjugate(ctx, f)?;
+        let r = &ch.mul(ctx, f_conjugate, f_inv)?;
+        let r_p2 = &ch.frobenius_map(ctx, r, 2)?;
+        let r = &ch.mul(ctx, r_p2, r)?;
+
+        let r_p = &ch.frobenius_map(ctx, r, 1)?;
+        let r_p2 = &ch.frobenius_map(ctx, r, 2)?;
+        let r_p3 = &ch.frobenius_map(ctx, r_p2, 1)?;
+
+        let r_x = &self.exp_by_x(ctx, r)?;
+        let r_x2 = &self.exp_by_x(ctx, r_x)?;
+        let r_x3 = &self.exp_by_x(ctx, r_x2)?;
+
+        let r_x_p = &ch.frobenius_map(ctx, r_x, 1)?;
+        let r_x2_p = &ch.frobenius_map(ctx, r_x2, 1)?;
+        let r_x3_p = &ch.frobenius_map(ctx, r_x3, 1)?;
+        let r_x2_p2 = &ch.frobenius_map(ctx, r_x2, 2)?;
+
+        let y0 = &ch.mul(ctx, r_p, r_p2)?;
+        let y0 = &ch.mul(ctx, y0, r_p3)?;
+        let y1 = &ch.conjugate(ctx, r)?;
+        let y2 = r_x2_p2;
+        let y3 = &ch.conjugate(ctx, r_x_p)?;
+        let y4 = &ch.mul(ctx, r_x, r_x2_p)?;
+        let y4 = &ch.conjugate(ctx, y4)?;
+        let y5 = &ch.conjugate(ctx, r_x2)?;
+        let y6 = &ch.mul(ctx, r_x3, r_x3_p)?;
+        let y6 = &ch.conjugate(ctx, y6)?;
+
+        let t0 = &ch.square(ctx, y6)?;
+        let t0 = &ch.mul(ctx, t0, y4)?;
+        let t0 = &ch.mul(ctx, t0, y5)?;
+        let t1 = &ch.mul(ctx, y3, y5)?;
+        let t1 = &ch.mul(ctx, t1, t0)?;
+        let t0 = &ch.mul(ctx, t0, y2)?;
+        let t1 = &ch.square(ctx, t1)?;
+        let t1 = &ch.mul(ctx, t1, t0)?;
+        let t1 = &ch.square(ctx, t1)?;
+        let t0 = &ch.mul(ctx, t1, y1)?;
+        let t1 = &ch.mul(ctx, t1, y0)?;
+        let t0 = &ch.square(ctx, t0)?;
+        ch.mul(ctx, t0, t1)
+    }
+
+    /// Computes the pairing $e(P, Q)$
+    pub fn pairing(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        p: &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFq12<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let f = &self.miller_loop(ctx, &[(p, q)])?;
+        self.final_exponentiation(ctx, f)
+    }
+
+    /// Constraints $\prod_i e(P_i, Q_i) = 1$ with a single final
+    /// exponentiation. For example $e(A, B) = e(C, D)$ is checked as
+    /// $e(A, B) * e(-C, D) = 1$
+    pub fn multi_pairing_check(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pairs: &[(
+            &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            &AssignedG2Point<Fq2, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<(), Error> {
+        let f = &self.miller_loop(ctx, pairs)?;
+        let f = &self.final_exponentiation(ctx, f)?;
+        self.fq12_chip.assert_one(ctx, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{six_x_plus_2_naf, PairingChip, BN_X};
+    use crate::curves::bn256::{
+        pairing, Bn256, Fq, Fq12, Fr as BnScalar, G1Affine, G2Affine, G2Prepared, G1, G2,
+    };
+    use crate::curves::pairing::{MillerLoopResult, MultiMillerLoop};
+    use crate::fq6::tests::{TestCircuitConfig, BIT_LEN_LIMB, NUMBER_OF_LIMBS};
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::dev::MockProver;
+    use crate::halo2::halo2curves::ff::Field;
+    use crate::halo2::halo2curves::group::{Curve, Group};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        fe_to_big, mock_prover_verify, modulus, DimensionMeasurement, RegionCtx,
+    };
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_six_x_plus_2_naf() {
+        let naf = six_x_plus_2_naf();
+        let n = naf
+            .iter()
+            .rev()
+            .fold(0i128, |acc, digit| acc * 2 + *digit as i128);
+        assert_eq!(n, 6 * BN_X as i128 + 2);
+        assert_eq!(*naf.last().unwrap(), 1);
+        assert!(naf.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+    }
+
+    /// Returns $e(P, Q)$ as an element of $F_{q^{12}}$. `Gt` of halo2curves
+    /// does not expose the underlying field element, so the Miller loop
+    /// output of halo2curves is raised to $(p^{12} - 1) / r$ here, and the
+    /// same Miller loop output is asserted to give `pairing(P, Q)` under the
+    /// final exponentiation of halo2curves.
+    fn native_pairing(p: &G1Affine, q: &G2Affine) -> Fq12 {
+        let f = Bn256::multi_miller_loop(&[(p, &G2Prepared::from(*q))]);
+        assert_eq!(f.final_exponentiation(), pairing(p, q));
+        let exponent = (modulus::<Fq>().pow(12) - 1u32) / modulus::<BnScalar>();
+        f.pow_vartime(exponent.to_u64_digits())
+    }
+
+    #[test]
+    fn test_native_pairing() {
+        let p = (G1::generator() * BnScalar::random(OsRng)).to_affine();
+        let q = (G2::generator() * BnScalar::random(OsRng)).to_affine();
+        let a = BnScalar::random(OsRng);
+
+        let e = native_pairing(&p, &q);
+        assert_ne!(e, Fq12::ONE);
+        assert_eq!(
+            e.pow_vartime(modulus::<BnScalar>().to_u64_digits()),
+            Fq12::ONE
+        );
+
+        // e(a * P, Q) = e(P, a * Q) = e(P, Q)^a
+        let e_a = e.pow_vartime(fe_to_big(a).to_u64_digits());
+        let e_0 = native_pairing(&(p * a).to_affine(), &q);
+        let e_1 = native_pairing(&p, &(q * a).to_affine());
+        assert_eq!(e_0, e_a);
+        assert_eq!(e_1, e_a);
+        assert_eq!(
+            pairing(&(p * a).to_affine(), &q),
+            pairing(&p, &(q * a).to_affine())
+        );
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestPairing {
+        p: G1Affine,
+        q: G2Affine,
+        expected: Fq12,
+    }
+
+    impl Circuit<BnScalar> for TestPairing {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<BnScalar>,
+        ) -> Result<(), Error> {
+            let pairing_chip = PairingChip::<BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let p = &pairing_chip
+                        .g1_chip()
+                        .assign_point(ctx, Value::known(self.p))?;
+                    let q = &pairing_chip
+                        .g2_chip()
+                        .assign_point(ctx, Value::known(self.q))?;
+                    let e_0 = &pairing_chip.pairing(ctx, p, q)?;
+                    let e_1 = &pairing_chip
+                        .fq12_chip()
+                        .assign_element(ctx, Value::known(self.expected))?;
+                    pairing_chip.fq12_chip().assert_equal(ctx, e_0, e_1)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_pairing() {
+        let p = (G1::generator() * BnScalar::random(OsRng)).to_affine();
+        let q = (G2::generator() * BnScalar::random(OsRng)).to_affine();
+        let circuit = TestPairing {
+            p,
+            q,
+            expected: native_pairing(&p, &q),
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        // pairing of another pair is rejected
+        let circuit = TestPairing {
+            p,
+            q,
+            expected: native_pairing(&p, &(q + G2::generator()).to_affine()),
+        };
+        assert_rejected(&circuit);
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestMultiPairingCheck {
+        pairs: Vec<(G1Affine, G2Affine)>,
+    }
+
+    impl Circuit<BnScalar> for TestMultiPairingCheck {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<BnScalar>,
+        ) -> Result<(), Error> {
+            let pairing_chip = PairingChip::<BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let pairs = self
+                        .pairs
+                        .iter()
+                        .map(|(p, q)| {
+                            let p = pairing_chip.g1_chip().assign_point(ctx, Value::known(*p))?;
+                            let q = pairing_chip.g2_chip().assign_point(ctx, Value::known(*q))?;
+                            Ok((p, q))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let pairs = pairs.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+                    pairing_chip.multi_pairing_check(ctx, &pairs)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_multi_pairing_check() {
+        // e(a * G1, b * G2) * e(-(a * b) * G1, G2) = 1
+        let a = BnScalar::random(OsRng);
+        let b = BnScalar::random(OsRng);
+        let pairs = vec![
+            (
+                (G1::generator() * a).to_affine(),
+                (G2::generator() * b).to_affine(),
+            ),
+            (
+                (-G1::generator() * (a * b)).to_affine(),
+                G2::generator().to_affine(),
+            ),
+        ];
+        let circuit = TestMultiPairingCheck {
+            pairs: pairs.clone(),
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        // e(a * G1, b * G2) * e(-(a * b + 1) * G1, G2) != 1
+        let mut pairs = pairs;
+        pairs[1].0 = (-G1::generator() * (a * b + BnScalar::ONE)).to_affine();
+        let circuit = TestMultiPairingCheck { pairs };
+        assert_rejected(&circuit);
+    }
+
+    fn assert_rejected<C: Circuit<BnScalar>>(circuit: &C) {
+        let dimension = DimensionMeasurement::measure(circuit).unwrap();
+        let prover = MockProver::run(dimension.k(), circuit, vec![vec![]]).unwrap();
+        assert!(prover
+            .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+            .is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod schnorr;
+
+pub(crate) use ecc::halo2;
+pub(crate) use ecc::integer;
+pub(crate) use ecc::maingate;
+
+#[cfg(test)]
+use halo2::halo2curves as curves;
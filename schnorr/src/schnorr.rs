@@ -0,0 +1,638 @@
+use super::integer::{IntegerChip, IntegerConfig};
+use crate::halo2;
+use crate::integer;
+use crate::maingate;
+use ecc::maingate::RegionCtx;
+use ecc::{AssignedPoint, EccConfig, GeneralEccChip};
+use halo2::arithmetic::CurveAffine;
+use halo2::halo2curves::ff::{Field, PrimeField};
+use halo2::plonk::Error;
+use integer::rns::Common;
+use integer::{AssignedInteger, IntegerInstructions};
+use maingate::{big_to_fe, AssignedValue, MainGateConfig, MainGateInstructions, RangeConfig};
+use transcript::{assign_integer_challenge, HasherChip};
+
+/// Bit length of challenges derived with Poseidon in generic Schnorr
+/// verification
+pub const CHALLENGE_BIT_LEN: usize = 128;
+
+#[derive(Clone, Debug)]
+pub struct SchnorrConfig {
+    pub main_gate_config: MainGateConfig,
+    pub range_config: RangeConfig,
+}
+
+impl SchnorrConfig {
+    pub fn new(range_config: RangeConfig, main_gate_config: MainGateConfig) -> Self {
+        Self {
+            range_config,
+            main_gate_config,
+        }
+    }
+
+    pub fn ecc_chip_config(&self) -> EccConfig {
+        EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+    }
+
+    pub fn integer_chip_config(&self) -> IntegerConfig {
+        IntegerConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+    }
+}
+
+/// BIP-340 signature where `r` is the x coordinate of `R`
+pub struct AssignedBip340Sig<
+    B: PrimeField,
+    S: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub r: AssignedInteger<B, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub s: AssignedInteger<S, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+/// Schnorr signature with the full commitment point `R`
+pub struct AssignedSchnorrSig<
+    B: PrimeField,
+    S: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub r: AssignedPoint<B, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    pub s: AssignedInteger<S, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+pub struct AssignedPublicKey<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    pub point: AssignedPoint<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+pub struct SchnorrChip<
+    E: CurveAffine,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(GeneralEccChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>);
+
+impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    SchnorrChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub fn new(ecc_chip: GeneralEccChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        Self(ecc_chip)
+    }
+
+    pub fn scalar_field_chip(
+        &self,
+    ) -> &IntegerChip<E::ScalarExt, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.0.scalar_field_chip()
+    }
+
+    fn ecc_chip(&self) -> GeneralEccChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.0.clone()
+    }
+}
+
+impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    SchnorrChip<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Assigns the point with given x coordinate and even y coordinate. Also
+    /// checks that `x` is in the field
+    pub fn lift_x(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let main_gate = ecc_chip.main_gate();
+        let base_chip = ecc_chip.base_field_chip();
+
+        let point = x.integer().map(|x| {
+            let x = big_to_fe::<E::Base>(x.value());
+            let y = (x.square() * x + E::a() * x + E::b()).sqrt();
+            // Invalid points are rejected by the constraints anyway
+            let y = Option::from(y).unwrap_or(E::Base::ZERO);
+            let y = if bool::from(y.is_odd()) { -y } else { y };
+            Option::from(E::from_xy(x, y)).unwrap_or(E::generator())
+        });
+        let point = ecc_chip.assign_point(ctx, point)?;
+        base_chip.assert_in_field(ctx, x)?;
+        base_chip.assert_equal(ctx, point.x(), x)?;
+        let sign = base_chip.sign(ctx, point.y())?;
+        main_gate.assert_zero(ctx, &sign)?;
+
+        Ok(point)
+    }
+
+    /// Verifies a BIP-340 signature against an x-only public key.
+    ///
+    /// Tagged hash `e = int(hash_BIP0340/challenge(r || P.x || m)) mod n` is
+    /// expected to be computed by the caller. Checks that `s * G = R + e * P`
+    /// where `P` and `R` are lifted from their x coordinates with even y.
+    /// As in the underlying multiplication `s` must not be zero. Auxiliary
+    /// point for `window_size = 4` must be assigned.
+    pub fn verify_bip340(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        sig: &AssignedBip340Sig<E::Base, E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk_x: &AssignedInteger<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        challenge: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        // 1. lift public key and R
+        let pk = self.lift_x(ctx, pk_x)?;
+        let r_point = self.lift_x(ctx, &sig.r)?;
+
+        // 2. check s * G = R + e * P
+        self.assert_schnorr_equation(ctx, &r_point, &sig.s, &pk, challenge)
+    }
+
+    /// Verifies a Schnorr signature where the challenge is derived in circuit
+    /// with Poseidon.
+    ///
+    /// Challenge is the `CHALLENGE_BIT_LEN` least significant bits of the
+    /// hash of limbs of `R.x, R.y, P.x, P.y` followed by the message. Then it
+    /// is checked that `s * G = R + e * P`. As in the underlying
+    /// multiplication `s` must not be zero. Auxiliary point for
+    /// `window_size = 4` must be assigned.
+    pub fn verify<const T: usize, const RATE: usize>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        hasher: &mut HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>,
+        sig: &AssignedSchnorrSig<E::Base, E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPublicKey<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        msg: &[AssignedValue<N>],
+    ) -> Result<(), Error> {
+        let challenge = self.challenge(ctx, hasher, &sig.r, &pk.point, msg)?;
+        self.assert_schnorr_equation(ctx, &sig.r, &sig.s, &pk.point, &challenge)
+    }
+
+    /// Derives challenge of the generic Schnorr signature as an integer in
+    /// the scalar field
+    pub fn challenge<const T: usize, const RATE: usize>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        hasher: &mut HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>,
+        r_point: &AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        msg: &[AssignedValue<N>],
+    ) -> Result<AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let base_chip = ecc_chip.base_field_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+
+        // Coordinates are hashed in canonical form
+        for coordinate in [r_point.x(), r_point.y(), pk.x(), pk.y()] {
+            base_chip.assert_in_field(ctx, coordinate)?;
+            let limbs: Vec<_> = coordinate.limbs().iter().map(|limb| limb.into()).collect();
+            hasher.update(&limbs);
+        }
+        hasher.update(msg);
+        let hash = hasher.hash(ctx)?;
+
+        assign_integer_challenge(ctx, scalar_chip, &hash, CHALLENGE_BIT_LEN)
+    }
+
+    /// Checks `s * G = R + e * P`
+    fn assert_schnorr_equation(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        r_point: &AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        s: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        pk: &AssignedPoint<E::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        challenge: &AssignedInteger<E::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let ecc_chip = self.ecc_chip();
+
+        // generator is a constant so its multiple is computed with precomputed
+        // tables
+        let s_g = ecc_chip.mul_fixed(ctx, E::generator(), s, 4)?;
+        let e_pk = ecc_chip.mul(ctx, pk, challenge, 4)?;
+        let rhs = ecc_chip.add(ctx, r_point, &e_pk)?;
+        ecc_chip.assert_equal(ctx, &s_g, &rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AssignedBip340Sig, AssignedPublicKey, AssignedSchnorrSig, SchnorrChip, CHALLENGE_BIT_LEN,
+    };
+    use crate::halo2;
+    use crate::integer;
+    use crate::maingate;
+    use ecc::integer::Range;
+    use ecc::maingate::{big_to_fe, decompose, fe_to_big, RegionCtx};
+    use ecc::{EccConfig, GeneralEccChip};
+    use halo2::arithmetic::CurveAffine;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::dev::MockProver;
+    use halo2::halo2curves::{
+        ff::{Field, FromUniformBytes, PrimeField},
+        group::{Curve, Group},
+    };
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use integer::IntegerInstructions;
+    use maingate::mock_prover_verify;
+    use maingate::{
+        DimensionMeasurement, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions,
+    };
+    use num_bigint::BigUint as big_uint;
+    use poseidon::{Poseidon, Spec};
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use std::marker::PhantomData;
+    use transcript::HasherChip;
+
+    const BIT_LEN_LIMB: usize = 68;
+    const NUMBER_OF_LIMBS: usize = 4;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitSchnorrVerifyConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestCircuitSchnorrVerifyConfig {
+        pub fn new<C: CurveAffine, N: PrimeField>(meta: &mut ConstraintSystem<N>) -> Self {
+            let (rns_base, rns_scalar) =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+            TestCircuitSchnorrVerifyConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        pub fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        pub fn config_range<N: PrimeField>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitBip340Verify<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E::Base>,
+        signature: Value<(E::Base, E::Scalar)>,
+        challenge: Value<E::Scalar>,
+
+        aux_generator: E,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: PrimeField> Circuit<N> for TestCircuitBip340Verify<E, N> {
+        type Config = TestCircuitSchnorrVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitSchnorrVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, 4, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            let schnorr_chip = SchnorrChip::new(ecc_chip.clone());
+            let base_chip = ecc_chip.base_field_chip();
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = self.signature.map(|signature| signature.0);
+                    let s = self.signature.map(|signature| signature.1);
+                    let sig = AssignedBip340Sig {
+                        r: base_chip.assign_integer(
+                            ctx,
+                            ecc_chip.new_unassigned_base(r),
+                            Range::Remainder,
+                        )?,
+                        s: scalar_chip.assign_integer(
+                            ctx,
+                            ecc_chip.new_unassigned_scalar(s),
+                            Range::Remainder,
+                        )?,
+                    };
+                    let pk_x = base_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_base(self.public_key),
+                        Range::Remainder,
+                    )?;
+                    let challenge = scalar_chip.assign_integer(
+                        ctx,
+                        ecc_chip.new_unassigned_scalar(self.challenge),
+                        Range::Remainder,
+                    )?;
+                    schnorr_chip.verify_bip340(ctx, &sig, &pk_x, &challenge)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn tagged_hash(tag: &str, data: &[&[u8]]) -> Vec<u8> {
+        let tag = Sha256::digest(tag.as_bytes());
+        let mut hasher = Sha256::new();
+        hasher.update(tag);
+        hasher.update(tag);
+        for data in data {
+            hasher.update(data);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    #[test]
+    fn test_bip340_verifier() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::{Fp, Fq, Secp256k1Affine as Secp256k1};
+
+        // Valid signatures from BIP-340 test vectors as
+        // (public key, message, signature)
+        let vectors = [
+            (
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+                 8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+                 AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+            (
+                "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC\
+                 97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            ),
+        ];
+
+        for (public_key, msg, signature) in vectors {
+            let public_key = from_hex(public_key);
+            let msg = from_hex(msg);
+            let signature = from_hex(signature);
+            let (r, s) = signature.split_at(32);
+
+            let challenge = tagged_hash("BIP0340/challenge", &[r, &public_key, &msg]);
+            let challenge: Fq = big_to_fe(big_uint::from_bytes_be(&challenge));
+            let public_key: Fp = big_to_fe(big_uint::from_bytes_be(&public_key));
+            let r: Fp = big_to_fe(big_uint::from_bytes_be(r));
+            let s: Fq = big_to_fe(big_uint::from_bytes_be(s));
+
+            let aux_generator = <Secp256k1 as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let circuit = TestCircuitBip340Verify::<Secp256k1, BnScalar> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r, s)),
+                challenge: Value::known(challenge),
+                aux_generator,
+                ..Default::default()
+            };
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+    }
+
+    fn assert_rejected<N: FromUniformBytes<64> + Ord, C: Circuit<N>>(circuit: &C) {
+        let dimension = DimensionMeasurement::measure(circuit).unwrap();
+        let prover = MockProver::run(dimension.k(), circuit, vec![vec![]]).unwrap();
+        assert!(prover
+            .verify_at_rows(dimension.advice_range(), dimension.advice_range())
+            .is_err());
+    }
+
+    const T: usize = 5;
+    const RATE: usize = 4;
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+
+    #[derive(Default, Clone)]
+    struct TestCircuitSchnorrVerify<E: CurveAffine, N: PrimeField> {
+        public_key: Value<E>,
+        signature: Value<(E, E::Scalar)>,
+        msg: Vec<Value<N>>,
+
+        aux_generator: E,
+    }
+
+    impl<E: CurveAffine, N: FromUniformBytes<64> + Ord> Circuit<N> for TestCircuitSchnorrVerify<E, N> {
+        type Config = TestCircuitSchnorrVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitSchnorrVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let mut ecc_chip = GeneralEccChip::<E, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "assign aux values",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc_chip.assign_aux_generator(ctx, Value::known(self.aux_generator))?;
+                    ecc_chip.assign_aux(ctx, 4, 1)?;
+                    Ok(())
+                },
+            )?;
+
+            let schnorr_chip = SchnorrChip::new(ecc_chip.clone());
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let r = self.signature.map(|signature| signature.0);
+                    let s = self.signature.map(|signature| signature.1);
+                    let sig = AssignedSchnorrSig {
+                        r: ecc_chip.assign_point(ctx, r)?,
+                        s: scalar_chip.assign_integer(
+                            ctx,
+                            ecc_chip.new_unassigned_scalar(s),
+                            Range::Remainder,
+                        )?,
+                    };
+                    let pk = AssignedPublicKey {
+                        point: ecc_chip.assign_point(ctx, self.public_key)?,
+                    };
+                    let msg = self
+                        .msg
+                        .iter()
+                        .map(|e| main_gate.assign_value(ctx, *e))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let spec = Spec::<N, T, RATE>::new(R_F, R_P);
+                    let mut hasher = HasherChip::<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>::new(
+                        ctx,
+                        &spec,
+                        &config.main_gate_config,
+                    )?;
+                    schnorr_chip.verify(ctx, &mut hasher, &sig, &pk, &msg)
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    /// Reference implementation of the challenge of generic Schnorr signature
+    fn challenge<C: CurveAffine, N: FromUniformBytes<64>>(
+        r_point: C,
+        public_key: C,
+        msg: &[N],
+    ) -> C::Scalar {
+        let mut hasher = Poseidon::<N, T, RATE>::new(R_F, R_P);
+        for point in [r_point, public_key] {
+            let coords = point.coordinates().unwrap();
+            for coordinate in [coords.x(), coords.y()] {
+                let limbs: Vec<N> = decompose(*coordinate, NUMBER_OF_LIMBS, BIT_LEN_LIMB)
+                    .into_iter()
+                    .map(|limb| big_to_fe(fe_to_big(limb)))
+                    .collect();
+                hasher.update(&limbs);
+            }
+        }
+        hasher.update(msg);
+        let hash = fe_to_big(hasher.squeeze());
+        let mask = (big_uint::from(1u64) << CHALLENGE_BIT_LEN) - 1u64;
+        big_to_fe(hash & mask)
+    }
+
+    #[test]
+    fn test_schnorr_verifier() {
+        fn run<C: CurveAffine, N: FromUniformBytes<64> + Ord>() {
+            let g = C::generator();
+
+            // Generate a key pair
+            let sk = <C as CurveAffine>::ScalarExt::random(OsRng);
+            let public_key = (g * sk).to_affine();
+
+            let msg: Vec<N> = (0..3).map(|_| N::random(OsRng)).collect();
+
+            // Sign with a random nonce
+            let k = <C as CurveAffine>::ScalarExt::random(OsRng);
+            let r_point = (g * k).to_affine();
+            let e = challenge(r_point, public_key, &msg);
+            let s = k + e * sk;
+
+            // Sanity check
+            assert_eq!((g * s).to_affine(), (r_point + public_key * e).to_affine());
+
+            let aux_generator = C::CurveExt::random(OsRng).to_affine();
+            let circuit = |public_key, s, msg: &[N]| TestCircuitSchnorrVerify::<C, N> {
+                public_key: Value::known(public_key),
+                signature: Value::known((r_point, s)),
+                msg: msg.iter().map(|e| Value::known(*e)).collect(),
+                aux_generator,
+            };
+            mock_prover_verify(&circuit(public_key, s, &msg), vec![vec![]]);
+
+            // wrong signature, key or message is rejected
+            let other_key = (g * <C as CurveAffine>::ScalarExt::random(OsRng)).to_affine();
+            let mut other_msg = msg.clone();
+            other_msg[0] += N::ONE;
+            for circuit in [
+                circuit(public_key, s + C::Scalar::ONE, &msg),
+                circuit(other_key, s, &msg),
+                circuit(public_key, s, &other_msg),
+            ] {
+                assert_rejected(&circuit);
+            }
+        }
+
+        use crate::curves::bn256::{Fr as BnScalar, G1Affine as Bn256};
+        use crate::curves::pasta::{EpAffine as Pallas, Fp as PastaFp};
+        use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
+        run::<Secp256k1, BnScalar>();
+        run::<Secp256k1, PastaFp>();
+        run::<Pallas, BnScalar>();
+        run::<Bn256, PastaFp>();
+    }
+}
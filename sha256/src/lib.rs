@@ -0,0 +1,14 @@
+//! `sha256` constrains SHA-256 hash of fixed length messages on top of
+//! `MainGate` and `RangeChip`
+
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+
+mod sha256;
+
+pub use maingate;
+pub use maingate::halo2;
+pub use sha256::*;
+
+#[cfg(test)]
+use halo2::halo2curves as curves;
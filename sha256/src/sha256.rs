@@ -0,0 +1,499 @@
+use crate::halo2::circuit::Value;
+use crate::halo2::halo2curves::ff::PrimeField;
+use crate::halo2::plonk::Error;
+use crate::maingate::{
+    decompose, AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions,
+    RangeChip, RangeConfig, RangeInstructions, RegionCtx, Term,
+};
+
+/// Bit length of a word
+pub const WORD_BIT_LEN: usize = 32;
+/// Bit length of lookup limbs that bytes, words and carries are ranged with
+pub const LIMB_BIT_LEN: usize = 8;
+/// Number of words in a digest
+pub const DIGEST_WORDS: usize = 8;
+
+const BLOCK_BYTES: usize = 64;
+
+const INITIAL_STATE: [u32; DIGEST_WORDS] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Word that is used in bitwise operations along with its little endian bit
+/// decomposition
+#[derive(Debug, Clone)]
+struct AssignedWord<F: PrimeField> {
+    value: AssignedValue<F>,
+    bits: Vec<AssignedCondition<F>>,
+}
+
+/// Returns terms that compose a word from its little endian bits
+fn word_terms<F: PrimeField>(bits: &[AssignedCondition<F>]) -> impl Iterator<Item = Term<'_, F>> {
+    bits.iter()
+        .enumerate()
+        .map(|(i, bit)| Term::Assigned(bit, F::from(1 << i)))
+}
+
+/// Configuration for [`Sha256Chip`]
+#[derive(Clone, Debug)]
+pub struct Sha256Config {
+    main_gate_config: MainGateConfig,
+    range_config: RangeConfig,
+}
+
+impl Sha256Config {
+    /// Creates new config from main gate and range configs. Range config is
+    /// expected to have `LIMB_BIT_LEN` in its composition bit lenghts
+    pub fn new(main_gate_config: MainGateConfig, range_config: RangeConfig) -> Self {
+        Self {
+            main_gate_config,
+            range_config,
+        }
+    }
+}
+
+/// `Sha256Chip` constrains SHA-256 hash of byte messages. Words are ranged
+/// with lookups and bitwise logic is applied to their bit decompositions
+#[derive(Clone, Debug)]
+pub struct Sha256Chip<F: PrimeField> {
+    main_gate: MainGate<F>,
+    range_chip: RangeChip<F>,
+}
+
+impl<F: PrimeField> Sha256Chip<F> {
+    /// Creates new chip from the given config
+    pub fn new(config: Sha256Config) -> Self {
+        Self {
+            main_gate: MainGate::new(config.main_gate_config),
+            range_chip: RangeChip::new(config.range_config),
+        }
+    }
+
+    /// Returns composition bit lenghts that range chip should be configured
+    /// with
+    pub fn composition_bit_lens() -> Vec<usize> {
+        vec![LIMB_BIT_LEN]
+    }
+
+    /// Returns main gate
+    pub fn main_gate(&self) -> &MainGate<F> {
+        &self.main_gate
+    }
+
+    /// Returns range chip
+    pub fn range_chip(&self) -> &RangeChip<F> {
+        &self.range_chip
+    }
+
+    /// Assigns bytes of a message and constrains them to be in byte range
+    pub fn assign_bytes(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bytes: &[Value<u8>],
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        bytes
+            .iter()
+            .map(|byte| {
+                self.range_chip.assign(
+                    ctx,
+                    byte.map(|byte| F::from(byte as u64)),
+                    LIMB_BIT_LEN,
+                    LIMB_BIT_LEN,
+                )
+            })
+            .collect()
+    }
+
+    /// Constrains SHA-256 digest of the message and returns it as big endian
+    /// 32-bit words. Bytes of the message are expected to be ranged, for
+    /// example with `assign_bytes`. Since length of the message is fixed
+    /// padding is added as constants
+    pub fn digest(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bytes: &[AssignedValue<F>],
+    ) -> Result<[AssignedValue<F>; DIGEST_WORDS], Error> {
+        let main_gate = self.main_gate();
+
+        // Pad with `0x80`, zeros and big endian bit length of the message
+        let number_of_zeros = (BLOCK_BYTES + 55 - bytes.len() % BLOCK_BYTES) % BLOCK_BYTES;
+        let bit_len = (bytes.len() as u64) * 8;
+        let padding = std::iter::once(0x80u8)
+            .chain(std::iter::repeat(0).take(number_of_zeros))
+            .chain(bit_len.to_be_bytes())
+            .map(|byte| main_gate.assign_constant(ctx, F::from(byte as u64)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let padded: Vec<&AssignedValue<F>> = bytes.iter().chain(padding.iter()).collect();
+        assert_eq!(padded.len() % BLOCK_BYTES, 0);
+
+        // Bytes are ranged so composed words are in word range
+        let words = padded
+            .chunks(4)
+            .map(|bytes| {
+                let terms: Vec<Term<F>> = bytes
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, byte)| Term::Assigned(*byte, F::from(1 << (8 * i))))
+                    .collect();
+                main_gate.compose(ctx, &terms, F::ZERO)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut state = INITIAL_STATE
+            .iter()
+            .map(|word| main_gate.assign_constant(ctx, F::from(*word as u64)))
+            .collect::<Result<Vec<_>, Error>>()?
+            .try_into()
+            .unwrap();
+        for block in words.chunks(BLOCK_BYTES / 4) {
+            state = self.compress(ctx, &state, block)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Applies compression function to a single block of 16 words
+    fn compress(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        state: &[AssignedValue<F>; DIGEST_WORDS],
+        block: &[AssignedValue<F>],
+    ) -> Result<[AssignedValue<F>; DIGEST_WORDS], Error> {
+        // Message schedule
+        let mut schedule = block
+            .iter()
+            .map(|word| self.word(ctx, word.clone()))
+            .collect::<Result<Vec<_>, Error>>()?;
+        for t in 16..64 {
+            let s0 = self.sigma(ctx, &schedule[t - 15].bits, &[7, 18], Some(3))?;
+            let s1 = self.sigma(ctx, &schedule[t - 2].bits, &[17, 19], Some(10))?;
+            let terms: Vec<Term<F>> = word_terms(&s0)
+                .chain(word_terms(&s1))
+                .chain([
+                    Term::assigned_to_add(&schedule[t - 7].value),
+                    Term::assigned_to_add(&schedule[t - 16].value),
+                ])
+                .collect();
+            let word = self.add_mod(ctx, &terms, F::ZERO)?;
+            let word = self.word(ctx, word)?;
+            schedule.push(word);
+        }
+
+        // Working variables `a, b, c, d, e, f, g, h`
+        let mut vars: [AssignedWord<F>; DIGEST_WORDS] = state
+            .iter()
+            .map(|word| self.word(ctx, word.clone()))
+            .collect::<Result<Vec<_>, Error>>()?
+            .try_into()
+            .unwrap();
+        for (constant, w) in ROUND_CONSTANTS.iter().zip(schedule.iter()) {
+            let constant = F::from(*constant as u64);
+            let [a, b, c, d, e, f, g, h] = &vars;
+
+            let s1 = self.sigma(ctx, &e.bits, &[6, 11, 25], None)?;
+            let ch = self.ch(ctx, &e.bits, &f.bits, &g.bits)?;
+            let s0 = self.sigma(ctx, &a.bits, &[2, 13, 22], None)?;
+            let maj = self.maj(ctx, &a.bits, &b.bits, &c.bits)?;
+
+            // t1 = h + S1(e) + ch(e, f, g) + k + w
+            let t1: Vec<Term<F>> = [
+                Term::assigned_to_add(&h.value),
+                Term::assigned_to_add(&w.value),
+            ]
+            .into_iter()
+            .chain(word_terms(&s1))
+            .chain(word_terms(&ch))
+            .collect();
+
+            // e' = d + t1
+            let terms: Vec<Term<F>> = t1
+                .iter()
+                .cloned()
+                .chain(std::iter::once(Term::assigned_to_add(&d.value)))
+                .collect();
+            let e = self.add_mod(ctx, &terms, constant)?;
+            let e = self.word(ctx, e)?;
+
+            // a' = t1 + S0(a) + maj(a, b, c)
+            let terms: Vec<Term<F>> = t1
+                .iter()
+                .cloned()
+                .chain(word_terms(&s0))
+                .chain(word_terms(&maj))
+                .collect();
+            let a = self.add_mod(ctx, &terms, constant)?;
+            let a = self.word(ctx, a)?;
+
+            vars.rotate_right(1);
+            vars[0] = a;
+            vars[4] = e;
+        }
+
+        let state = state
+            .iter()
+            .zip(vars.iter())
+            .map(|(word, var)| {
+                self.add_mod(
+                    ctx,
+                    &[
+                        Term::assigned_to_add(word),
+                        Term::assigned_to_add(&var.value),
+                    ],
+                    F::ZERO,
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(state.try_into().unwrap())
+    }
+
+    /// Decomposes a word that is already in word range into bits
+    fn word(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        value: AssignedValue<F>,
+    ) -> Result<AssignedWord<F>, Error> {
+        let bits = self.main_gate.to_bits(ctx, &value, WORD_BIT_LEN)?;
+        Ok(AssignedWord { value, bits })
+    }
+
+    /// Returns sum of terms modulo `2^32` where sum is expected to be less
+    /// than `2^40`. Result and the carry are ranged with lookups
+    fn add_mod(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        terms: &[Term<F>],
+        constant: F,
+    ) -> Result<AssignedValue<F>, Error> {
+        let decomposed = Term::compose(terms, constant)
+            .map(|sum| decompose(sum, 2, WORD_BIT_LEN))
+            .transpose_vec(2);
+        let result = self
+            .range_chip
+            .assign(ctx, decomposed[0], LIMB_BIT_LEN, WORD_BIT_LEN)?;
+        let carry = self
+            .range_chip
+            .assign(ctx, decomposed[1], LIMB_BIT_LEN, LIMB_BIT_LEN)?;
+
+        let terms: Vec<Term<F>> = terms
+            .iter()
+            .cloned()
+            .chain([
+                Term::assigned_to_sub(&result),
+                Term::Assigned(&carry, -F::from(1 << WORD_BIT_LEN)),
+            ])
+            .collect();
+        self.main_gate.assert_zero_sum(ctx, &terms, constant)?;
+
+        Ok(result)
+    }
+
+    /// Returns xor of rotations of a word and optionally a right shift of it
+    fn sigma(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        bits: &[AssignedCondition<F>],
+        rotations: &[usize],
+        shift: Option<usize>,
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        (0..WORD_BIT_LEN)
+            .map(|i| {
+                let mut operands = rotations
+                    .iter()
+                    .map(|rotation| &bits[(i + rotation) % WORD_BIT_LEN])
+                    .chain(shift.and_then(|shift| bits.get(i + shift)));
+                let first = operands.next().unwrap().clone();
+                operands.try_fold(first, |acc, bit| self.main_gate.xor(ctx, &acc, bit))
+            })
+            .collect()
+    }
+
+    /// Returns `(e & f) ^ (!e & g)` as `g + e * (f - g)`
+    fn ch(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        e: &[AssignedCondition<F>],
+        f: &[AssignedCondition<F>],
+        g: &[AssignedCondition<F>],
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        e.iter()
+            .zip(f.iter())
+            .zip(g.iter())
+            .map(|((e, f), g)| {
+                let f_minus_g = self.main_gate.sub(ctx, f, g)?;
+                self.main_gate.mul_add(ctx, e, &f_minus_g, g)
+            })
+            .collect()
+    }
+
+    /// Returns `(a & b) ^ (a & c) ^ (b & c)` as `a * b + c * (a ^ b)`
+    fn maj(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &[AssignedCondition<F>],
+        b: &[AssignedCondition<F>],
+        c: &[AssignedCondition<F>],
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        a.iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| {
+                let a_xor_b = self.main_gate.xor(ctx, a, b)?;
+                let c_and_a_xor_b = self.main_gate.mul(ctx, c, &a_xor_b)?;
+                self.main_gate.mul_add(ctx, a, b, &c_and_a_xor_b)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sha256Chip, Sha256Config, DIGEST_WORDS};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::PrimeField;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        mock_prover_verify, MainGate, MainGateInstructions, RangeChip, RangeInstructions, RegionCtx,
+    };
+    use rand_core::{OsRng, RngCore};
+    use sha2::{Digest, Sha256};
+    use std::marker::PhantomData;
+
+    fn digest_words(digest: &[u8]) -> [u32; DIGEST_WORDS] {
+        digest
+            .chunks(4)
+            .map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        sha256_config: Sha256Config,
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuit<F: PrimeField> {
+        message: Vec<u8>,
+        expected: [u32; DIGEST_WORDS],
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let range_config = RangeChip::<F>::configure(
+                meta,
+                &main_gate_config,
+                Sha256Chip::<F>::composition_bit_lens(),
+                vec![],
+            );
+            TestCircuitConfig {
+                sha256_config: Sha256Config::new(main_gate_config, range_config),
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let sha256_chip = Sha256Chip::<F>::new(config.sha256_config);
+            let main_gate = sha256_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let message: Vec<Value<u8>> = self
+                        .message
+                        .iter()
+                        .map(|byte| Value::known(*byte))
+                        .collect();
+                    let message = sha256_chip.assign_bytes(ctx, &message)?;
+                    let digest = sha256_chip.digest(ctx, &message)?;
+                    for (word, expected) in digest.iter().zip(self.expected.iter()) {
+                        main_gate.assert_equal_to_constant(ctx, word, F::from(*expected as u64))?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            sha256_chip.range_chip().load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run(message: &[u8], expected: [u32; DIGEST_WORDS]) {
+        let circuit = TestCircuit::<Fr> {
+            message: message.to_vec(),
+            expected,
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_sha256_nist_vectors() {
+        // FIPS 180-2 examples and the empty message
+        run(
+            b"abc",
+            [
+                0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+                0xf20015ad,
+            ],
+        );
+        run(
+            b"",
+            [
+                0xe3b0c442, 0x98fc1c14, 0x9afbf4c8, 0x996fb924, 0x27ae41e4, 0x649b934c, 0xa495991b,
+                0x7852b855,
+            ],
+        );
+        run(
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmjklmnklmnolmnopmnopqnopq",
+            [
+                0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167, 0xf6ecedd4,
+                0x19db06c1,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sha256_padding_boundaries() {
+        // Lengths around the point where padding spills into another block
+        for len in [55, 56, 64] {
+            let mut message = vec![0u8; len];
+            OsRng.fill_bytes(&mut message);
+            let expected = digest_words(&Sha256::digest(&message));
+            run(&message, expected);
+        }
+    }
+}
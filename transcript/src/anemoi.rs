@@ -0,0 +1,343 @@
+use crate::{
+    halo2::{halo2curves::ff::PrimeField, plonk::Error},
+    maingate::{
+        big_to_fe, AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RegionCtx, Term,
+    },
+    permutation::{add_inputs_with_padding, pow_inv, sbox_exponents, Permutation},
+};
+use num_bigint::BigUint as big_uint;
+
+/// First digits of `pi` that round constants are derived from
+const PI_0: &str = "1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+
+/// `AnemoiSpec` holds parameters of Anemoi permutation with a single column,
+/// that is a state of two words where the first word is rate.
+///
+/// Outputs are not checked against vectors of the Anemoi paper or its
+/// reference code, so this is not an interoperability primitive: digests and
+/// challenges must not be expected to match other Anemoi instances until such
+/// vectors are added
+#[derive(Debug, Clone)]
+pub struct AnemoiSpec<F: PrimeField> {
+    generator: F,
+    delta: F,
+    alpha: u64,
+    alpha_inv: Vec<u64>,
+    constants: Vec<(F, F)>,
+}
+
+impl<F: PrimeField> AnemoiSpec<F> {
+    /// Generates parameters for given number of rounds. `generator` is the
+    /// generator of the multiplicative group that is used in the flystel
+    /// and in round constants
+    pub fn new(number_of_rounds: usize, generator: F) -> Self {
+        let (alpha, alpha_inv) = sbox_exponents::<F>();
+        let delta = generator.invert().unwrap();
+        let pi_0: F = big_to_fe(big_uint::parse_bytes(PI_0.as_bytes(), 10).unwrap());
+
+        // C_r = g * pi_0^(2r) + (pi_0^r + 1)^alpha
+        // D_r = g + (pi_0^r + 1)^alpha + delta
+        let constants = (0..number_of_rounds as u64)
+            .map(|r| {
+                let pi_0_r = pi_0.pow_vartime([r]);
+                let t = (pi_0_r + F::ONE).pow_vartime([alpha]);
+                (generator * pi_0_r.square() + t, generator + t + delta)
+            })
+            .collect();
+
+        Self {
+            generator,
+            delta,
+            alpha,
+            alpha_inv,
+            constants,
+        }
+    }
+
+    /// Returns number of rounds
+    pub fn number_of_rounds(&self) -> usize {
+        self.constants.len()
+    }
+
+    /// Returns round constants
+    pub fn constants(&self) -> &[(F, F)] {
+        &self.constants
+    }
+
+    /// Applies the permutation to the given state out of circuit
+    pub fn permute(&self, state: &mut [F; 2]) {
+        let [mut x, mut y] = *state;
+        for (c, d) in self.constants.iter() {
+            x += c;
+            y += d;
+            // Pseudo-Hadamard transform
+            y += x;
+            x += y;
+            // Flystel
+            x -= self.generator * y.square();
+            y -= x.pow_vartime(&self.alpha_inv);
+            x += self.generator * y.square() + self.delta;
+        }
+        y += x;
+        x += y;
+        *state = [x, y];
+    }
+}
+
+/// `AnemoiChip` constrains Anemoi sponge with rate of a single word. It
+/// matches `AnemoiSpec::permute` but is not checked against other Anemoi
+/// implementations
+#[derive(Debug, Clone)]
+pub struct AnemoiChip<F: PrimeField> {
+    state: [AssignedValue<F>; 2],
+    absorbing: Vec<AssignedValue<F>>,
+    spec: AnemoiSpec<F>,
+    main_gate_config: MainGateConfig,
+}
+
+impl<F: PrimeField> AnemoiChip<F> {
+    /// Constructs new anemoi chip with all zero initial state
+    pub fn new(
+        ctx: &mut RegionCtx<'_, F>,
+        spec: &AnemoiSpec<F>,
+        main_gate_config: &MainGateConfig,
+    ) -> Result<Self, Error> {
+        let main_gate = MainGate::<_>::new(main_gate_config.clone());
+        let x = main_gate.assign_constant(ctx, F::ZERO)?;
+        let y = main_gate.assign_constant(ctx, F::ZERO)?;
+
+        Ok(Self {
+            state: [x, y],
+            absorbing: vec![],
+            spec: spec.clone(),
+            main_gate_config: main_gate_config.clone(),
+        })
+    }
+
+    /// Construct main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        MainGate::<_>::new(self.main_gate_config.clone())
+    }
+}
+
+impl<F: PrimeField> Permutation<F, 2, 1> for AnemoiChip<F> {
+    fn absorbing(&mut self) -> &mut Vec<AssignedValue<F>> {
+        &mut self.absorbing
+    }
+
+    fn permutation(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: Vec<AssignedValue<F>>,
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let spec = &self.spec;
+        add_inputs_with_padding(ctx, &main_gate, &mut self.state[..1], &inputs)?;
+        let [mut x, mut y] = self.state.clone();
+
+        let g = spec.generator;
+        for (c, d) in spec.constants.iter() {
+            // Round constants are merged into pseudo-Hadamard transform
+            // y' = x + y + c + d
+            // x' = 2x + y + 2c + d
+            let y_next = main_gate.compose(
+                ctx,
+                &[Term::Assigned(&x, F::ONE), Term::Assigned(&y, F::ONE)],
+                *c + d,
+            )?;
+            x = main_gate.compose(
+                ctx,
+                &[
+                    Term::Assigned(&x, F::ONE.double()),
+                    Term::Assigned(&y, F::ONE),
+                ],
+                c.double() + d,
+            )?;
+            y = y_next;
+
+            // Flystel
+            let y_square = main_gate.mul(ctx, &y, &y)?;
+            x = main_gate.compose(
+                ctx,
+                &[Term::Assigned(&x, F::ONE), Term::Assigned(&y_square, -g)],
+                F::ZERO,
+            )?;
+            let x_root = pow_inv(ctx, &main_gate, &x, spec.alpha, &spec.alpha_inv)?;
+            y = main_gate.sub(ctx, &y, &x_root)?;
+            let y_square = main_gate.mul(ctx, &y, &y)?;
+            x = main_gate.compose(
+                ctx,
+                &[Term::Assigned(&x, F::ONE), Term::Assigned(&y_square, g)],
+                spec.delta,
+            )?;
+        }
+
+        let y_next = main_gate.add(ctx, &x, &y)?;
+        x = main_gate.compose(
+            ctx,
+            &[
+                Term::Assigned(&x, F::ONE.double()),
+                Term::Assigned(&y, F::ONE),
+            ],
+            F::ZERO,
+        )?;
+        self.state = [x, y_next];
+
+        Ok(())
+    }
+
+    fn output(&self) -> AssignedValue<F> {
+        self.state[0].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnemoiChip, AnemoiSpec};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::Field;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        big_to_fe, mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RegionCtx,
+    };
+    use crate::permutation::Permutation;
+    use num_bigint::BigUint as big_uint;
+    use rand_core::OsRng;
+
+    const NUMBER_OF_ROUNDS: usize = 21;
+
+    fn from_hex(e: &str) -> Fr {
+        big_to_fe(big_uint::parse_bytes(e.as_bytes(), 16).unwrap())
+    }
+
+    // Sponge out of circuit with the same padding rule
+    fn hash(spec: &AnemoiSpec<Fr>, inputs: &[Fr]) -> Fr {
+        let mut state = [Fr::ZERO; 2];
+        for e in inputs.iter().chain(std::iter::once(&Fr::ONE)) {
+            state[0] += e;
+            spec.permute(&mut state);
+        }
+        state[0]
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit {
+        spec: AnemoiSpec<Fr>,
+        inputs: Value<Vec<Fr>>,
+        n: usize,
+        expected: Value<Fr>,
+    }
+
+    impl Circuit<Fr> for TestCircuit {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let main_gate_config = MainGate::<Fr>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut chip = AnemoiChip::new(ctx, &self.spec, &config.main_gate_config)?;
+
+                    for e in self.inputs.as_ref().transpose_vec(self.n) {
+                        let e = main_gate.assign_value(ctx, e.map(|e| *e))?;
+                        chip.absorb(&[e]);
+                    }
+                    let output = chip.squeeze(ctx)?;
+                    let expected = main_gate.assign_value(ctx, self.expected)?;
+                    main_gate.assert_equal(ctx, &output, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    // Expected values below are recorded from this implementation to catch
+    // regressions. They are not external test vectors
+
+    #[test]
+    fn test_anemoi_known_permutation() {
+        let spec = AnemoiSpec::new(NUMBER_OF_ROUNDS, Fr::from(5));
+        assert_eq!(spec.constants()[0].0, Fr::from(0x25));
+        assert_eq!(
+            spec.constants()[0].1,
+            from_hex("135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c666668c")
+        );
+
+        let mut state = [Fr::ZERO, Fr::ONE];
+        spec.permute(&mut state);
+        assert_eq!(
+            state,
+            [
+                from_hex("808e3921fc7a9cc2158eab2c805f80d33ff254237fe6b2ce06f83572b833eab"),
+                from_hex("107063a755b95efa530e745b35b8fbcce2a26d3b92bb12ee2c34b3a92719d01"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_anemoi_known_hash() {
+        let spec = AnemoiSpec::new(NUMBER_OF_ROUNDS, Fr::from(5));
+        let expected = [
+            "f9694224ef77a987d4f98c3c003c7f65fa6a4f613ad798a19bd9ddcd7acd295",
+            "1a59e6103d5e5002a83b4d8fbc0e4e9bc89fc7cab03e07611a6930536d0883bb",
+            "19bc74645778392db7bb72d71c2004cdef80b59492b7ff0d62b0775f8c146207",
+        ];
+        for (n, expected) in expected.iter().enumerate() {
+            let inputs: Vec<Fr> = (1..=n as u64).map(Fr::from).collect();
+            let expected = from_hex(expected);
+            assert_eq!(hash(&spec, &inputs), expected);
+
+            let circuit = TestCircuit {
+                spec: spec.clone(),
+                inputs: Value::known(inputs),
+                n,
+                expected: Value::known(expected),
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+        }
+    }
+
+    #[test]
+    fn test_anemoi_random_inputs() {
+        let spec = AnemoiSpec::new(NUMBER_OF_ROUNDS, Fr::from(5));
+        for n in 0..5 {
+            let inputs: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let expected = hash(&spec, &inputs);
+            let circuit = TestCircuit {
+                spec: spec.clone(),
+                inputs: Value::known(inputs),
+                n,
+                expected: Value::known(expected),
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+        }
+    }
+}
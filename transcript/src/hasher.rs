@@ -1,6 +1,7 @@
 use crate::{
     halo2::{halo2curves::ff::PrimeField, plonk::Error},
     maingate::{AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RegionCtx, Term},
+    permutation::Permutation,
 };
 use poseidon::{SparseMDSMatrix, Spec, State};
 
@@ -38,13 +39,27 @@ impl<
         ctx: &mut RegionCtx<'_, F>,
         spec: &Spec<F, T, RATE>,
         main_gate_config: &MainGateConfig,
+    ) -> Result<Self, Error> {
+        // Capacity element of the reference hasher for variable length inputs
+        let capacity = State::<_, T>::default().words()[0];
+        Self::new_with_capacity(ctx, spec, main_gate_config, capacity)
+    }
+
+    /// Constructs new hasher chip with the given capacity element for domain
+    /// separation. `new` uses `2^64` for compatibility with the reference
+    /// hasher
+    pub fn new_with_capacity(
+        ctx: &mut RegionCtx<'_, F>,
+        spec: &Spec<F, T, RATE>,
+        main_gate_config: &MainGateConfig,
+        capacity: F,
     ) -> Result<Self, Error> {
         let main_gate = MainGate::<_>::new(main_gate_config.clone());
 
-        let initial_state = State::<_, T>::default()
-            .words()
-            .iter()
-            .map(|word| main_gate.assign_constant(ctx, *word))
+        let initial_state = Some(capacity)
+            .into_iter()
+            .chain(std::iter::repeat(F::ZERO).take(T - 1))
+            .map(|word| main_gate.assign_constant(ctx, word))
             .collect::<Result<Vec<AssignedValue<F>>, Error>>()?;
 
         Ok(Self {
@@ -57,8 +72,44 @@ impl<
 
     /// Appends field elements to the absorbation line. It won't perform
     /// permutation here
+    pub fn absorb(&mut self, elements: &[AssignedValue<F>]) {
+        Permutation::absorb(self, elements)
+    }
+
+    /// Absorbs pending elements with padding and returns a single output.
+    /// State is preserved so absorbing and squeezing can be interleaved.
+    /// Squeezing with nothing absorbed applies a padding only permutation
+    pub fn squeeze(&mut self, ctx: &mut RegionCtx<'_, F>) -> Result<AssignedValue<F>, Error> {
+        Permutation::squeeze(self, ctx)
+    }
+
+    /// Squeezes `n` outputs where each is followed by a padding only
+    /// permutation as in `n` consecutive calls of `squeeze`
+    pub fn squeeze_n(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        n: usize,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        Permutation::squeeze_n(self, ctx, n)
+    }
+
+    /// Constrains poseidon permutation while mutating the given state
+    pub fn permutation(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: Vec<AssignedValue<F>>,
+    ) -> Result<(), Error> {
+        Permutation::permutation(self, ctx, inputs)
+    }
+
+    /// Same as `absorb`
     pub fn update(&mut self, elements: &[AssignedValue<F>]) {
-        self.absorbing.extend_from_slice(elements);
+        self.absorb(elements)
+    }
+
+    /// Same as `squeeze`
+    pub fn hash(&mut self, ctx: &mut RegionCtx<'_, F>) -> Result<AssignedValue<F>, Error> {
+        self.squeeze(ctx)
     }
 }
 
@@ -257,9 +308,22 @@ impl<
 
         Ok(())
     }
+}
+
+impl<
+        F: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+    > Permutation<F, T, RATE> for HasherChip<F, NUMBER_OF_LIMBS, BIT_LEN, T, RATE>
+{
+    fn absorbing(&mut self) -> &mut Vec<AssignedValue<F>> {
+        &mut self.absorbing
+    }
 
     /// Constrains poseidon permutation while mutating the given state
-    pub fn permutation(
+    fn permutation(
         &mut self,
         ctx: &mut RegionCtx<'_, F>,
         inputs: Vec<AssignedValue<F>>,
@@ -298,24 +362,157 @@ impl<
         Ok(())
     }
 
-    pub fn hash(&mut self, ctx: &mut RegionCtx<'_, F>) -> Result<AssignedValue<F>, Error> {
-        // Get elements to be hashed
-        let input_elements = self.absorbing.clone();
-        // Flush the input que
-        self.absorbing.clear();
-
-        let mut padding_offset = 0;
-        // Apply permutation to `RATE`Ï sized chunks
-        for chunk in input_elements.chunks(RATE) {
-            padding_offset = RATE - chunk.len();
-            self.permutation(ctx, chunk.to_vec())?;
+    fn output(&self) -> AssignedValue<F> {
+        self.state.0[1].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HasherChip;
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RegionCtx,
+    };
+    use paste::paste;
+    use poseidon::{Poseidon, Spec};
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    enum Op<F: PrimeField> {
+        Absorb(Vec<F>),
+        Squeeze(usize),
+    }
+
+    struct TestSponge<F: PrimeField, const T: usize, const RATE: usize> {
+        spec: Spec<F, T, RATE>,
+        ops: Vec<Op<F>>,
+        expected: Vec<F>,
+    }
+
+    impl<F: PrimeField, const T: usize, const RATE: usize> Circuit<F> for TestSponge<F, T, RATE> {
+        type Config = MainGateConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
         }
 
-        // If last chunking is full apply another permutation for collution resistance
-        if padding_offset == 0 {
-            self.permutation(ctx, vec![])?;
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            MainGate::<F>::configure(meta)
         }
 
-        Ok(self.state.0[1].clone())
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut hasher = HasherChip::<F, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>::new(
+                        ctx, &self.spec, &config,
+                    )?;
+
+                    let mut outputs = vec![];
+                    for op in self.ops.iter() {
+                        match op {
+                            Op::Absorb(inputs) => {
+                                let inputs = inputs
+                                    .iter()
+                                    .map(|e| main_gate.assign_value(ctx, Value::known(*e)))
+                                    .collect::<Result<Vec<_>, Error>>()?;
+                                hasher.absorb(&inputs);
+                            }
+                            Op::Squeeze(n) => outputs.extend(hasher.squeeze_n(ctx, *n)?),
+                        }
+                    }
+                    assert_eq!(outputs.len(), self.expected.len());
+                    for (output, expected) in outputs.iter().zip(self.expected.iter()) {
+                        main_gate.assert_equal_to_constant(ctx, output, *expected)?;
+                    }
+
+                    // Default capacity is the variable length domain of the
+                    // reference hasher and other capacities separate domains
+                    let input = main_gate.assign_value(ctx, Value::known(F::ONE))?;
+                    let mut outputs = vec![];
+                    for capacity in [F::from_u128(1 << 64), F::from_u128(2 << 64)] {
+                        let mut hasher =
+                            HasherChip::<F, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>::new_with_capacity(
+                                ctx, &self.spec, &config, capacity,
+                            )?;
+                        hasher.absorb(&[input.clone()]);
+                        outputs.push(hasher.squeeze(ctx)?);
+                    }
+                    let mut hasher = HasherChip::<F, NUMBER_OF_LIMBS, BIT_LEN_LIMB, T, RATE>::new(
+                        ctx, &self.spec, &config,
+                    )?;
+                    hasher.absorb(&[input]);
+                    let output = hasher.squeeze(ctx)?;
+                    main_gate.assert_equal(ctx, &output, &outputs[0])?;
+                    main_gate.assert_not_equal(ctx, &output, &outputs[1])?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
     }
+
+    macro_rules! test {
+        ($RF:expr, $RP:expr, $T:expr, $RATE:expr) => {
+            paste! {
+                #[test]
+                fn [<test_sponge_ $RF _ $RP _ $T _ $RATE>]() {
+                    let mut rng = OsRng;
+                    let mut ref_hasher = Poseidon::<Fr, $T, $RATE>::new($RF, $RP);
+                    let spec = Spec::<Fr, $T, $RATE>::new($RF, $RP);
+
+                    // Interleave absorptions of lengths around multiples of
+                    // rate with single and multiple squeezes
+                    let mut ops = vec![];
+                    let mut expected = vec![];
+                    for _ in 0..6 {
+                        let inputs = (0..rng.gen_range(0..3 * $RATE))
+                            .map(|_| Fr::random(OsRng))
+                            .collect::<Vec<Fr>>();
+                        ref_hasher.update(&inputs[..]);
+                        ops.push(Op::Absorb(inputs));
+
+                        let n = rng.gen_range(0..3);
+                        expected.extend((0..n).map(|_| ref_hasher.squeeze()));
+                        ops.push(Op::Squeeze(n));
+                    }
+                    expected.push(ref_hasher.squeeze());
+                    ops.push(Op::Squeeze(1));
+
+                    let circuit = TestSponge::<Fr, $T, $RATE> {
+                        spec,
+                        ops,
+                        expected,
+                    };
+                    mock_prover_verify(&circuit, vec![vec![]]);
+                }
+            }
+        };
+    }
+
+    test!(8, 57, 3, 2);
+    test!(8, 57, 5, 4);
+    test!(8, 57, 9, 8);
 }
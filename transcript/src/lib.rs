@@ -1,10 +1,18 @@
+mod anemoi;
 mod hasher;
+mod native;
+mod permutation;
+mod rescue;
 mod transcript;
 
+pub use anemoi::{AnemoiChip, AnemoiSpec};
 pub use ecc;
 pub use ecc::halo2;
 pub use ecc::maingate;
 pub use hasher::HasherChip;
+pub use native::{PoseidonChallenge, PoseidonTranscript};
+pub use permutation::Permutation;
+pub use rescue::{RescuePrimeChip, RescuePrimeSpec};
 
 pub use crate::transcript::*;
 
@@ -0,0 +1,344 @@
+use crate::{
+    halo2::{
+        arithmetic::CurveAffine,
+        halo2curves::{
+            ff::{FromUniformBytes, PrimeField},
+            group::GroupEncoding,
+        },
+        transcript::{
+            EncodedChallenge, Transcript, TranscriptRead, TranscriptReadBuffer, TranscriptWrite,
+            TranscriptWriterBuffer,
+        },
+    },
+    transcript::{encode_integer, integer_challenge, PointRepresentation},
+};
+use poseidon::Poseidon;
+use std::{
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+
+/// `PoseidonChallenge` is the challenge squeezed from `PoseidonTranscript`
+/// used as is without any further encoding
+#[derive(Clone, Copy, Debug)]
+pub struct PoseidonChallenge<C: CurveAffine>(C::Scalar);
+
+impl<C: CurveAffine> EncodedChallenge<C> for PoseidonChallenge<C> {
+    type Input = C::Scalar;
+
+    fn new(challenge_input: &C::Scalar) -> Self {
+        PoseidonChallenge(*challenge_input)
+    }
+
+    fn get_scalar(&self) -> C::Scalar {
+        self.0
+    }
+}
+
+/// `PoseidonTranscript` is the native counterpart of `TranscriptChip`. Points
+/// and scalars are absorbed with the same `PointRepresentation` encoding and
+/// sponge so that challenges are reproduced exactly in circuit. Proof stream
+/// keeps the usual compressed point and canonical scalar encodings.
+#[derive(Debug, Clone)]
+pub struct PoseidonTranscript<
+    C: CurveAffine<ScalarExt = N>,
+    N: FromUniformBytes<64>,
+    E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+    S,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN: usize,
+    const T: usize,
+    const RATE: usize,
+    const R_F: usize,
+    const R_P: usize,
+> {
+    stream: S,
+    hasher: Poseidon<N, T, RATE>,
+    _marker: PhantomData<(C, E)>,
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        S,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > PoseidonTranscript<C, N, E, S, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    /// Constructs the transcript over a proof stream
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            hasher: Poseidon::new(R_F, R_P),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Absorbs a foreign field element in the same canonical limb encoding
+    /// of `TranscriptChip::write_integer`
+    pub fn common_integer<F: PrimeField>(&mut self, integer: F) {
+        self.hasher
+            .update(&encode_integer::<F, N, NUMBER_OF_LIMBS, BIT_LEN>(integer));
+    }
+
+    /// Squeezes a challenge in the foreign field as
+    /// `TranscriptChip::squeeze_integer` does. Challenge is truncated to
+    /// `integer_challenge_bit_len` bits which is a soundness parameter of
+    /// protocols using it
+    pub fn squeeze_integer<F: PrimeField>(&mut self) -> F {
+        integer_challenge::<F, N, BIT_LEN>(self.hasher.squeeze())
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        R: Read,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > PoseidonTranscript<C, N, E, R, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    /// Reads a foreign field element from the proof and absorbs it
+    pub fn read_integer<F: PrimeField>(&mut self) -> io::Result<F> {
+        let mut data = F::Repr::default();
+        self.stream.read_exact(data.as_mut())?;
+        let integer: F = Option::from(F::from_repr(data)).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "invalid field element encoding in proof",
+            )
+        })?;
+        self.common_integer(integer);
+        Ok(integer)
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        W: Write,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > PoseidonTranscript<C, N, E, W, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    /// Absorbs a foreign field element and writes it to the proof
+    pub fn write_integer<F: PrimeField>(&mut self, integer: F) -> io::Result<()> {
+        self.common_integer(integer);
+        self.stream.write_all(integer.to_repr().as_ref())
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        S,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > Transcript<C, PoseidonChallenge<C>>
+    for PoseidonTranscript<C, N, E, S, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    fn squeeze_challenge(&mut self) -> PoseidonChallenge<C> {
+        PoseidonChallenge::new(&self.hasher.squeeze())
+    }
+
+    fn common_point(&mut self, point: C) -> io::Result<()> {
+        let encoded = E::encode(point).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "cannot write points at infinity to the transcript",
+            )
+        })?;
+        self.hasher.update(&encoded[..]);
+        Ok(())
+    }
+
+    fn common_scalar(&mut self, scalar: N) -> io::Result<()> {
+        self.hasher.update(&[scalar]);
+        Ok(())
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        R: Read,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > TranscriptRead<C, PoseidonChallenge<C>>
+    for PoseidonTranscript<C, N, E, R, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    fn read_point(&mut self) -> io::Result<C> {
+        let mut compressed = C::Repr::default();
+        self.stream.read_exact(compressed.as_mut())?;
+        let point: C = Option::from(C::from_bytes(&compressed)).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::Other, "invalid point encoding in proof")
+        })?;
+        self.common_point(point)?;
+        Ok(point)
+    }
+
+    fn read_scalar(&mut self) -> io::Result<N> {
+        let mut data = <N as PrimeField>::Repr::default();
+        self.stream.read_exact(data.as_mut())?;
+        let scalar: N = Option::from(N::from_repr(data)).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                "invalid field element encoding in proof",
+            )
+        })?;
+        self.common_scalar(scalar)?;
+        Ok(scalar)
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        R: Read,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > TranscriptReadBuffer<R, C, PoseidonChallenge<C>>
+    for PoseidonTranscript<C, N, E, R, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    fn init(reader: R) -> Self {
+        Self::new(reader)
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        W: Write,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > TranscriptWrite<C, PoseidonChallenge<C>>
+    for PoseidonTranscript<C, N, E, W, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    fn write_point(&mut self, point: C) -> io::Result<()> {
+        self.common_point(point)?;
+        let compressed = point.to_bytes();
+        self.stream.write_all(compressed.as_ref())
+    }
+
+    fn write_scalar(&mut self, scalar: N) -> io::Result<()> {
+        self.common_scalar(scalar)?;
+        let data = scalar.to_repr();
+        self.stream.write_all(data.as_ref())
+    }
+}
+
+impl<
+        C: CurveAffine<ScalarExt = N>,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        W: Write,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        const R_F: usize,
+        const R_P: usize,
+    > TranscriptWriterBuffer<W, C, PoseidonChallenge<C>>
+    for PoseidonTranscript<C, N, E, W, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, R_F, R_P>
+{
+    fn init(writer: W) -> Self {
+        Self::new(writer)
+    }
+
+    fn finalize(self) -> W {
+        self.stream
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PoseidonTranscript;
+    use crate::curves::bn256::{Fq, Fr, G1Affine, G1};
+    use crate::halo2::halo2curves::ff::Field;
+    use crate::halo2::halo2curves::group::{Curve, Group};
+    use crate::halo2::transcript::{
+        EncodedChallenge, Transcript, TranscriptRead, TranscriptReadBuffer, TranscriptWrite,
+        TranscriptWriterBuffer,
+    };
+    use crate::transcript::{LimbRepresentation, NativeRepresentation, PointRepresentation};
+    use rand_core::OsRng;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    fn run<E: PointRepresentation<G1Affine, Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>() {
+        type NativeTranscript<E, S> =
+            PoseidonTranscript<G1Affine, Fr, E, S, NUMBER_OF_LIMBS, BIT_LEN_LIMB, 5, 4, 8, 57>;
+
+        let points = (0..3)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let integers = (0..3).map(|_| Fq::random(OsRng)).collect::<Vec<_>>();
+
+        let mut writer =
+            <NativeTranscript<E, Vec<u8>> as TranscriptWriterBuffer<_, _, _>>::init(vec![]);
+        let mut challenges = vec![];
+        let mut integer_challenges = vec![];
+        for ((point, scalar), integer) in points.iter().zip(scalars.iter()).zip(integers.iter()) {
+            writer.write_point(*point).unwrap();
+            writer.write_scalar(*scalar).unwrap();
+            challenges.push(writer.squeeze_challenge().get_scalar());
+            writer.write_integer(*integer).unwrap();
+            integer_challenges.push(writer.squeeze_integer::<Fq>());
+        }
+        assert!(writer.write_point(G1::identity().to_affine()).is_err());
+        let proof = writer.finalize();
+
+        let mut reader =
+            <NativeTranscript<E, &[u8]> as TranscriptReadBuffer<_, _, _>>::init(&proof[..]);
+        for (i, (point, scalar)) in points.iter().zip(scalars.iter()).enumerate() {
+            assert_eq!(reader.read_point().unwrap(), *point);
+            assert_eq!(reader.read_scalar().unwrap(), *scalar);
+            assert_eq!(reader.squeeze_challenge().get_scalar(), challenges[i]);
+            assert_eq!(reader.read_integer::<Fq>().unwrap(), integers[i]);
+            assert_eq!(reader.squeeze_integer::<Fq>(), integer_challenges[i]);
+        }
+    }
+
+    #[test]
+    fn test_native_transcript() {
+        run::<LimbRepresentation>();
+        run::<NativeRepresentation>();
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    halo2::{halo2curves::ff::PrimeField, plonk::Error},
+    maingate::{modulus, AssignedValue, MainGate, MainGateInstructions, RegionCtx, Term},
+};
+use num_bigint::BigUint as big_uint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// `Permutation` is implemented by chips that constrain a sponge permutation
+/// over `T` sized state of native field elements with `RATE` words of rate.
+/// Absorbing and squeezing are provided on top of it so that
+/// `TranscriptChip` can be used with any of the permutations.
+///
+/// Inputs are absorbed in `RATE` sized chunks and the last chunk is padded
+/// with a single one. If the last chunk is full or nothing is absorbed an
+/// extra chunk that only contains the padding is absorbed.
+pub trait Permutation<F: PrimeField, const T: usize, const RATE: usize> {
+    /// Returns absorbation line of elements waiting for the next squeeze
+    fn absorbing(&mut self) -> &mut Vec<AssignedValue<F>>;
+
+    /// Adds at most `RATE` inputs to the rate part of the state and pads
+    /// with a single one if there are less than `RATE` inputs. Then
+    /// constrains the permutation while mutating the state
+    fn permutation(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: Vec<AssignedValue<F>>,
+    ) -> Result<(), Error>;
+
+    /// Returns the word of the state that is squeezed
+    fn output(&self) -> AssignedValue<F>;
+
+    /// Appends field elements to the absorbation line. It won't perform
+    /// permutation here
+    fn absorb(&mut self, elements: &[AssignedValue<F>]) {
+        self.absorbing().extend_from_slice(elements);
+    }
+
+    /// Absorbs pending elements with padding and returns a single output.
+    /// State is preserved so absorbing and squeezing can be interleaved.
+    /// Squeezing with nothing absorbed applies a padding only permutation
+    fn squeeze(&mut self, ctx: &mut RegionCtx<'_, F>) -> Result<AssignedValue<F>, Error> {
+        // Get elements to be hashed and flush the input que
+        let input_elements = std::mem::take(self.absorbing());
+
+        let mut padding_offset = 0;
+        // Apply permutation to `RATE` sized chunks
+        for chunk in input_elements.chunks(RATE) {
+            padding_offset = RATE - chunk.len();
+            self.permutation(ctx, chunk.to_vec())?;
+        }
+
+        // If last chunking is full apply another permutation for collution resistance
+        if padding_offset == 0 {
+            self.permutation(ctx, vec![])?;
+        }
+
+        Ok(self.output())
+    }
+
+    /// Squeezes `n` outputs where each is followed by a padding only
+    /// permutation as in `n` consecutive calls of `squeeze`
+    fn squeeze_n(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        n: usize,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        (0..n).map(|_| self.squeeze(ctx)).collect()
+    }
+}
+
+/// Adds inputs to the given rate words and the padding to the next one
+pub(crate) fn add_inputs_with_padding<F: PrimeField>(
+    ctx: &mut RegionCtx<'_, F>,
+    main_gate: &MainGate<F>,
+    rate: &mut [AssignedValue<F>],
+    inputs: &[AssignedValue<F>],
+) -> Result<(), Error> {
+    assert!(inputs.len() <= rate.len());
+    for (word, input) in rate.iter_mut().zip(inputs.iter()) {
+        *word = main_gate.add(ctx, word, input)?;
+    }
+    if let Some(word) = rate.get_mut(inputs.len()) {
+        *word = main_gate.add_constant(ctx, word, F::ONE)?;
+    }
+    Ok(())
+}
+
+/// Returns the smallest `alpha >= 3` such that `x -> x^alpha` is a
+/// permutation of `F` together with limbs of `1/alpha mod p - 1`
+pub(crate) fn sbox_exponents<F: PrimeField>() -> (u64, Vec<u64>) {
+    let p_minus_one = modulus::<F>() - 1u64;
+    let alpha = (3u64..)
+        .find(|alpha| p_minus_one.gcd(&big_uint::from(*alpha)).is_one())
+        .unwrap();
+    let alpha_inv = (1..alpha)
+        .map(|k| &p_minus_one * k + 1u64)
+        .find(|e| (e % alpha).is_zero())
+        .unwrap()
+        / alpha;
+    (alpha, alpha_inv.to_u64_digits())
+}
+
+/// Returns `x^alpha`
+pub(crate) fn pow<F: PrimeField>(
+    ctx: &mut RegionCtx<'_, F>,
+    main_gate: &MainGate<F>,
+    x: &AssignedValue<F>,
+    alpha: u64,
+) -> Result<AssignedValue<F>, Error> {
+    assert!(alpha > 1);
+    let mut acc = x.clone();
+    for i in (0..63 - alpha.leading_zeros() as usize).rev() {
+        acc = main_gate.mul(ctx, &acc, &acc)?;
+        if (alpha >> i) & 1 == 1 {
+            acc = main_gate.mul(ctx, &acc, x)?;
+        }
+    }
+    Ok(acc)
+}
+
+/// Returns `x^(1/alpha)` where `alpha_inv` is the inverse of `alpha` in the
+/// multiplicative group. Result is witnessed and constrained with `y^alpha =
+/// x`
+pub(crate) fn pow_inv<F: PrimeField>(
+    ctx: &mut RegionCtx<'_, F>,
+    main_gate: &MainGate<F>,
+    x: &AssignedValue<F>,
+    alpha: u64,
+    alpha_inv: &[u64],
+) -> Result<AssignedValue<F>, Error> {
+    let y = main_gate.assign_value(ctx, x.value().map(|x| x.pow_vartime(alpha_inv)))?;
+    let y_alpha = pow(ctx, main_gate, &y, alpha)?;
+    main_gate.assert_equal(ctx, &y_alpha, x)?;
+    Ok(y)
+}
+
+/// Applies `state <- matrix * state + constants`
+pub(crate) fn apply_matrix<F: PrimeField, const T: usize>(
+    ctx: &mut RegionCtx<'_, F>,
+    main_gate: &MainGate<F>,
+    state: &mut [AssignedValue<F>; T],
+    matrix: &[[F; T]; T],
+    constants: Option<&[F; T]>,
+) -> Result<(), Error> {
+    let new_state = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let terms = state
+                .iter()
+                .zip(row.iter())
+                .map(|(e, word)| Term::Assigned(e, *word))
+                .collect::<Vec<Term<F>>>();
+            let constant = constants.map(|constants| constants[i]).unwrap_or(F::ZERO);
+            main_gate.compose(ctx, &terms[..], constant)
+        })
+        .collect::<Result<Vec<AssignedValue<F>>, Error>>()?;
+    *state = new_state.try_into().unwrap();
+    Ok(())
+}
@@ -0,0 +1,435 @@
+use crate::{
+    halo2::{halo2curves::ff::PrimeField, plonk::Error},
+    maingate::{
+        big_to_fe, modulus, AssignedValue, MainGate, MainGateConfig, MainGateInstructions,
+        RegionCtx,
+    },
+    permutation::{
+        add_inputs_with_padding, apply_matrix, pow, pow_inv, sbox_exponents, Permutation,
+    },
+};
+use num_bigint::BigUint as big_uint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// `RescuePrimeSpec` holds Rescue-Prime parameters. Round constants are
+/// sampled from SHAKE256 and the MDS matrix is derived from a Vandermonde
+/// matrix following the Rescue-Prime specification.
+///
+/// Outputs are not checked against vectors of the Rescue-Prime reference
+/// implementation, so this is not an interoperability primitive: digests and
+/// challenges must not be expected to match other Rescue-Prime instances
+/// until such vectors are added
+#[derive(Debug, Clone)]
+pub struct RescuePrimeSpec<F: PrimeField, const T: usize, const RATE: usize> {
+    alpha: u64,
+    alpha_inv: Vec<u64>,
+    mds: [[F; T]; T],
+    // Two sets of constants per round
+    constants: Vec<[F; T]>,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> RescuePrimeSpec<F, T, RATE> {
+    /// Generates parameters for given number of rounds and security level
+    /// in bits. `primitive_element` is the generator of the multiplicative
+    /// group that is used in MDS matrix generation
+    pub fn new(number_of_rounds: usize, security_level: usize, primitive_element: F) -> Self {
+        assert!(RATE > 0 && RATE < T);
+        let (alpha, alpha_inv) = sbox_exponents::<F>();
+        let mds = Self::generate_mds(primitive_element);
+        let constants = Self::generate_constants(number_of_rounds, security_level);
+
+        Self {
+            alpha,
+            alpha_inv,
+            mds,
+            constants,
+        }
+    }
+
+    /// Returns the transpose of the right half of the reduced row echelon
+    /// form of `T x 2T` Vandermonde matrix
+    fn generate_mds(primitive_element: F) -> [[F; T]; T] {
+        let mut vandermonde: Vec<Vec<F>> = (0..T)
+            .map(|i| {
+                (0..2 * T)
+                    .map(|j| primitive_element.pow_vartime([(i * j) as u64]))
+                    .collect()
+            })
+            .collect();
+
+        for col in 0..T {
+            let pivot = (col..T)
+                .find(|row| !bool::from(vandermonde[*row][col].is_zero()))
+                .unwrap();
+            vandermonde.swap(col, pivot);
+
+            let inv = vandermonde[col][col].invert().unwrap();
+            vandermonde[col].iter_mut().for_each(|e| *e *= inv);
+
+            let pivot_row = vandermonde[col].clone();
+            for (i, row) in vandermonde.iter_mut().enumerate() {
+                if i != col {
+                    let factor = row[col];
+                    for (e, p) in row.iter_mut().zip(pivot_row.iter()) {
+                        *e -= factor * p;
+                    }
+                }
+            }
+        }
+
+        let mut mds = [[F::ZERO; T]; T];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, e) in row.iter_mut().enumerate() {
+                *e = vandermonde[j][T + i];
+            }
+        }
+        mds
+    }
+
+    /// Reads round constants from SHAKE256 seeded with the parameter string
+    fn generate_constants(number_of_rounds: usize, security_level: usize) -> Vec<[F; T]> {
+        let seed = format!(
+            "Rescue-XLIX({},{},{},{})",
+            modulus::<F>(),
+            T,
+            T - RATE,
+            security_level
+        );
+        let mut shake = Shake256::default();
+        shake.update(seed.as_bytes());
+        let mut reader = shake.finalize_xof();
+
+        let bytes_per_int = (F::NUM_BITS as usize + 7) / 8 + 1;
+        (0..2 * number_of_rounds)
+            .map(|_| {
+                let mut constants = [F::ZERO; T];
+                for constant in constants.iter_mut() {
+                    let mut bytes = vec![0u8; bytes_per_int];
+                    reader.read(&mut bytes);
+                    *constant = big_to_fe(big_uint::from_bytes_le(&bytes));
+                }
+                constants
+            })
+            .collect()
+    }
+
+    /// Returns number of rounds
+    pub fn number_of_rounds(&self) -> usize {
+        self.constants.len() / 2
+    }
+
+    /// Returns MDS matrix
+    pub fn mds(&self) -> &[[F; T]; T] {
+        &self.mds
+    }
+
+    /// Returns round constants, two sets per round
+    pub fn constants(&self) -> &[[F; T]] {
+        &self.constants
+    }
+
+    fn apply_mds(&self, state: &mut [F; T], constants: &[F; T]) {
+        let mut new_state = *constants;
+        for (new_word, row) in new_state.iter_mut().zip(self.mds.iter()) {
+            for (word, e) in state.iter().zip(row.iter()) {
+                *new_word += *word * e;
+            }
+        }
+        *state = new_state;
+    }
+
+    /// Applies the permutation to the given state out of circuit
+    pub fn permute(&self, state: &mut [F; T]) {
+        for constants in self.constants.chunks(2) {
+            state
+                .iter_mut()
+                .for_each(|word| *word = word.pow_vartime([self.alpha]));
+            self.apply_mds(state, &constants[0]);
+            state
+                .iter_mut()
+                .for_each(|word| *word = word.pow_vartime(&self.alpha_inv));
+            self.apply_mds(state, &constants[1]);
+        }
+    }
+}
+
+/// `RescuePrimeChip` constrains Rescue-Prime sponge where the first `RATE`
+/// words of the state are rate and the first word is squeezed. It matches
+/// `RescuePrimeSpec::permute` but is not checked against other Rescue-Prime
+/// implementations
+#[derive(Debug, Clone)]
+pub struct RescuePrimeChip<F: PrimeField, const T: usize, const RATE: usize> {
+    state: [AssignedValue<F>; T],
+    absorbing: Vec<AssignedValue<F>>,
+    spec: RescuePrimeSpec<F, T, RATE>,
+    main_gate_config: MainGateConfig,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> RescuePrimeChip<F, T, RATE> {
+    /// Constructs new rescue chip with all zero initial state
+    pub fn new(
+        ctx: &mut RegionCtx<'_, F>,
+        spec: &RescuePrimeSpec<F, T, RATE>,
+        main_gate_config: &MainGateConfig,
+    ) -> Result<Self, Error> {
+        let main_gate = MainGate::<_>::new(main_gate_config.clone());
+        let initial_state = (0..T)
+            .map(|_| main_gate.assign_constant(ctx, F::ZERO))
+            .collect::<Result<Vec<AssignedValue<F>>, Error>>()?;
+
+        Ok(Self {
+            state: initial_state.try_into().unwrap(),
+            absorbing: vec![],
+            spec: spec.clone(),
+            main_gate_config: main_gate_config.clone(),
+        })
+    }
+
+    /// Construct main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        MainGate::<_>::new(self.main_gate_config.clone())
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Permutation<F, T, RATE>
+    for RescuePrimeChip<F, T, RATE>
+{
+    fn absorbing(&mut self) -> &mut Vec<AssignedValue<F>> {
+        &mut self.absorbing
+    }
+
+    fn permutation(
+        &mut self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: Vec<AssignedValue<F>>,
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let spec = &self.spec;
+        add_inputs_with_padding(ctx, &main_gate, &mut self.state[..RATE], &inputs)?;
+
+        for constants in spec.constants.chunks(2) {
+            for word in self.state.iter_mut() {
+                *word = pow(ctx, &main_gate, word, spec.alpha)?;
+            }
+            apply_matrix(
+                ctx,
+                &main_gate,
+                &mut self.state,
+                &spec.mds,
+                Some(&constants[0]),
+            )?;
+            for word in self.state.iter_mut() {
+                *word = pow_inv(ctx, &main_gate, word, spec.alpha, &spec.alpha_inv)?;
+            }
+            apply_matrix(
+                ctx,
+                &main_gate,
+                &mut self.state,
+                &spec.mds,
+                Some(&constants[1]),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn output(&self) -> AssignedValue<F> {
+        self.state[0].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RescuePrimeChip, RescuePrimeSpec};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::halo2curves::ff::Field;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::{
+        big_to_fe, mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RegionCtx,
+    };
+    use crate::permutation::Permutation;
+    use num_bigint::BigUint as big_uint;
+    use rand_core::OsRng;
+
+    fn from_hex(e: &str) -> Fr {
+        big_to_fe(big_uint::parse_bytes(e.as_bytes(), 16).unwrap())
+    }
+
+    fn spec<const T: usize, const RATE: usize>(
+        number_of_rounds: usize,
+    ) -> RescuePrimeSpec<Fr, T, RATE> {
+        RescuePrimeSpec::new(number_of_rounds, 128, Fr::from(5))
+    }
+
+    // Sponge out of circuit with the same padding rule
+    fn hash<const T: usize, const RATE: usize>(
+        spec: &RescuePrimeSpec<Fr, T, RATE>,
+        inputs: &[Fr],
+    ) -> Fr {
+        let mut padded = inputs.to_vec();
+        padded.push(Fr::ONE);
+        padded.resize((padded.len() + RATE - 1) / RATE * RATE, Fr::ZERO);
+
+        let mut state = [Fr::ZERO; T];
+        for chunk in padded.chunks(RATE) {
+            for (word, e) in state.iter_mut().zip(chunk.iter()) {
+                *word += e;
+            }
+            spec.permute(&mut state);
+        }
+        state[0]
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<const T: usize, const RATE: usize> {
+        spec: RescuePrimeSpec<Fr, T, RATE>,
+        inputs: Value<Vec<Fr>>,
+        n: usize,
+        expected: Value<Fr>,
+    }
+
+    impl<const T: usize, const RATE: usize> Circuit<Fr> for TestCircuit<T, RATE> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let main_gate_config = MainGate::<Fr>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut chip = RescuePrimeChip::<Fr, T, RATE>::new(
+                        ctx,
+                        &self.spec,
+                        &config.main_gate_config,
+                    )?;
+
+                    for e in self.inputs.as_ref().transpose_vec(self.n) {
+                        let e = main_gate.assign_value(ctx, e.map(|e| *e))?;
+                        chip.absorb(&[e]);
+                    }
+                    let output = chip.squeeze(ctx)?;
+                    let expected = main_gate.assign_value(ctx, self.expected)?;
+                    main_gate.assert_equal(ctx, &output, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    // Expected values below are recorded from this implementation to catch
+    // regressions. They are not external test vectors
+
+    #[test]
+    fn test_rescue_prime_known_permutation() {
+        let spec = spec::<3, 2>(14);
+        assert_eq!(spec.number_of_rounds(), 14);
+        assert_eq!(spec.mds()[0][0], Fr::from(0x7d));
+        assert_eq!(
+            spec.constants()[0][0],
+            from_hex("241214b64e37a42dddc49216b6433fe75e4af3533a8c8961def18b459420ce96")
+        );
+
+        let mut state = [Fr::from(0), Fr::from(1), Fr::from(2)];
+        spec.permute(&mut state);
+        assert_eq!(
+            state,
+            [
+                from_hex("dc30ccd5d64e5bea071e99087ef86d433eb156aa0500a823298f9bb05328bd2"),
+                from_hex("189893368d5815608c56e44cc67f7e821e093bb6254a0553f9ff69f4d99debc8"),
+                from_hex("1acafc768221448ebc51fa2cd1e3c9b2044a0c04f3509d833b0a82c7e3462610"),
+            ]
+        );
+
+        let spec = spec::<5, 4>(9);
+        let mut state = [0, 1, 2, 3, 4].map(Fr::from);
+        spec.permute(&mut state);
+        assert_eq!(
+            state[0],
+            from_hex("2cd8fc0509157f1faf9f730aeb40f6eb745872e281675e39a54b12f60b504dae")
+        );
+    }
+
+    #[test]
+    fn test_rescue_prime_known_hash() {
+        fn run<const T: usize, const RATE: usize>(number_of_rounds: usize, expected: &[&str]) {
+            let spec = spec::<T, RATE>(number_of_rounds);
+            for (n, expected) in expected.iter().enumerate() {
+                let inputs: Vec<Fr> = (1..=n as u64).map(Fr::from).collect();
+                let expected = from_hex(expected);
+                assert_eq!(hash(&spec, &inputs), expected);
+
+                let circuit = TestCircuit::<T, RATE> {
+                    spec: spec.clone(),
+                    inputs: Value::known(inputs),
+                    n,
+                    expected: Value::known(expected),
+                };
+                mock_prover_verify(&circuit, vec![vec![]]);
+            }
+        }
+
+        run::<3, 2>(
+            14,
+            &[
+                "1a384773dde306fa6c183a08dd23e4524afb22e2f49f1bb4ba8409832dd3ac75",
+                "244427b72c2d0755b26ee1361f2ad7eb7f52c10f849d3f071bf76e4c2b300afd",
+                "2c1e496c13685c6475ced933fa87ee0fec53d9d9f99442a0342c488237287659",
+                "170d1571f3c534351e1455a3c04b07131a97af5353c387555971562abab11efc",
+                "1f699a85a90add6e2ea2fd231b66dde675f69479ab7ac8c3c5502fb68ed24b22",
+            ],
+        );
+        run::<5, 4>(
+            9,
+            &[
+                "b1abb5d611d83ad710334c86e0681f301ded5113466aa6234712a6d8367278",
+                "1b6f54e4b10d5335af8c98bf8bedafdcbf7d9ea9719918c85cecddf353154481",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rescue_prime_random_inputs() {
+        let spec = spec::<3, 2>(14);
+        for n in 0..7 {
+            let inputs: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let expected = hash(&spec, &inputs);
+            let circuit = TestCircuit::<3, 2> {
+                spec: spec.clone(),
+                inputs: Value::known(inputs),
+                n,
+                expected: Value::known(expected),
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+        }
+    }
+}
@@ -2,17 +2,21 @@ use crate::{
     halo2::{arithmetic::CurveAffine, halo2curves::ff::PrimeField, plonk::Error},
     hasher::HasherChip,
     maingate::{AssignedValue, RegionCtx},
+    permutation::Permutation,
 };
 use ecc::{
     halo2::circuit::Chip,
-    maingate::{big_to_fe, decompose, fe_to_big},
-    AssignedPoint, BaseFieldEccChip,
+    integer::{rns::Integer, AssignedInteger, IntegerChip, IntegerInstructions, Range},
+    maingate::{big_to_fe, decompose, fe_to_big, MainGateInstructions, Term},
+    AssignedPoint, BaseFieldEccChip, GeneralEccChip,
 };
 use poseidon::Spec;
 
-/// `PointRepresentation` will encode point with an implemented strategy
+/// `PointRepresentation` will encode point with an implemented strategy.
+/// Points are given with the integer chip of their base field so that both
+/// points of `BaseFieldEccChip` and `GeneralEccChip` can be encoded
 pub trait PointRepresentation<
-    C: CurveAffine<ScalarExt = N>,
+    C: CurveAffine,
     N: PrimeField,
     const NUMBER_OF_LIMBS: usize,
     const BIT_LEN_LIMB: usize,
@@ -20,7 +24,7 @@ pub trait PointRepresentation<
 {
     fn encode_assigned(
         ctx: &mut RegionCtx<'_, N>,
-        ecc_chip: &BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        base_field_chip: &IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedValue<N>>, Error>;
 
@@ -32,21 +36,17 @@ pub trait PointRepresentation<
 #[derive(Default)]
 pub struct LimbRepresentation;
 
-impl<
-        C: CurveAffine<ScalarExt = N>,
-        N: PrimeField,
-        const NUMBER_OF_LIMBS: usize,
-        const BIT_LEN_LIMB: usize,
-    > PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> for LimbRepresentation
+impl<C: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> for LimbRepresentation
 {
     fn encode_assigned(
         ctx: &mut RegionCtx<'_, N>,
-        ecc_chip: &BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        base_field_chip: &IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedValue<N>>, Error> {
         let mut encoded: Vec<AssignedValue<N>> =
             point.x().limbs().iter().map(|limb| limb.into()).collect();
-        encoded.push(ecc_chip.sign(ctx, point)?);
+        encoded.push(base_field_chip.sign(ctx, point.y())?);
         Ok(encoded)
     }
 
@@ -64,7 +64,9 @@ impl<
     }
 }
 
-/// `NativeRepresentation` encodes point as `[native(x),  native(y)]`
+/// `NativeRepresentation` encodes point as `[native(x),  native(y)]`. It is
+/// only available for curves whose scalar field is the native field, points
+/// of emulated curves must be encoded with `LimbRepresentation`
 #[derive(Default)]
 pub struct NativeRepresentation;
 
@@ -77,7 +79,7 @@ impl<
 {
     fn encode_assigned(
         _: &mut RegionCtx<'_, N>,
-        _: &BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        _: &IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedValue<N>>, Error> {
         Ok(vec![point.x().native().clone(), point.y().native().clone()])
@@ -96,18 +98,110 @@ impl<
     }
 }
 
+/// Returns bit length of challenges squeezed into the foreign field `W`,
+/// which is the largest length `assign_integer_challenge` accepts. Truncated
+/// challenge is canonical in `W` and its limbs fit in the native field.
+///
+/// This is a soundness parameter: integer challenges are sampled from
+/// $2^{bit\_len}$ values rather than from the whole of `W`, so a protocol that
+/// relies on a random challenge has soundness error of about
+/// $d / 2^{bit\_len}$ instead of $d / |W|$ for a polynomial identity of
+/// degree $d$. For example it is 204 bits for the secp256k1 scalar field with
+/// 68 bit limbs over BN254
+pub fn integer_challenge_bit_len<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>() -> usize
+{
+    let native_bit_len = ((N::NUM_BITS as usize - 1) / BIT_LEN_LIMB) * BIT_LEN_LIMB;
+    std::cmp::min(W::CAPACITY as usize, native_bit_len)
+}
+
+/// Encodes a foreign field element into canonical limbs as
+/// `TranscriptChip::write_integer` absorbs it
+pub fn encode_integer<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(
+    integer: W,
+) -> Vec<N> {
+    decompose(integer, NUMBER_OF_LIMBS, BIT_LEN_LIMB)
+        .into_iter()
+        .map(|limb| big_to_fe(fe_to_big(limb)))
+        .collect()
+}
+
+/// Assigns the `bit_len` least significant bits of the canonical
+/// decomposition of a native challenge as an integer in the foreign field
+/// `W`. Integer is bound to the truncated challenge through its native value,
+/// so `bit_len` must not exceed `integer_challenge_bit_len`
+pub fn assign_integer_challenge<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(
+    ctx: &mut RegionCtx<'_, N>,
+    integer_chip: &IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    challenge: &AssignedValue<N>,
+    bit_len: usize,
+) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+    assert!(bit_len <= integer_challenge_bit_len::<W, N, BIT_LEN_LIMB>());
+    let main_gate = integer_chip.main_gate();
+    let number_of_limbs = (bit_len + BIT_LEN_LIMB - 1) / BIT_LEN_LIMB;
+    assert!(number_of_limbs <= NUMBER_OF_LIMBS);
+
+    let bits = main_gate.to_bits_canonical(ctx, challenge)?;
+    let mut base = N::ONE;
+    let terms: Vec<Term<N>> = bits
+        .iter()
+        .take(bit_len)
+        .map(|bit| {
+            let term = Term::Assigned(bit, base);
+            base = base.double();
+            term
+        })
+        .collect();
+    let truncated = main_gate.compose(ctx, &terms, N::ZERO)?;
+
+    let integer = truncated
+        .value()
+        .map(|truncated| Integer::from_big(fe_to_big(*truncated), integer_chip.rns()));
+    let integer = integer_chip.assign_integer(ctx, integer.into(), Range::Remainder)?;
+    for limb in integer.limbs().iter().skip(number_of_limbs) {
+        main_gate.assert_zero(ctx, limb.as_ref())?;
+    }
+    main_gate.assert_equal(ctx, integer.native(), &truncated)?;
+
+    Ok(integer)
+}
+
+/// Maps a native challenge into the foreign field `W` as
+/// `TranscriptChip::squeeze_integer` does
+pub fn integer_challenge<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+    challenge: N,
+) -> W {
+    let bit_len = integer_challenge_bit_len::<W, N, BIT_LEN_LIMB>();
+    let challenge = fe_to_big(challenge);
+    let high = (challenge.clone() >> bit_len) << bit_len;
+    big_to_fe(challenge - high)
+}
+
+/// `TranscriptChip` constrains a transcript over the sponge of permutation
+/// `P` which is Poseidon `HasherChip` by default. Points are either assigned
+/// with `BaseFieldEccChip` or with `GeneralEccChip` when the curve is emulated
 #[derive(Clone, Debug)]
 pub struct TranscriptChip<
-    C: CurveAffine<ScalarExt = N>,
+    C: CurveAffine,
     N: PrimeField,
     E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
     const NUMBER_OF_LIMBS: usize,
     const BIT_LEN: usize,
     const T: usize,
     const RATE: usize,
+    P: Permutation<N, T, RATE> = HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN, T, RATE>,
 > {
-    ecc_chip: BaseFieldEccChip<C, NUMBER_OF_LIMBS, BIT_LEN>,
-    hasher_chip: HasherChip<N, NUMBER_OF_LIMBS, BIT_LEN, T, RATE>,
+    base_field_chip: IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN>,
+    hasher_chip: P,
     _point_repr: E,
 }
 
@@ -131,16 +225,71 @@ impl<
         let main_gate = ecc_chip.main_gate();
         let main_gate_config = main_gate.config();
         let hasher_chip = HasherChip::new(ctx, spec, main_gate_config)?;
-        Ok(Self {
-            ecc_chip,
+        Ok(Self::new_with_permutation(
+            ecc_chip.integer_chip().clone(),
             hasher_chip,
             _point_repr,
-        })
+        ))
+    }
+}
+
+impl<
+        C: CurveAffine,
+        N: PrimeField,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+    > TranscriptChip<C, N, E, NUMBER_OF_LIMBS, BIT_LEN, T, RATE>
+{
+    /// Constructs the transcript chip for points of an emulated curve
+    pub fn new_general(
+        ctx: &mut RegionCtx<'_, N>,
+        spec: &Spec<N, T, RATE>,
+        ecc_chip: GeneralEccChip<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        _point_repr: E,
+    ) -> Result<Self, Error> {
+        let main_gate = ecc_chip.main_gate();
+        let main_gate_config = main_gate.config();
+        let hasher_chip = HasherChip::new(ctx, spec, main_gate_config)?;
+        Ok(Self::new_with_permutation(
+            ecc_chip.base_field_chip().clone(),
+            hasher_chip,
+            _point_repr,
+        ))
+    }
+}
+
+impl<
+        C: CurveAffine,
+        N: PrimeField,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN: usize,
+        const T: usize,
+        const RATE: usize,
+        P: Permutation<N, T, RATE>,
+    > TranscriptChip<C, N, E, NUMBER_OF_LIMBS, BIT_LEN, T, RATE, P>
+{
+    /// Constructs the transcript chip over an already constructed
+    /// permutation chip. `base_field_chip` is the integer chip of the base
+    /// field of the curve that points are assigned with
+    pub fn new_with_permutation(
+        base_field_chip: IntegerChip<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        hasher_chip: P,
+        _point_repr: E,
+    ) -> Self {
+        Self {
+            base_field_chip,
+            hasher_chip,
+            _point_repr,
+        }
     }
 
     /// Write scalar to the transcript
     pub fn write_scalar(&mut self, scalar: &AssignedValue<N>) {
-        self.hasher_chip.update(&[scalar.clone()]);
+        self.hasher_chip.absorb(&[scalar.clone()]);
     }
 
     /// Write point to the transcript
@@ -149,42 +298,101 @@ impl<
         ctx: &mut RegionCtx<'_, N>,
         point: &AssignedPoint<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN>,
     ) -> Result<(), Error> {
-        let encoded = E::encode_assigned(ctx, &self.ecc_chip, point)?;
-        self.hasher_chip.update(&encoded[..]);
+        let encoded = E::encode_assigned(ctx, &self.base_field_chip, point)?;
+        self.hasher_chip.absorb(&encoded[..]);
         Ok(())
     }
 
+    /// Write foreign field integer to the transcript. Integer is reduced and
+    /// its limbs are absorbed in canonical form
+    pub fn write_integer<W: PrimeField>(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer_chip: &IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN>,
+    ) -> Result<(), Error> {
+        let integer = &integer_chip.reduce(ctx, integer)?;
+        integer_chip.assert_in_field(ctx, integer)?;
+        let limbs: Vec<AssignedValue<N>> = integer.limbs().iter().map(|limb| limb.into()).collect();
+        self.hasher_chip.absorb(&limbs[..]);
+        Ok(())
+    }
+
+    /// Constrain squeezing new challenge as a foreign field integer. Challenge
+    /// is truncated to `integer_challenge_bit_len` least significant bits of
+    /// its canonical decomposition and is not reduced modulo `W`, so it
+    /// carries only `integer_challenge_bit_len` bits of entropy which bounds
+    /// the soundness of protocols using it
+    pub fn squeeze_integer<W: PrimeField>(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer_chip: &IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN>, Error> {
+        let challenge = self.hasher_chip.squeeze(ctx)?;
+        assign_integer_challenge(
+            ctx,
+            integer_chip,
+            &challenge,
+            integer_challenge_bit_len::<W, N, BIT_LEN>(),
+        )
+    }
+
     // Constrain squeezing new challenge
     pub fn squeeze(&mut self, ctx: &mut RegionCtx<'_, N>) -> Result<AssignedValue<N>, Error> {
-        self.hasher_chip.hash(ctx)
+        self.hasher_chip.squeeze(ctx)
+    }
+
+    // Constrain squeezing `n` new challenges
+    pub fn squeeze_n(
+        &mut self,
+        ctx: &mut RegionCtx<'_, N>,
+        n: usize,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        self.hasher_chip.squeeze_n(ctx, n)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::curves::bn256::{Fq, Fr, G1Affine, G1};
+    use crate::curves::pasta::{EqAffine as Vesta, Fp as PastaFp};
+    use crate::curves::secp256k1::Secp256k1Affine as Secp256k1;
     use crate::halo2::circuit::Layouter;
     use crate::halo2::circuit::SimpleFloorPlanner;
-    use crate::halo2::halo2curves::ff::{Field, PrimeField};
+    use crate::halo2::halo2curves::ff::{Field, FromUniformBytes, PrimeField};
     use crate::halo2::plonk::Error;
     use crate::halo2::plonk::{Circuit, ConstraintSystem};
+    use crate::maingate::big_to_fe;
     use crate::maingate::mock_prover_verify;
     use crate::maingate::MainGate;
     use crate::maingate::MainGateConfig;
     use crate::maingate::{MainGateInstructions, RegionCtx};
-    use crate::transcript::LimbRepresentation;
-    use crate::TranscriptChip;
+    use crate::transcript::{
+        encode_integer, integer_challenge, integer_challenge_bit_len, LimbRepresentation,
+        NativeRepresentation, PointRepresentation,
+    };
+    use crate::{
+        Permutation, PoseidonTranscript, RescuePrimeChip, RescuePrimeSpec, TranscriptChip,
+    };
     use ecc::halo2::arithmetic::CurveAffine;
     use ecc::halo2::circuit::Value;
-    use ecc::integer::rns::Rns;
+    use ecc::halo2::halo2curves::group::{Curve, Group};
+    use ecc::halo2::transcript::{
+        EncodedChallenge, Transcript, TranscriptWrite, TranscriptWriterBuffer,
+    };
+    use ecc::integer::rns::{Integer, Rns};
+    use ecc::integer::{IntegerInstructions, Range};
     use ecc::maingate::RangeChip;
     use ecc::maingate::RangeConfig;
     use ecc::maingate::RangeInstructions;
-    use ecc::BaseFieldEccChip;
     use ecc::EccConfig;
+    use ecc::{BaseFieldEccChip, GeneralEccChip};
+    use num_bigint::BigUint as big_uint;
     use paste::paste;
     use poseidon::Poseidon;
     use poseidon::Spec;
     use rand_core::OsRng;
+    use std::marker::PhantomData;
 
     const NUMBER_OF_LIMBS: usize = 4;
     const BIT_LEN_LIMB: usize = 68;
@@ -329,6 +537,455 @@ mod tests {
         };
     }
 
+    struct TestCircuitNativeTranscript<E> {
+        spec: Spec<Fr, 5, 4>,
+        points: Vec<G1Affine>,
+        scalars: Vec<Fr>,
+        challenges: Vec<Fr>,
+        // Integers are written as sums to cover unreduced inputs
+        integers: Vec<(Fq, Fq)>,
+        integer_challenges: Vec<Fq>,
+        _marker: PhantomData<E>,
+    }
+
+    impl<E: PointRepresentation<G1Affine, Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> Circuit<Fr>
+        for TestCircuitNativeTranscript<E>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            TestCircuitConfig::new::<G1Affine>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+            let ecc_chip = BaseFieldEccChip::<G1Affine, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+            let integer_chip = ecc_chip.integer_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut transcript_chip =
+                        TranscriptChip::<_, _, _, NUMBER_OF_LIMBS, BIT_LEN_LIMB, 5, 4>::new(
+                            ctx,
+                            &self.spec,
+                            ecc_chip.clone(),
+                            E::default(),
+                        )?;
+
+                    for (i, (point, scalar)) in
+                        self.points.iter().zip(self.scalars.iter()).enumerate()
+                    {
+                        let point = ecc_chip.assign_point(ctx, Value::known(*point))?;
+                        let scalar = main_gate.assign_value(ctx, Value::known(*scalar))?;
+                        transcript_chip.write_point(ctx, &point)?;
+                        transcript_chip.write_scalar(&scalar);
+                        let squeezed = transcript_chip.squeeze(ctx)?;
+                        main_gate.assert_equal_to_constant(ctx, &squeezed, self.challenges[i])?;
+
+                        let (a, b) = self.integers[i];
+                        let [a, b] = [a, b]
+                            .map(|e| Value::known(Integer::from_fe(e, integer_chip.rns())).into());
+                        let a = integer_chip.assign_integer(ctx, a, Range::Remainder)?;
+                        let b = integer_chip.assign_integer(ctx, b, Range::Remainder)?;
+                        let integer = integer_chip.add(ctx, &a, &b)?;
+                        transcript_chip.write_integer(ctx, integer_chip, &integer)?;
+                        let squeezed = transcript_chip.squeeze_integer(ctx, integer_chip)?;
+                        let expected =
+                            integer_chip.assign_constant(ctx, self.integer_challenges[i])?;
+                        integer_chip.assert_equal(ctx, &squeezed, &expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run_native_transcript<
+        E: PointRepresentation<G1Affine, Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    >() {
+        let points = (0..3)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let integers = (0..3)
+            .map(|_| (Fq::random(OsRng), Fq::random(OsRng)))
+            .collect::<Vec<_>>();
+
+        let mut transcript = PoseidonTranscript::<
+            G1Affine,
+            Fr,
+            E,
+            Vec<u8>,
+            NUMBER_OF_LIMBS,
+            BIT_LEN_LIMB,
+            5,
+            4,
+            8,
+            57,
+        >::init(vec![]);
+        let mut challenges = vec![];
+        let mut integer_challenges = vec![];
+        for ((point, scalar), (a, b)) in points.iter().zip(scalars.iter()).zip(integers.iter()) {
+            transcript.write_point(*point).unwrap();
+            transcript.write_scalar(*scalar).unwrap();
+            challenges.push(transcript.squeeze_challenge().get_scalar());
+            transcript.write_integer(*a + *b).unwrap();
+            integer_challenges.push(transcript.squeeze_integer::<Fq>());
+        }
+
+        let circuit = TestCircuitNativeTranscript {
+            spec: Spec::new(8, 57),
+            points,
+            scalars,
+            challenges,
+            integers,
+            integer_challenges,
+            _marker: PhantomData::<E>,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_native_transcript_compatibility() {
+        run_native_transcript::<LimbRepresentation>();
+        run_native_transcript::<NativeRepresentation>();
+    }
+
+    #[test]
+    fn test_integer_challenge_bit_len() {
+        type W = <Secp256k1 as CurveAffine>::ScalarExt;
+        let bit_len = integer_challenge_bit_len::<W, Fr, BIT_LEN_LIMB>();
+        assert_eq!(bit_len, 204);
+
+        // challenges are truncated rather than reduced modulo `W`
+        let challenge = big_to_fe::<Fr>((big_uint::from(3u64) << bit_len) + 5u64);
+        let challenge = integer_challenge::<W, Fr, BIT_LEN_LIMB>(challenge);
+        assert_eq!(challenge, W::from(5));
+    }
+
+    struct TestCircuitRescueTranscript {
+        spec: RescuePrimeSpec<Fr, 3, 2>,
+        points: Vec<G1Affine>,
+        scalars: Vec<Fr>,
+        challenges: Vec<Fr>,
+    }
+
+    impl Circuit<Fr> for TestCircuitRescueTranscript {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            TestCircuitConfig::new::<G1Affine>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+            let ecc_chip = BaseFieldEccChip::<G1Affine, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let rescue_chip =
+                        RescuePrimeChip::new(ctx, &self.spec, &config.main_gate_config)?;
+                    let mut transcript_chip = TranscriptChip::<
+                        _,
+                        _,
+                        _,
+                        NUMBER_OF_LIMBS,
+                        BIT_LEN_LIMB,
+                        3,
+                        2,
+                        RescuePrimeChip<Fr, 3, 2>,
+                    >::new_with_permutation(
+                        ecc_chip.integer_chip().clone(),
+                        rescue_chip,
+                        LimbRepresentation,
+                    );
+
+                    for (i, (point, scalar)) in
+                        self.points.iter().zip(self.scalars.iter()).enumerate()
+                    {
+                        let point = ecc_chip.assign_point(ctx, Value::known(*point))?;
+                        let scalar = main_gate.assign_value(ctx, Value::known(*scalar))?;
+                        transcript_chip.write_point(ctx, &point)?;
+                        transcript_chip.write_scalar(&scalar);
+                        let squeezed = transcript_chip.squeeze(ctx)?;
+                        main_gate.assert_equal_to_constant(ctx, &squeezed, self.challenges[i])?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_rescue_transcript() {
+        let spec = RescuePrimeSpec::<Fr, 3, 2>::new(14, 128, Fr::from(5));
+        let points = (0..3)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+        // Sponge out of circuit where every squeeze pads the absorbed inputs
+        let mut state = [Fr::ZERO; 3];
+        let challenges = points
+            .iter()
+            .zip(scalars.iter())
+            .map(|(point, scalar)| {
+                let mut inputs = <LimbRepresentation as PointRepresentation<
+                    G1Affine,
+                    Fr,
+                    NUMBER_OF_LIMBS,
+                    BIT_LEN_LIMB,
+                >>::encode(*point)
+                .unwrap();
+                inputs.push(*scalar);
+                inputs.push(Fr::ONE);
+                inputs.resize((inputs.len() + 1) / 2 * 2, Fr::ZERO);
+                for chunk in inputs.chunks(2) {
+                    state[0] += chunk[0];
+                    state[1] += chunk[1];
+                    spec.permute(&mut state);
+                }
+                state[0]
+            })
+            .collect();
+
+        let circuit = TestCircuitRescueTranscript {
+            spec,
+            points,
+            scalars,
+            challenges,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[derive(Clone)]
+    struct TestGeneralCircuitConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestGeneralCircuitConfig {
+        fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        fn new<C: CurveAffine, N: FromUniformBytes<64>>(meta: &mut ConstraintSystem<N>) -> Self {
+            let rns_base = Rns::<C::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let rns_scalar = Rns::<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lens: Vec<usize> = vec![];
+            overflow_bit_lens.extend(rns_base.overflow_lengths());
+            overflow_bit_lens.extend(rns_scalar.overflow_lengths());
+            let composition_bit_lens = vec![BIT_LEN_LIMB / NUMBER_OF_LIMBS];
+
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                composition_bit_lens,
+                overflow_bit_lens,
+            );
+            TestGeneralCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn config_range<N: FromUniformBytes<64>>(
+            &self,
+            layouter: &mut impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let range_chip = RangeChip::<N>::new(self.range_config.clone());
+            range_chip.load_table(layouter)?;
+
+            Ok(())
+        }
+    }
+
+    struct TestGeneralCircuit<
+        C: CurveAffine,
+        N: FromUniformBytes<64>,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    > {
+        points: Vec<C>,
+        scalars: Vec<N>,
+        challenges: Vec<N>,
+        integer: C::Scalar,
+        integer_challenge: C::Scalar,
+        _marker: PhantomData<E>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: FromUniformBytes<64> + Ord,
+            E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        > Circuit<N> for TestGeneralCircuit<C, N, E>
+    {
+        type Config = TestGeneralCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestGeneralCircuitConfig::new::<C, N>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let ecc_chip = GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.ecc_chip_config(),
+            );
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let spec = Spec::<N, 5, 4>::new(8, 57);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let mut transcript_chip = TranscriptChip::<
+                        _,
+                        _,
+                        _,
+                        NUMBER_OF_LIMBS,
+                        BIT_LEN_LIMB,
+                        5,
+                        4,
+                    >::new_general(
+                        ctx, &spec, ecc_chip.clone(), E::default()
+                    )?;
+
+                    for ((point, scalar), challenge) in self
+                        .points
+                        .iter()
+                        .zip(self.scalars.iter())
+                        .zip(self.challenges.iter())
+                    {
+                        let point = ecc_chip.assign_point(ctx, Value::known(*point))?;
+                        let scalar = main_gate.assign_value(ctx, Value::known(*scalar))?;
+                        transcript_chip.write_point(ctx, &point)?;
+                        transcript_chip.write_scalar(&scalar);
+                        let squeezed = transcript_chip.squeeze(ctx)?;
+                        main_gate.assert_equal_to_constant(ctx, &squeezed, *challenge)?;
+                    }
+
+                    let integer = Integer::from_fe(self.integer, scalar_chip.rns());
+                    let integer = scalar_chip.assign_integer(
+                        ctx,
+                        Value::known(integer).into(),
+                        Range::Remainder,
+                    )?;
+                    transcript_chip.write_integer(ctx, scalar_chip, &integer)?;
+                    let squeezed = transcript_chip.squeeze_integer(ctx, scalar_chip)?;
+                    let expected = scalar_chip.assign_constant(ctx, self.integer_challenge)?;
+                    scalar_chip.assert_equal(ctx, &squeezed, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run_general<
+        C: CurveAffine,
+        N: FromUniformBytes<64> + Ord,
+        E: PointRepresentation<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    >() {
+        let points = (0..3)
+            .map(|_| C::CurveExt::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| N::random(OsRng)).collect::<Vec<_>>();
+        let integer = C::Scalar::random(OsRng);
+
+        let mut hasher = Poseidon::<N, 5, 4>::new(8, 57);
+        let challenges = points
+            .iter()
+            .zip(scalars.iter())
+            .map(|(point, scalar)| {
+                hasher.update(&E::encode(*point).unwrap());
+                hasher.update(&[*scalar]);
+                hasher.squeeze()
+            })
+            .collect::<Vec<_>>();
+        hasher.update(&encode_integer::<C::Scalar, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(integer));
+        let integer_challenge = integer_challenge::<C::Scalar, N, BIT_LEN_LIMB>(hasher.squeeze());
+
+        let circuit = TestGeneralCircuit::<C, N, E> {
+            points,
+            scalars,
+            challenges,
+            integer,
+            integer_challenge,
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_general_transcript() {
+        run_general::<Secp256k1, Fr, LimbRepresentation>();
+        run_general::<G1Affine, Fr, LimbRepresentation>();
+        run_general::<G1Affine, Fr, NativeRepresentation>();
+        run_general::<Vesta, PastaFp, LimbRepresentation>();
+    }
+
     test!(8, 57, 3, 2);
     test!(8, 57, 4, 3);
     test!(8, 57, 5, 4);